@@ -0,0 +1,244 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::{
+	path::{Path, PathBuf},
+	fs::{self, File},
+	io,
+	ops::{Deref, DerefMut},
+	sync::atomic::{AtomicU64, Ordering},
+	ffi::OsString,
+};
+
+use crate::file::FileWriter;
+
+static TEMP_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Wraps a file writer which writes to a temporary file next to the target
+/// path. The temporary file replaces the target only when the writer is
+/// committed, so the target is never left partially written. If the writer
+/// is dropped without being committed, the temporary file is removed and
+/// the target is left untouched.
+///
+/// The wrapped writer is accessible through `Deref` and `DerefMut`.
+pub struct AtomicWriter<W>
+where
+	W: FileWriter,
+{
+	writer: Option<W>,
+	file: File,
+
+	temp_path: PathBuf,
+	path: PathBuf,
+}
+
+impl<W> AtomicWriter<W>
+where
+	W: FileWriter,
+{
+	pub(crate) fn new<P>(path: P) -> io::Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		let path = path.as_ref().to_path_buf();
+		let temp_path = temp_path(&path)?;
+
+		let file = File::create(&temp_path)?;
+		let handle = file.try_clone()?;
+
+		let writer = match W::from_file(file) {
+			Ok(writer) => writer,
+
+			Err(err) => {
+				let _ = fs::remove_file(&temp_path);
+				return Err(err);
+			},
+		};
+
+		let writer = AtomicWriter {
+			writer: Some(writer),
+			file: handle,
+
+			temp_path,
+			path,
+		};
+
+		Ok(writer)
+	}
+
+	/// Flushes the writer and renames the temporary file over the target
+	/// path, replacing it in a single step.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::{
+	///     FileWriter,
+	///     text::TextWriter,
+	/// };
+	///
+	/// let mut writer = TextWriter::from_path_atomic("/path/to/file").unwrap();
+	///
+	/// writer.write_line(b"data").unwrap();
+	/// writer.commit().unwrap();
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the writer could not be
+	/// flushed or the temporary file could not be renamed. In that case,
+	/// the temporary file is removed and the target is left untouched.
+	pub fn commit(mut self) -> io::Result<()> {
+		let result = self.replace_target();
+
+		if result.is_err() {
+			let _ = fs::remove_file(&self.temp_path);
+		}
+
+		result
+	}
+
+	fn replace_target(&mut self) -> io::Result<()> {
+		if let Some(mut writer) = self.writer.take() {
+			writer.flush()?;
+		}
+
+		self.file.sync_all()?;
+		fs::rename(&self.temp_path, &self.path)
+	}
+}
+
+impl<W> Deref for AtomicWriter<W>
+where
+	W: FileWriter,
+{
+	type Target = W;
+
+	fn deref(&self) -> &Self::Target {
+		self.writer.as_ref().unwrap()
+	}
+}
+
+impl<W> DerefMut for AtomicWriter<W>
+where
+	W: FileWriter,
+{
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		self.writer.as_mut().unwrap()
+	}
+}
+
+impl<W> Drop for AtomicWriter<W>
+where
+	W: FileWriter,
+{
+	fn drop(&mut self) {
+		// the writer is only taken on commit, which cleans up after
+		// itself, so if it is still present the temporary file is discarded
+		if self.writer.take().is_some() {
+			let _ = fs::remove_file(&self.temp_path);
+		}
+	}
+}
+
+/// Returns a unique, hidden path in the same directory as the supplied
+/// path, so the rename does not cross file systems.
+fn temp_path(path: &Path) -> io::Result<PathBuf> {
+	let Some(file_name) = path.file_name() else {
+		return Err(io::Error::new(
+			io::ErrorKind::InvalidInput,
+			"the path does not name a file",
+		));
+	};
+
+	let mut temp_name = OsString::from(".");
+
+	temp_name.push(file_name);
+	temp_name.push(format!(
+		".{}.{}.tmp",
+		std::process::id(),
+		TEMP_COUNT.fetch_add(1, Ordering::Relaxed),
+	));
+
+	Ok(path.with_file_name(temp_name))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env, fs, io};
+
+	use crate::file::{
+		FileWriter,
+		text::TextWriter,
+		csv::{CsvWriter, WriteRow, RowData},
+	};
+
+	struct Values(Vec<u32>);
+
+	impl WriteRow for Values {
+		fn as_row(&self, row: &mut RowData) -> io::Result<()> {
+			for value in &self.0 {
+				row.push(value);
+			}
+
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn it_replaces_the_target_only_on_commit() {
+		let dir = env::temp_dir().join(format!("kwik_atomic_{}", std::process::id()));
+		fs::create_dir_all(&dir).unwrap();
+
+		let path = dir.join("data.txt");
+		fs::write(&path, "original\n").unwrap();
+
+		let mut writer = TextWriter::from_path_atomic(&path).unwrap();
+		writer.write_line(b"partial").unwrap();
+		drop(writer);
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "original\n");
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+		let mut writer = TextWriter::from_path_atomic(&path).unwrap();
+		writer.write_line(b"first").unwrap();
+		writer.write_line(b"second").unwrap();
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "original\n");
+
+		writer.commit().unwrap();
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "first\nsecond\n");
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+		let mut writer = CsvWriter::<Values>::from_path_atomic(&path).unwrap();
+		writer.write_row(&Values(vec![1, 2, 3])).unwrap();
+		writer.commit().unwrap();
+
+		assert_eq!(fs::read_to_string(&path).unwrap(), "1,2,3\n");
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[test]
+	fn it_removes_the_temporary_file_on_failed_commits() {
+		let dir = env::temp_dir().join(format!("kwik_atomic_failed_{}", std::process::id()));
+		let path = dir.join("data");
+
+		// a file cannot be renamed over a non-empty directory
+		fs::create_dir_all(path.join("child")).unwrap();
+
+		let mut writer = TextWriter::from_path_atomic(&path).unwrap();
+		writer.write_line(b"data").unwrap();
+
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 2);
+		assert!(writer.commit().is_err());
+		assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+		assert!(path.join("child").is_dir());
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
+}
@@ -0,0 +1,219 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::{
+	future,
+	marker::PhantomData,
+	path::Path,
+	pin::Pin,
+	task::{Context, Poll},
+	io,
+};
+
+use tokio::{
+	fs::File,
+	io::{AsyncRead, BufReader, ReadBuf},
+};
+
+use futures_core::Stream;
+
+use crate::file::binary::ReadChunk;
+
+/// Reads a binary file in chunks without blocking the async runtime.
+pub struct AsyncBinaryReader<T>
+where
+	T: ReadChunk,
+{
+	file: BufReader<File>,
+	size: u64,
+	buf: Box<[u8]>,
+	filled: usize,
+	count: u64,
+
+	_marker: PhantomData<T>,
+}
+
+impl<T> AsyncBinaryReader<T>
+where
+	T: ReadChunk,
+{
+	/// Opens the file at the supplied path.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::binary::AsyncBinaryReader;
+	///
+	/// # async fn read() {
+	/// let reader = AsyncBinaryReader::<u64>::from_path("/path/to/file").await.unwrap();
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the file could not be opened.
+	pub async fn from_path<P>(path: P) -> io::Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		AsyncBinaryReader::from_file(File::open(path).await?).await
+	}
+
+	/// Reads chunks from the supplied file, starting at its current position.
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the file's metadata could
+	/// not be read.
+	pub async fn from_file(file: File) -> io::Result<Self> {
+		let size = file.metadata().await?.len();
+
+		let reader = AsyncBinaryReader {
+			file: BufReader::new(file),
+			size,
+			buf: vec![0; T::size()].into_boxed_slice(),
+			filled: 0,
+			count: 0,
+
+			_marker: PhantomData,
+		};
+
+		Ok(reader)
+	}
+
+	/// Returns the size of the file in bytes.
+	#[inline]
+	pub fn size(&self) -> u64 {
+		self.size
+	}
+
+	/// Reads one chunk of the binary file and returns a `Result` containing
+	/// the parsed object, or `None` if the end of the file has been reached.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::binary::AsyncBinaryReader;
+	///
+	/// # async fn read() {
+	/// let mut reader = AsyncBinaryReader::<u64>::from_path("/path/to/file").await.unwrap();
+	///
+	/// while let Some(chunk) = reader.read_chunk().await.unwrap() {
+	///     // do something with the chunk
+	/// }
+	/// # }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the chunk could not be read
+	/// or parsed, or if the file ends partway through a chunk.
+	pub async fn read_chunk(&mut self) -> io::Result<Option<T>> {
+		future::poll_fn(|cx| self.poll_chunk(cx)).await
+	}
+
+	fn poll_chunk(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Option<T>>> {
+		while self.filled < self.buf.len() {
+			let mut buf = ReadBuf::new(&mut self.buf[self.filled..]);
+
+			match Pin::new(&mut self.file).poll_read(cx, &mut buf) {
+				Poll::Ready(Ok(())) => {},
+				Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+				Poll::Pending => return Poll::Pending,
+			}
+
+			let read_len = buf.filled().len();
+
+			if read_len == 0 {
+				if self.filled == 0 {
+					return Poll::Ready(Ok(None));
+				}
+
+				self.filled = 0;
+
+				return Poll::Ready(Err(io::Error::new(
+					io::ErrorKind::UnexpectedEof,
+					format!("Partial chunk at the end of the file after chunk {}", self.count),
+				)));
+			}
+
+			self.filled += read_len;
+		}
+
+		self.filled = 0;
+		self.count += 1;
+
+		Poll::Ready(T::from_chunk(&self.buf).map(Some))
+	}
+}
+
+impl<T> Stream for AsyncBinaryReader<T>
+where
+	T: ReadChunk + Unpin,
+{
+	type Item = io::Result<T>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		self
+			.get_mut()
+			.poll_chunk(cx)
+			.map(Result::transpose)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		env,
+		fs,
+		future,
+		pin::Pin,
+	};
+
+	use futures_core::Stream;
+
+	use crate::file::{
+		FileWriter,
+		binary::{BinaryWriter, AsyncBinaryReader},
+	};
+
+	#[tokio::test]
+	async fn it_reads_chunks_asynchronously() {
+		let path = env::temp_dir().join(format!("kwik_async_{}.bin", std::process::id()));
+		let mut writer = BinaryWriter::<u64>::from_path(&path).unwrap();
+
+		for index in 0..100u64 {
+			writer.write_chunk(&(index * 3)).unwrap();
+		}
+
+		writer.flush().unwrap();
+		drop(writer);
+
+		let mut reader = AsyncBinaryReader::<u64>::from_path(&path).await.unwrap();
+
+		assert_eq!(reader.size(), 800);
+		assert_eq!(reader.read_chunk().await.unwrap(), Some(0));
+		assert_eq!(reader.read_chunk().await.unwrap(), Some(3));
+
+		let mut values = Vec::<u64>::new();
+
+		while let Some(value) = future::poll_fn(|cx| Pin::new(&mut reader).poll_next(cx)).await {
+			values.push(value.unwrap());
+		}
+
+		assert_eq!(values, (2..100).map(|index| index * 3).collect::<Vec<_>>());
+		assert!(reader.read_chunk().await.unwrap().is_none());
+
+		// a trailing partial chunk is an error
+		fs::write(&path, [0u8; 12]).unwrap();
+
+		let mut reader = AsyncBinaryReader::<u64>::from_path(&path).await.unwrap();
+
+		assert_eq!(reader.read_chunk().await.unwrap(), Some(0));
+		assert!(reader.read_chunk().await.is_err());
+
+		fs::remove_file(path).unwrap();
+	}
+}
@@ -0,0 +1,331 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::{
+	marker::PhantomData,
+	path::Path,
+	fs::File,
+	io::{
+		self,
+		BufReader,
+		BufWriter,
+		Read,
+		Write,
+		Seek,
+		SeekFrom,
+	},
+};
+
+use crate::file::{
+	FileReader,
+	FileWriter,
+	ReadStream,
+	WriteStream,
+	stream_len,
+	binary::{ReadChunk, WriteChunk},
+};
+
+const CHECKSUM_SIZE: usize = 4;
+
+/// Reads a binary file in chunks, each followed by a CRC32 checksum of
+/// its payload, as written by a `ChecksummedBinaryWriter`. Each chunk is
+/// verified before it is parsed.
+pub struct ChecksummedBinaryReader<T>
+where
+	T: ReadChunk,
+{
+	file: BufReader<Box<dyn ReadStream>>,
+	size: u64,
+	buf: Box<[u8]>,
+	count: u64,
+
+	_marker: PhantomData<T>,
+}
+
+/// Writes a binary file in chunks, appending a CRC32 checksum of each
+/// chunk's payload after it.
+pub struct ChecksummedBinaryWriter<T>
+where
+	T: WriteChunk,
+{
+	file: BufWriter<Box<dyn WriteStream>>,
+	buf: Vec<u8>,
+	count: u64,
+
+	_marker: PhantomData<T>,
+}
+
+pub struct ChecksummedIter<'a, T>
+where
+	T: ReadChunk,
+{
+	reader: &'a mut ChecksummedBinaryReader<T>,
+}
+
+impl<T> FileReader for ChecksummedBinaryReader<T>
+where
+	T: ReadChunk,
+{
+	fn from_path<P>(path: P) -> io::Result<Self>
+	where
+		Self: Sized,
+		P: AsRef<Path>,
+	{
+		ChecksummedBinaryReader::from_file(File::open(path)?)
+	}
+
+	fn from_reader<R>(mut reader: R) -> io::Result<Self>
+	where
+		Self: Sized,
+		R: 'static + Read + Seek + Send,
+	{
+		let size = stream_len(&mut reader).unwrap_or(0);
+
+		let reader = ChecksummedBinaryReader {
+			file: BufReader::new(Box::new(reader) as Box<dyn ReadStream>),
+			size,
+			buf: vec![0; T::size() + CHECKSUM_SIZE].into_boxed_slice(),
+			count: 0,
+
+			_marker: PhantomData,
+		};
+
+		Ok(reader)
+	}
+
+	#[inline]
+	fn size(&self) -> u64 {
+		self.size
+	}
+}
+
+impl<T> ChecksummedBinaryReader<T>
+where
+	T: ReadChunk,
+{
+	/// Reads one chunk and its checksum from the binary file, and returns
+	/// a `Result` containing the parsed chunk. If the end of the file is
+	/// reached, an `io::Error` is returned.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::{
+	///     FileReader,
+	///     binary::ChecksummedBinaryReader,
+	/// };
+	///
+	/// let mut reader = ChecksummedBinaryReader::<u64>::from_path("/path/to/file").unwrap();
+	///
+	/// while let Ok(value) = reader.read_chunk() {
+	///     // do something with the value
+	/// }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the chunk could not be read
+	/// or its checksum does not match its payload.
+	#[inline]
+	pub fn read_chunk(&mut self) -> io::Result<T> {
+		self.file.read_exact(&mut self.buf)?;
+		self.count += 1;
+
+		let (payload, checksum) = self.buf.split_at(T::size());
+		let checksum = u32::from_le_bytes(checksum.try_into().unwrap());
+
+		if crc32fast::hash(payload) != checksum {
+			let message = format!("Checksum mismatch at chunk {}", self.count);
+			return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+		}
+
+		T::from_chunk(payload)
+	}
+
+	/// Returns an iterator over the binary file. The iterator takes a mutable
+	/// reference to `self` as it is iterating over a stream. This means performing
+	/// the iteration modifies the reader's position in the file.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::{
+	///     FileReader,
+	///     binary::ChecksummedBinaryReader,
+	/// };
+	///
+	/// let mut reader = ChecksummedBinaryReader::<u64>::from_path("/path/to/file").unwrap();
+	///
+	/// for value in reader.iter() {
+	///     // do something with the value
+	/// }
+	/// ```
+	#[inline]
+	pub fn iter(&mut self) -> ChecksummedIter<'_, T> {
+		ChecksummedIter {
+			reader: self
+		}
+	}
+}
+
+impl<T> Iterator for ChecksummedIter<'_, T>
+where
+	T: ReadChunk,
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.reader.read_chunk() {
+			Ok(chunk) => Some(chunk),
+			Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => None,
+
+			Err(_) => panic!(
+				"An error occurred on chunk {} when reading binary file",
+				self.reader.count,
+			),
+		}
+	}
+}
+
+impl<T> FileWriter for ChecksummedBinaryWriter<T>
+where
+	T: WriteChunk,
+{
+	fn from_path<P>(path: P) -> io::Result<Self>
+	where
+		Self: Sized,
+		P: AsRef<Path>,
+	{
+		ChecksummedBinaryWriter::from_file(File::create(path)?)
+	}
+
+	fn from_writer<W>(writer: W) -> io::Result<Self>
+	where
+		Self: Sized,
+		W: 'static + Write + Seek + Send,
+	{
+		let writer = ChecksummedBinaryWriter {
+			file: BufWriter::new(Box::new(writer) as Box<dyn WriteStream>),
+			buf: Vec::<u8>::with_capacity(T::size() + CHECKSUM_SIZE),
+			count: 0,
+
+			_marker: PhantomData,
+		};
+
+		Ok(writer)
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		self.file.flush()
+	}
+}
+
+impl<T> ChecksummedBinaryWriter<T>
+where
+	T: WriteChunk,
+{
+	/// Writes one chunk to the binary file, followed by the CRC32 checksum
+	/// of the chunk.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::{
+	///     FileWriter,
+	///     binary::ChecksummedBinaryWriter,
+	/// };
+	///
+	/// let mut writer = ChecksummedBinaryWriter::<u64>::from_path("/path/to/file").unwrap();
+	///
+	/// writer.write_chunk(&5).unwrap();
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the chunk could not be written.
+	#[inline]
+	pub fn write_chunk(&mut self, object: &T) -> io::Result<()> {
+		self.buf.clear();
+		self.count += 1;
+
+		object.as_chunk(&mut self.buf)?;
+
+		if self.buf.len() != T::size() {
+			let message = format!("Invalid chunk size at chunk {}", self.count);
+			return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+		}
+
+		let checksum = crc32fast::hash(&self.buf);
+		self.buf.extend_from_slice(&checksum.to_le_bytes());
+
+		self.file.write_all(&self.buf)
+	}
+}
+
+impl<T> Seek for ChecksummedBinaryReader<T>
+where
+	T: ReadChunk,
+{
+	fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+		self.file.seek(pos)
+	}
+}
+
+impl<T> Seek for ChecksummedBinaryWriter<T>
+where
+	T: WriteChunk,
+{
+	fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+		self.file.seek(pos)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env, fs, io};
+
+	use crate::file::{
+		FileReader,
+		FileWriter,
+		binary::{ChecksummedBinaryReader, ChecksummedBinaryWriter},
+	};
+
+	#[test]
+	fn it_detects_corrupt_chunks() {
+		let path = env::temp_dir().join(format!("kwik_checksum_{}.bin", std::process::id()));
+		let mut writer = ChecksummedBinaryWriter::<u32>::from_path(&path).unwrap();
+
+		for value in 0..10u32 {
+			writer.write_chunk(&(value * 7)).unwrap();
+		}
+
+		writer.flush().unwrap();
+		drop(writer);
+
+		let mut reader = ChecksummedBinaryReader::<u32>::from_path(&path).unwrap();
+
+		assert_eq!(reader.size(), 80);
+		assert_eq!(reader.iter().collect::<Vec<_>>(), (0..10).map(|value| value * 7).collect::<Vec<_>>());
+
+		// flips a bit in the payload of the fourth chunk
+		let mut data = fs::read(&path).unwrap();
+		data[3 * 8 + 1] ^= 0x01;
+		fs::write(&path, data).unwrap();
+
+		let mut reader = ChecksummedBinaryReader::<u32>::from_path(&path).unwrap();
+
+		for value in 0..3u32 {
+			assert_eq!(reader.read_chunk().unwrap(), value * 7);
+		}
+
+		let err = reader.read_chunk().unwrap_err();
+
+		assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+		assert!(err.to_string().contains("chunk 4"));
+
+		assert_eq!(reader.read_chunk().unwrap(), 28);
+
+		fs::remove_file(path).unwrap();
+	}
+}
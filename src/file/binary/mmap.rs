@@ -0,0 +1,224 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::{
+	marker::PhantomData,
+	path::Path,
+	fs::File,
+	io,
+};
+
+use memmap2::Mmap;
+
+use crate::file::binary::ReadChunk;
+
+/// Reads a binary file in chunks by mapping it into memory, allowing
+/// any chunk to be accessed by its index without seeking.
+pub struct MmapReader<T>
+where
+	T: ReadChunk,
+{
+	mmap: Mmap,
+	len: usize,
+
+	_marker: PhantomData<T>,
+}
+
+pub struct MmapIter<'a, T>
+where
+	T: ReadChunk,
+{
+	reader: &'a MmapReader<T>,
+	index: usize,
+}
+
+impl<T> MmapReader<T>
+where
+	T: ReadChunk,
+{
+	/// Opens the file at the supplied path and maps it into memory.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::binary::MmapReader;
+	///
+	/// let reader = MmapReader::<u64>::from_path("/path/to/file").unwrap();
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the file could not be opened
+	/// or mapped.
+	pub fn from_path<P>(path: P) -> io::Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		MmapReader::from_file(File::open(path)?)
+	}
+
+	/// Maps the supplied file into memory.
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the file could not be mapped.
+	pub fn from_file(file: File) -> io::Result<Self> {
+		// SAFETY: the mapping is only read, but its contents are undefined
+		// if the file is modified by another process while it is mapped
+		let mmap = unsafe { Mmap::map(&file)? };
+
+		let len = match T::size() {
+			0 => 0,
+			size => mmap.len() / size,
+		};
+
+		let reader = MmapReader {
+			mmap,
+			len,
+
+			_marker: PhantomData,
+		};
+
+		Ok(reader)
+	}
+
+	/// Returns the number of complete chunks in the file.
+	#[inline]
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns `true` if the file contains no complete chunks.
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Parses and returns the chunk at the supplied index.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::binary::MmapReader;
+	///
+	/// let reader = MmapReader::<u64>::from_path("/path/to/file").unwrap();
+	///
+	/// if let Ok(value) = reader.get(10) {
+	///     // do something with the value
+	/// }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the index is out of bounds
+	/// or the chunk could not be parsed.
+	#[inline]
+	pub fn get(&self, index: usize) -> io::Result<T> {
+		if index >= self.len {
+			return Err(io::Error::new(
+				io::ErrorKind::UnexpectedEof,
+				format!("Chunk {index} is out of bounds"),
+			));
+		}
+
+		let start = index * T::size();
+		T::from_chunk(&self.mmap[start..start + T::size()])
+	}
+
+	/// Returns an iterator over the chunks of the file.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::binary::MmapReader;
+	///
+	/// let reader = MmapReader::<u64>::from_path("/path/to/file").unwrap();
+	///
+	/// for value in reader.iter() {
+	///     // do something with the value
+	/// }
+	/// ```
+	#[inline]
+	pub fn iter(&self) -> MmapIter<'_, T> {
+		MmapIter {
+			reader: self,
+			index: 0,
+		}
+	}
+}
+
+impl<T> Iterator for MmapIter<'_, T>
+where
+	T: ReadChunk,
+{
+	type Item = T;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.index >= self.reader.len() {
+			return None;
+		}
+
+		match self.reader.get(self.index) {
+			Ok(chunk) => {
+				self.index += 1;
+				Some(chunk)
+			},
+
+			Err(_) => panic!(
+				"An error occurred on chunk {} when reading binary file",
+				self.index + 1,
+			),
+		}
+	}
+}
+
+impl<'a, T> IntoIterator for &'a MmapReader<T>
+where
+	T: ReadChunk,
+{
+	type Item = T;
+	type IntoIter = MmapIter<'a, T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{env, fs};
+
+	use crate::file::{
+		FileWriter,
+		binary::{BinaryWriter, MmapReader},
+	};
+
+	#[test]
+	fn it_reads_chunks_by_index() {
+		let path = env::temp_dir().join(format!("kwik_mmap_{}.bin", std::process::id()));
+		let mut writer = BinaryWriter::<u64>::from_path(&path).unwrap();
+
+		for index in 0..100u64 {
+			writer.write_chunk(&(index * 3)).unwrap();
+		}
+
+		writer.flush().unwrap();
+		drop(writer);
+
+		let reader = MmapReader::<u64>::from_path(&path).unwrap();
+
+		assert_eq!(reader.len(), 100);
+
+		for index in [57, 0, 99, 12, 42] {
+			assert_eq!(reader.get(index).unwrap(), index as u64 * 3);
+		}
+
+		assert!(reader.get(100).is_err());
+		assert_eq!(reader.iter().sum::<u64>(), (0..100).map(|index| index * 3).sum());
+
+		fs::remove_file(path).unwrap();
+	}
+}
@@ -7,6 +7,13 @@
 
 mod reader;
 mod writer;
+mod checksum;
+
+#[cfg(feature = "mmap")]
+mod mmap;
+
+#[cfg(feature = "tokio")]
+mod async_reader;
 
 use std::mem;
 
@@ -33,8 +40,15 @@ pub trait SizedChunk {
 pub use crate::file::binary::{
 	reader::{BinaryReader, ReadChunk, Iter, IntoIter},
 	writer::{BinaryWriter, WriteChunk},
+	checksum::{ChecksummedBinaryReader, ChecksummedBinaryWriter, ChecksummedIter},
 };
 
+#[cfg(feature = "mmap")]
+pub use crate::file::binary::mmap::{MmapReader, MmapIter};
+
+#[cfg(feature = "tokio")]
+pub use crate::file::binary::async_reader::AsyncBinaryReader;
+
 macro_rules! impl_sized_chunk_primitive {
 	($T:ty) => {
 		impl SizedChunk for $T {
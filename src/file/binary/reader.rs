@@ -20,6 +20,8 @@ use std::{
 
 use crate::file::{
 	FileReader,
+	ReadStream,
+	stream_len,
 	binary::SizedChunk,
 };
 
@@ -28,7 +30,8 @@ pub struct BinaryReader<T>
 where
 	T: ReadChunk,
 {
-	file: BufReader<File>,
+	file: BufReader<Box<dyn ReadStream>>,
+	size: u64,
 	buf: Box<[u8]>,
 	count: u64,
 
@@ -101,12 +104,16 @@ where
 		BinaryReader::from_file(File::open(path)?)
 	}
 
-	fn from_file(file: File) -> io::Result<Self>
+	fn from_reader<R>(mut reader: R) -> io::Result<Self>
 	where
 		Self: Sized,
+		R: 'static + Read + Seek + Send,
 	{
+		let size = stream_len(&mut reader).unwrap_or(0);
+
 		let reader = BinaryReader {
-			file: BufReader::new(file),
+			file: BufReader::new(Box::new(reader) as Box<dyn ReadStream>),
+			size,
 			buf: vec![0; T::size()].into_boxed_slice(),
 			count: 0,
 
@@ -118,12 +125,7 @@ where
 
 	#[inline]
 	fn size(&self) -> u64 {
-		let metadata = self.file
-			.get_ref()
-			.metadata()
-			.expect("Could not get binary file's size");
-
-		metadata.len()
+		self.size
 	}
 }
 
@@ -224,7 +226,7 @@ where
 	/// }
 	/// ```
 	#[inline]
-	pub fn iter(&mut self) -> Iter<T> {
+	pub fn iter(&mut self) -> Iter<'_, T> {
 		Iter {
 			reader: self
 		}
@@ -349,3 +351,33 @@ impl_read_chunk_primitive!(f32);
 impl_read_chunk_primitive!(f64);
 impl_read_chunk_primitive!(char);
 impl_read_chunk_primitive!(bool);
+
+#[cfg(test)]
+mod tests {
+	use std::io::{Cursor, Seek, SeekFrom};
+
+	use crate::file::{
+		FileReader,
+		binary::BinaryReader,
+	};
+
+	#[test]
+	fn it_reads_chunks_from_a_stream() {
+		let values = [1u64, 20, 300, 4_000];
+
+		let data = values
+			.iter()
+			.flat_map(|value| value.to_le_bytes())
+			.collect::<Vec<u8>>();
+
+		let mut reader = BinaryReader::<u64>::from_reader(Cursor::new(data)).unwrap();
+
+		assert_eq!(reader.size(), 32);
+		assert_eq!(reader.iter().collect::<Vec<u64>>(), values);
+
+		reader.seek(SeekFrom::Start(16)).unwrap();
+
+		assert_eq!(reader.read_chunk().unwrap(), 300);
+		assert_eq!(reader.into_iter().collect::<Vec<u64>>(), vec![4_000]);
+	}
+}
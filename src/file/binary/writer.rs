@@ -20,6 +20,7 @@ use std::{
 
 use crate::file::{
 	FileWriter,
+	WriteStream,
 	binary::SizedChunk,
 };
 
@@ -28,7 +29,7 @@ pub struct BinaryWriter<T>
 where
 	T: WriteChunk,
 {
-	file: BufWriter<File>,
+	file: BufWriter<Box<dyn WriteStream>>,
 	buf: Vec<u8>,
 	count: u64,
 
@@ -83,12 +84,13 @@ where
 		BinaryWriter::from_file(File::create(path)?)
 	}
 
-	fn from_file(file: File) -> io::Result<Self>
+	fn from_writer<W>(writer: W) -> io::Result<Self>
 	where
 		Self: Sized,
+		W: 'static + Write + Seek + Send,
 	{
 		let writer = BinaryWriter {
-			file: BufWriter::new(file),
+			file: BufWriter::new(Box::new(writer) as Box<dyn WriteStream>),
 			buf: Vec::<u8>::with_capacity(T::size()),
 			count: 0,
 
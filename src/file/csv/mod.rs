@@ -20,6 +20,7 @@ use csv::StringRecord;
 #[derive(Default)]
 pub struct RowData {
 	data: StringRecord,
+	headers: Option<StringRecord>,
 }
 
 impl RowData {
@@ -62,6 +63,58 @@ impl RowData {
 			))
 	}
 
+	/// Returns the value of the column with the supplied header name. The
+	/// headers are only available if they were read with `set_has_headers`.
+	///
+	/// # Examples
+	/// ```
+	/// use std::io::{self, Cursor};
+	///
+	/// use kwik::file::{
+	///     FileReader,
+	///     csv::{CsvReader, ReadRow, RowData},
+	/// };
+	///
+	/// struct Latency {
+	///     value: u32,
+	/// }
+	///
+	/// impl ReadRow for Latency {
+	///     fn from_row(row: &RowData) -> io::Result<Self> {
+	///         let value = row
+	///             .get_by_name("latency_ms")?
+	///             .parse::<u32>()
+	///             .map_err(|_| io::Error::from(io::ErrorKind::InvalidData))?;
+	///
+	///         Ok(Latency { value })
+	///     }
+	/// }
+	///
+	/// let data = Cursor::new("host,latency_ms\na,12\n");
+	///
+	/// let mut reader = CsvReader::<Latency>::from_reader(data)
+	///     .unwrap()
+	///     .with_has_headers()
+	///     .unwrap();
+	///
+	/// assert_eq!(reader.read_row().unwrap().value, 12);
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the headers were not read or
+	/// if there is no column with the supplied name.
+	#[inline]
+	pub fn get_by_name(&self, name: &str) -> io::Result<&str> {
+		let index = self.column_index(name)
+			.ok_or(io::Error::new(
+				io::ErrorKind::InvalidData,
+				format!("Invalid CSV column {name}"),
+			))?;
+
+		self.get(index)
+	}
+
 	/// Adds a new column to the end of the row.
 	#[inline]
 	pub fn push<T>(&mut self, value: T)
@@ -72,7 +125,18 @@ impl RowData {
 	}
 }
 
+impl RowData {
+	fn column_index(&self, name: &str) -> Option<usize> {
+		self.headers
+			.as_ref()?
+			.iter()
+			.position(|header| header == name)
+	}
+}
+
 pub use crate::file::csv::{
-	reader::{CsvReader, ReadRow, Iter, IntoIter},
+	reader::{CsvReader, ReadRow, Iter, TryIter, IntoIter, DeserializeIter},
 	writer::{CsvWriter, WriteRow},
 };
+
+pub use csv::{QuoteStyle, Terminator};
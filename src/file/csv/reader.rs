@@ -8,14 +8,17 @@
 use std::{
 	path::Path,
 	fs::File,
-	io::{self, Seek, SeekFrom},
+	io::{self, Read, Seek, SeekFrom},
 	marker::PhantomData,
 };
 
 use csv::{Reader, ReaderBuilder};
+use serde::de::DeserializeOwned;
 
 use crate::file::{
 	FileReader,
+	ReadStream,
+	stream_len,
 	csv::RowData,
 };
 
@@ -24,7 +27,8 @@ pub struct CsvReader<T>
 where
 	T: ReadRow,
 {
-	file: Reader<File>,
+	file: Reader<Box<dyn ReadStream>>,
+	size: u64,
 	buf: RowData,
 	count: u64,
 
@@ -74,6 +78,13 @@ where
 	reader: &'a mut CsvReader<T>,
 }
 
+pub struct TryIter<'a, T>
+where
+	T: ReadRow,
+{
+	reader: &'a mut CsvReader<T>,
+}
+
 pub struct IntoIter<T>
 where
 	T: ReadRow,
@@ -81,6 +92,16 @@ where
 	reader: CsvReader<T>,
 }
 
+pub struct DeserializeIter<'a, T, D>
+where
+	T: ReadRow,
+	D: DeserializeOwned,
+{
+	reader: &'a mut CsvReader<T>,
+
+	_marker: PhantomData<D>,
+}
+
 impl<T> FileReader for CsvReader<T>
 where
 	T: ReadRow,
@@ -93,16 +114,20 @@ where
 		CsvReader::from_file(File::open(path)?)
 	}
 
-	fn from_file(file: File) -> io::Result<Self>
+	fn from_reader<R>(mut reader: R) -> io::Result<Self>
 	where
 		Self: Sized,
+		R: 'static + Read + Seek + Send,
 	{
+		let size = stream_len(&mut reader).unwrap_or(0);
+
 		let reader = ReaderBuilder::new()
 			.has_headers(false)
-			.from_reader(file);
+			.from_reader(Box::new(reader) as Box<dyn ReadStream>);
 
 		let reader = CsvReader {
 			file: reader,
+			size,
 			buf: RowData::default(),
 			count: 0,
 
@@ -114,12 +139,7 @@ where
 
 	#[inline]
 	fn size(&self) -> u64 {
-		let metadata = self.file
-			.get_ref()
-			.metadata()
-			.expect("Could not get CSV file's size");
-
-		metadata.len()
+		self.size
 	}
 }
 
@@ -186,6 +206,7 @@ where
 			));
 		}
 
+		self.buf.headers = Some(self.buf.data.clone());
 		self.count += 1;
 
 		Ok(())
@@ -230,6 +251,48 @@ where
 		Ok(self)
 	}
 
+	/// Returns the index of the column with the supplied header name, or
+	/// `None` if the headers were not read or there is no such column.
+	///
+	/// # Examples
+	/// ```
+	/// use std::io::{self, Cursor};
+	///
+	/// use kwik::file::{
+	///     FileReader,
+	///     csv::{CsvReader, ReadRow, RowData},
+	/// };
+	///
+	/// let data = Cursor::new("host,latency_ms\na,12\n");
+	///
+	/// let reader = CsvReader::<MyStruct>::from_reader(data)
+	///     .unwrap()
+	///     .with_has_headers()
+	///     .unwrap();
+	///
+	/// assert_eq!(reader.column_index("latency_ms"), Some(1));
+	/// assert_eq!(reader.column_index("missing"), None);
+	///
+	/// struct MyStruct {
+	///     // data fields
+	///     data: u32,
+	/// }
+	///
+	/// impl ReadRow for MyStruct {
+	///     fn from_row(row: &RowData) -> io::Result<Self>
+	///     where
+	///         Self: Sized,
+	///     {
+	///         // parse the row and return an instance of `Self` on success
+	///         Ok(MyStruct { data: 0 })
+	///     }
+	/// }
+	/// ```
+	#[inline]
+	pub fn column_index(&self, name: &str) -> Option<usize> {
+		self.buf.column_index(name)
+	}
+
 	/// Reads one row of the CSV file and returns an option containing
 	/// the parsed row. If the end of the file is reached, `None` is returned.
 	///
@@ -269,6 +332,94 @@ where
 	/// This function will return an error if the row could not be read.
 	#[inline]
 	pub fn read_row(&mut self) -> io::Result<T> {
+		self.read_record()?;
+
+		let row = T::from_row(&self.buf)?;
+		Ok(row)
+	}
+
+	/// Returns an iterator which deserializes each remaining row of the CSV
+	/// file into the supplied type using `serde`. If the headers were read,
+	/// struct fields are matched to columns by name; otherwise, they are
+	/// matched by position. Unlike `iter`, rows which could not be
+	/// deserialized are returned as errors.
+	///
+	/// # Examples
+	/// ```
+	/// use std::io::{self, Cursor};
+	/// use serde::Deserialize;
+	///
+	/// use kwik::file::{
+	///     FileReader,
+	///     csv::{CsvReader, ReadRow, RowData},
+	/// };
+	///
+	/// #[derive(Deserialize)]
+	/// struct Request {
+	///     host: String,
+	///     latency_ms: u32,
+	/// }
+	///
+	/// let data = Cursor::new("latency_ms,host\n12,a\n");
+	///
+	/// let mut reader = CsvReader::<MyStruct>::from_reader(data)
+	///     .unwrap()
+	///     .with_has_headers()
+	///     .unwrap();
+	///
+	/// let requests = reader
+	///     .deserialize::<Request>()
+	///     .collect::<io::Result<Vec<_>>>()
+	///     .unwrap();
+	///
+	/// assert_eq!(requests[0].latency_ms, 12);
+	///
+	/// struct MyStruct {
+	///     // data fields
+	///     data: u32,
+	/// }
+	///
+	/// impl ReadRow for MyStruct {
+	///     fn from_row(row: &RowData) -> io::Result<Self>
+	///     where
+	///         Self: Sized,
+	///     {
+	///         // parse the row and return an instance of `Self` on success
+	///         Ok(MyStruct { data: 0 })
+	///     }
+	/// }
+	/// ```
+	#[inline]
+	pub fn deserialize<D>(&mut self) -> DeserializeIter<'_, T, D>
+	where
+		D: DeserializeOwned,
+	{
+		DeserializeIter {
+			reader: self,
+
+			_marker: PhantomData,
+		}
+	}
+
+	fn read_deserialized<D>(&mut self) -> io::Result<D>
+	where
+		D: DeserializeOwned,
+	{
+		self.read_record()?;
+
+		self.buf.data
+			.deserialize::<D>(self.buf.headers.as_ref())
+			.map_err(|err| {
+				let message = format!(
+					"An error occurred on row {} when deserializing CSV file: {err}",
+					self.count,
+				);
+
+				io::Error::new(io::ErrorKind::InvalidData, message)
+			})
+	}
+
+	fn read_record(&mut self) -> io::Result<()> {
 		self.buf.data.clear();
 
 		let result = self.file
@@ -291,8 +442,7 @@ where
 
 		self.count += 1;
 
-		let row = T::from_row(&self.buf)?;
-		Ok(row)
+		Ok(())
 	}
 
 	/// Returns an iterator over the CSV file. The iterator takes a mutable
@@ -330,11 +480,61 @@ where
 	/// }
 	/// ```
 	#[inline]
-	pub fn iter(&mut self) -> Iter<T> {
+	pub fn iter(&mut self) -> Iter<'_, T> {
 		Iter {
 			reader: self
 		}
 	}
+
+	/// Returns an iterator over the CSV file which yields each row as a
+	/// `Result`. Unlike `iter`, rows which could not be read or parsed are
+	/// returned as errors rather than panicking, so they can be skipped or
+	/// logged. The iterator ends at the end of the file.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use std::io;
+	///
+	/// use kwik::file::{
+	///     FileReader,
+	///     csv::{CsvReader, ReadRow, RowData},
+	/// };
+	///
+	/// let mut reader = CsvReader::<MyStruct>::from_path("/path/to/file").unwrap();
+	///
+	/// for result in reader.try_iter() {
+	///     match result {
+	///         Ok(object) => {
+	///             // do something with the object
+	///         },
+	///
+	///         Err(err) => {
+	///             // handle the malformed row
+	///         },
+	///     }
+	/// }
+	///
+	/// struct MyStruct {
+	///     // data fields
+	///     data: u32,
+	/// }
+	///
+	/// impl ReadRow for MyStruct {
+	///     fn from_row(row: &RowData) -> io::Result<Self>
+	///     where
+	///         Self: Sized,
+	///     {
+	///         // parse the row and return an instance of `Self` on success
+	///         Ok(MyStruct { data: 0 })
+	///     }
+	/// }
+	/// ```
+	#[inline]
+	pub fn try_iter(&mut self) -> TryIter<'_, T> {
+		TryIter {
+			reader: self
+		}
+	}
 }
 
 impl<T> Seek for CsvReader<T>
@@ -342,7 +542,7 @@ where
 	T: ReadRow,
 {
 	fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
-		self.file.get_ref().seek(pos)
+		self.file.get_mut().seek(pos)
 	}
 }
 
@@ -365,6 +565,20 @@ where
 	}
 }
 
+impl<T> Iterator for TryIter<'_, T>
+where
+	T: ReadRow,
+{
+	type Item = io::Result<T>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.reader.read_row() {
+			Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => None,
+			result => Some(result),
+		}
+	}
+}
+
 impl<T> IntoIterator for CsvReader<T>
 where
 	T: ReadRow,
@@ -397,3 +611,148 @@ where
 		}
 	}
 }
+
+impl<T, D> Iterator for DeserializeIter<'_, T, D>
+where
+	T: ReadRow,
+	D: DeserializeOwned,
+{
+	type Item = io::Result<D>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.reader.read_deserialized() {
+			Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => None,
+			result => Some(result),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io::{self, Cursor};
+	use serde::Deserialize;
+
+	use crate::file::{
+		FileReader,
+		csv::{CsvReader, ReadRow, RowData},
+	};
+
+	struct Request {
+		host: String,
+		latency_ms: u32,
+	}
+
+	impl ReadRow for Request {
+		fn from_row(row: &RowData) -> io::Result<Self> {
+			let latency_ms = row
+				.get_by_name("latency_ms")?
+				.parse::<u32>()
+				.map_err(|_| io::Error::from(io::ErrorKind::InvalidData))?;
+
+			let request = Request {
+				host: row.get_by_name("host")?.to_string(),
+				latency_ms,
+			};
+
+			Ok(request)
+		}
+	}
+
+	#[test]
+	fn it_reads_columns_by_name() {
+		let data = Cursor::new("latency_ms,status,host\n12,200,a\n30,500,b\n");
+
+		let mut reader = CsvReader::<Request>::from_reader(data)
+			.unwrap()
+			.with_has_headers()
+			.unwrap();
+
+		assert_eq!(reader.column_index("host"), Some(2));
+		assert_eq!(reader.column_index("latency_ms"), Some(0));
+		assert_eq!(reader.column_index("missing"), None);
+
+		let requests = reader.iter().collect::<Vec<_>>();
+
+		assert_eq!(requests.len(), 2);
+
+		assert_eq!(requests[0].host, "a");
+		assert_eq!(requests[0].latency_ms, 12);
+		assert_eq!(requests[1].host, "b");
+		assert_eq!(requests[1].latency_ms, 30);
+	}
+
+	#[test]
+	fn it_rejects_names_without_headers() {
+		let data = Cursor::new("latency_ms,host\n12,a\n");
+		let mut reader = CsvReader::<Request>::from_reader(data).unwrap();
+
+		assert_eq!(reader.column_index("host"), None);
+		assert!(reader.read_row().is_err());
+	}
+
+	#[derive(Deserialize)]
+	struct Response {
+		host: String,
+		status: u16,
+		latency_ms: Option<f64>,
+	}
+
+	#[test]
+	fn it_deserializes_rows() {
+		let data = Cursor::new("latency_ms,status,host\n12.5,200,a\n,500,b\n");
+
+		let mut reader = CsvReader::<Request>::from_reader(data)
+			.unwrap()
+			.with_has_headers()
+			.unwrap();
+
+		let responses = reader
+			.deserialize::<Response>()
+			.collect::<io::Result<Vec<_>>>()
+			.unwrap();
+
+		assert_eq!(responses.len(), 2);
+
+		assert_eq!(responses[0].host, "a");
+		assert_eq!(responses[0].status, 200);
+		assert_eq!(responses[0].latency_ms, Some(12.5));
+		assert_eq!(responses[1].host, "b");
+		assert_eq!(responses[1].status, 500);
+		assert_eq!(responses[1].latency_ms, None);
+	}
+
+	#[test]
+	fn it_returns_deserialize_errors() {
+		let data = Cursor::new("a,200,1\nb,invalid,2\n");
+		let mut reader = CsvReader::<Request>::from_reader(data).unwrap();
+
+		let results = reader
+			.deserialize::<(String, u16, f64)>()
+			.collect::<Vec<_>>();
+
+		assert_eq!(results.len(), 2);
+		assert_eq!(results[0].as_ref().unwrap().1, 200);
+		assert_eq!(results[1].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+	}
+
+	#[test]
+	fn it_returns_row_errors() {
+		let data = Cursor::new("latency_ms,host\n12,a\nslow,b\n30,c,extra\n45,d\n");
+
+		let mut reader = CsvReader::<Request>::from_reader(data)
+			.unwrap()
+			.with_has_headers()
+			.unwrap();
+
+		let results = reader.try_iter().collect::<Vec<_>>();
+
+		assert_eq!(results.len(), 4);
+
+		assert_eq!(results[0].as_ref().unwrap().host, "a");
+		assert!(results[1].as_ref().is_err_and(|err| err.kind() == io::ErrorKind::InvalidData));
+		assert!(results[2].as_ref().is_err_and(|err| err.kind() == io::ErrorKind::InvalidData));
+		assert_eq!(results[3].as_ref().unwrap().latency_ms, 45);
+
+		assert!(reader.try_iter().next().is_none());
+	}
+}
@@ -8,15 +8,17 @@
 use std::{
 	path::Path,
 	fs::File,
-	io::{self, Seek, SeekFrom},
+	io::{self, Write, Seek, SeekFrom, Cursor},
 	fmt::Display,
 	marker::PhantomData,
+	mem,
 };
 
-use csv::Writer;
+use csv::{Writer, WriterBuilder, QuoteStyle, Terminator};
 
 use crate::file::{
 	FileWriter,
+	WriteStream,
 	csv::RowData,
 };
 
@@ -25,7 +27,8 @@ pub struct CsvWriter<T>
 where
 	T: WriteRow,
 {
-	file: Writer<File>,
+	file: Writer<Box<dyn WriteStream>>,
+	builder: WriterBuilder,
 	buf: RowData,
 	count: u64,
 
@@ -75,14 +78,17 @@ where
 		CsvWriter::from_file(File::create(path)?)
 	}
 
-	fn from_file(file: File) -> io::Result<Self>
+	fn from_writer<W>(writer: W) -> io::Result<Self>
 	where
 		Self: Sized,
+		W: 'static + Write + Seek + Send,
 	{
-		let file = Writer::from_writer(file);
+		let builder = WriterBuilder::new();
+		let file = builder.from_writer(Box::new(writer) as Box<dyn WriteStream>);
 
 		let writer = CsvWriter {
 			file,
+			builder,
 			buf: RowData::default(),
 			count: 0,
 
@@ -101,6 +107,240 @@ impl<T> CsvWriter<T>
 where
 	T: WriteRow,
 {
+	/// Sets the field delimiter (e.g., `b'\t'` for tab-separated values)
+	/// of the writer. The default delimiter is a comma.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use std::io;
+	///
+	/// use kwik::file::{
+	///     FileWriter,
+	///     csv::{CsvWriter, WriteRow, RowData},
+	/// };
+	///
+	/// let mut writer = CsvWriter::<MyStruct>::from_path("/path/to/file.tsv").unwrap();
+	///
+	/// writer.set_delimiter(b'\t').unwrap();
+	///
+	/// struct MyStruct {
+	///     // data fields
+	///     data: u32,
+	/// }
+	///
+	/// impl WriteRow for MyStruct {
+	///     fn as_row(&self, row: &mut RowData) -> io::Result<()>
+	///     where
+	///         Self: Sized,
+	///     {
+	///         row.push(self.data);
+	///         Ok(())
+	///     }
+	/// }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if a row has already been written.
+	pub fn set_delimiter(&mut self, delimiter: u8) -> io::Result<()> {
+		self.rebuild(|builder| builder.delimiter(delimiter))
+	}
+
+	/// Returns the writer with its field delimiter set, which is useful
+	/// when opening and configuring a writer in one expression.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use std::io;
+	///
+	/// use kwik::file::{
+	///     FileWriter,
+	///     csv::{CsvWriter, WriteRow, RowData},
+	/// };
+	///
+	/// let writer = CsvWriter::<MyStruct>::from_path("/path/to/file.tsv").unwrap()
+	///     .with_delimiter(b'\t').unwrap();
+	///
+	/// struct MyStruct {
+	///     // data fields
+	///     data: u32,
+	/// }
+	///
+	/// impl WriteRow for MyStruct {
+	///     fn as_row(&self, row: &mut RowData) -> io::Result<()>
+	///     where
+	///         Self: Sized,
+	///     {
+	///         row.push(self.data);
+	///         Ok(())
+	///     }
+	/// }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if a row has already been written.
+	pub fn with_delimiter(mut self, delimiter: u8) -> io::Result<Self> {
+		self.set_delimiter(delimiter)?;
+		Ok(self)
+	}
+
+	/// Sets when the writer wraps fields in quotes. By default, fields are
+	/// only quoted when necessary (e.g., when they contain the delimiter).
+	///
+	/// # Examples
+	/// ```no_run
+	/// use std::io;
+	///
+	/// use kwik::file::{
+	///     FileWriter,
+	///     csv::{CsvWriter, WriteRow, RowData, QuoteStyle},
+	/// };
+	///
+	/// let mut writer = CsvWriter::<MyStruct>::from_path("/path/to/file").unwrap();
+	///
+	/// writer.set_quote_style(QuoteStyle::Always).unwrap();
+	///
+	/// struct MyStruct {
+	///     // data fields
+	///     data: u32,
+	/// }
+	///
+	/// impl WriteRow for MyStruct {
+	///     fn as_row(&self, row: &mut RowData) -> io::Result<()>
+	///     where
+	///         Self: Sized,
+	///     {
+	///         row.push(self.data);
+	///         Ok(())
+	///     }
+	/// }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if a row has already been written.
+	pub fn set_quote_style(&mut self, quote_style: QuoteStyle) -> io::Result<()> {
+		self.rebuild(|builder| builder.quote_style(quote_style))
+	}
+
+	/// Returns the writer with its quote style set, which is useful when
+	/// opening and configuring a writer in one expression.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use std::io;
+	///
+	/// use kwik::file::{
+	///     FileWriter,
+	///     csv::{CsvWriter, WriteRow, RowData, QuoteStyle},
+	/// };
+	///
+	/// let writer = CsvWriter::<MyStruct>::from_path("/path/to/file").unwrap()
+	///     .with_quote_style(QuoteStyle::Always).unwrap();
+	///
+	/// struct MyStruct {
+	///     // data fields
+	///     data: u32,
+	/// }
+	///
+	/// impl WriteRow for MyStruct {
+	///     fn as_row(&self, row: &mut RowData) -> io::Result<()>
+	///     where
+	///         Self: Sized,
+	///     {
+	///         row.push(self.data);
+	///         Ok(())
+	///     }
+	/// }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if a row has already been written.
+	pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> io::Result<Self> {
+		self.set_quote_style(quote_style)?;
+		Ok(self)
+	}
+
+	/// Sets the terminator the writer ends each row with. By default,
+	/// rows are terminated by `\n`.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use std::io;
+	///
+	/// use kwik::file::{
+	///     FileWriter,
+	///     csv::{CsvWriter, WriteRow, RowData, Terminator},
+	/// };
+	///
+	/// let mut writer = CsvWriter::<MyStruct>::from_path("/path/to/file").unwrap();
+	///
+	/// writer.set_terminator(Terminator::CRLF).unwrap();
+	///
+	/// struct MyStruct {
+	///     // data fields
+	///     data: u32,
+	/// }
+	///
+	/// impl WriteRow for MyStruct {
+	///     fn as_row(&self, row: &mut RowData) -> io::Result<()>
+	///     where
+	///         Self: Sized,
+	///     {
+	///         row.push(self.data);
+	///         Ok(())
+	///     }
+	/// }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if a row has already been written.
+	pub fn set_terminator(&mut self, terminator: Terminator) -> io::Result<()> {
+		self.rebuild(|builder| builder.terminator(terminator))
+	}
+
+	/// Returns the writer with its row terminator set, which is useful
+	/// when opening and configuring a writer in one expression.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use std::io;
+	///
+	/// use kwik::file::{
+	///     FileWriter,
+	///     csv::{CsvWriter, WriteRow, RowData, Terminator},
+	/// };
+	///
+	/// let writer = CsvWriter::<MyStruct>::from_path("/path/to/file").unwrap()
+	///     .with_terminator(Terminator::CRLF).unwrap();
+	///
+	/// struct MyStruct {
+	///     // data fields
+	///     data: u32,
+	/// }
+	///
+	/// impl WriteRow for MyStruct {
+	///     fn as_row(&self, row: &mut RowData) -> io::Result<()>
+	///     where
+	///         Self: Sized,
+	///     {
+	///         row.push(self.data);
+	///         Ok(())
+	///     }
+	/// }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if a row has already been written.
+	pub fn with_terminator(mut self, terminator: Terminator) -> io::Result<Self> {
+		self.set_terminator(terminator)?;
+		Ok(self)
+	}
+
 	/// Adds a header row to the CSV file.
 	///
 	/// # Examples
@@ -256,11 +496,174 @@ where
 	}
 }
 
+impl<T> CsvWriter<T>
+where
+	T: WriteRow,
+{
+	/// Applies the supplied option to the builder and recreates the inner
+	/// writer with it, around the same stream.
+	fn rebuild<F>(&mut self, f: F) -> io::Result<()>
+	where
+		F: FnOnce(&mut WriterBuilder) -> &mut WriterBuilder,
+	{
+		if self.count > 0 {
+			return Err(io::Error::new(
+				io::ErrorKind::InvalidData,
+				"CSV options can only be set before the first row",
+			));
+		}
+
+		f(&mut self.builder);
+		self.with_stream(|_| Ok(()))
+	}
+
+	/// Flushes the inner writer and calls the supplied function with its
+	/// stream, after which the inner writer is recreated around the stream.
+	/// The CSV writer only exposes a shared reference to its stream, so it
+	/// must be taken out of the writer in order to be modified.
+	fn with_stream<F, R>(&mut self, f: F) -> io::Result<R>
+	where
+		F: FnOnce(&mut Box<dyn WriteStream>) -> io::Result<R>,
+	{
+		// flushing first means a failure leaves the inner writer in place
+		self.file.flush()?;
+
+		let placeholder = Writer::from_writer(Box::new(Cursor::new(Vec::new())) as Box<dyn WriteStream>);
+
+		let mut stream = match mem::replace(&mut self.file, placeholder).into_inner() {
+			Ok(stream) => stream,
+
+			Err(err) => {
+				let error = io::Error::new(err.error().kind(), err.error().to_string());
+
+				self.file = err.into_inner();
+				return Err(error);
+			},
+		};
+
+		let result = f(&mut stream);
+		self.file = self.builder.from_writer(stream);
+
+		result
+	}
+}
+
 impl<T> Seek for CsvWriter<T>
 where
 	T: WriteRow,
 {
 	fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
-		self.file.get_ref().seek(pos)
+		self.with_stream(|stream| stream.seek(pos))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		io::{self, Cursor, Write, Seek, SeekFrom},
+		sync::{
+			Arc,
+			Mutex,
+			atomic::{AtomicBool, Ordering},
+		},
+	};
+
+	use crate::file::{
+		FileWriter,
+		csv::{CsvWriter, WriteRow, RowData, QuoteStyle, Terminator},
+	};
+
+	// the flag makes writes fail, to simulate a stream which cannot be flushed
+	#[derive(Clone, Default)]
+	struct SharedBuf(Arc<Mutex<Cursor<Vec<u8>>>>, Arc<AtomicBool>);
+
+	impl Write for SharedBuf {
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+			if self.1.load(Ordering::Relaxed) {
+				return Err(io::Error::other("write failed"));
+			}
+
+			self.0.lock().unwrap().write(buf)
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	impl Seek for SharedBuf {
+		fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+			self.0.lock().unwrap().seek(pos)
+		}
+	}
+
+	struct Item {
+		name: &'static str,
+		value: u32,
+	}
+
+	impl WriteRow for Item {
+		fn as_row(&self, row: &mut RowData) -> io::Result<()> {
+			row.push(self.name);
+			row.push(self.value);
+
+			Ok(())
+		}
+	}
+
+	fn written(buf: &SharedBuf) -> String {
+		String::from_utf8(buf.0.lock().unwrap().get_ref().clone()).unwrap()
+	}
+
+	#[test]
+	fn it_writes_with_configured_quoting() {
+		let buf = SharedBuf::default();
+
+		let mut writer = CsvWriter::<Item>::from_writer(buf.clone())
+			.unwrap()
+			.with_quote_style(QuoteStyle::Always)
+			.unwrap();
+
+		writer.set_headers(&["name", "value"]).unwrap();
+		writer.write_row(&Item { name: "a", value: 1 }).unwrap();
+		writer.flush().unwrap();
+
+		assert_eq!(written(&buf), "\"name\",\"value\"\n\"a\",\"1\"\n");
+
+		assert!(writer.set_delimiter(b';').is_err());
+	}
+
+	#[test]
+	fn it_writes_with_configured_delimiters_and_terminators() {
+		let buf = SharedBuf::default();
+
+		let mut writer = CsvWriter::<Item>::from_writer(buf.clone())
+			.unwrap()
+			.with_delimiter(b'\t')
+			.unwrap()
+			.with_terminator(Terminator::CRLF)
+			.unwrap();
+
+		writer.write_row(&Item { name: "a,b", value: 1 }).unwrap();
+		writer.flush().unwrap();
+
+		assert_eq!(written(&buf), "a,b\t1\r\n");
+	}
+
+	#[test]
+	fn it_keeps_the_stream_when_a_seek_fails_to_flush() {
+		let buf = SharedBuf::default();
+		let mut writer = CsvWriter::<Item>::from_writer(buf.clone()).unwrap();
+
+		writer.write_row(&Item { name: "a", value: 1 }).unwrap();
+
+		buf.1.store(true, Ordering::Relaxed);
+		assert!(writer.seek(SeekFrom::Start(0)).is_err());
+
+		buf.1.store(false, Ordering::Relaxed);
+		writer.write_row(&Item { name: "b", value: 2 }).unwrap();
+		writer.flush().unwrap();
+
+		assert_eq!(written(&buf), "a,1\nb,2\n");
 	}
 }
@@ -10,10 +10,24 @@ pub mod text;
 pub mod csv;
 pub mod hash;
 
+mod walk;
+mod atomic;
+
 use std::{
-	io,
 	path::Path,
 	fs::File,
+	io::{
+		self,
+		Read,
+		Write,
+		Seek,
+		SeekFrom,
+	},
+};
+
+pub use crate::file::{
+	walk::{walk, Walk},
+	atomic::AtomicWriter,
 };
 
 pub trait FileReader {
@@ -30,9 +44,52 @@ pub trait FileReader {
 	fn from_file(file: File) -> io::Result<Self>
 	where
 		Self: Sized,
-	;
+	{
+		Self::from_reader(file)
+	}
 
-	/// Returns the number of bytes in the opened file.
+	/// Opens the reader with the supplied stream, such as a network stream
+	/// or an in-memory `Cursor`.
+	///
+	/// # Examples
+	/// ```
+	/// use std::io::Cursor;
+	///
+	/// use kwik::file::{
+	///     FileReader,
+	///     binary::BinaryReader,
+	/// };
+	///
+	/// let data = [1u32, 2, 3]
+	///     .iter()
+	///     .flat_map(|value| value.to_le_bytes())
+	///     .collect::<Vec<u8>>();
+	///
+	/// let reader = BinaryReader::<u32>::from_reader(Cursor::new(data)).unwrap();
+	///
+	/// assert_eq!(reader.size(), 12);
+	/// assert_eq!(reader.into_iter().collect::<Vec<u32>>(), vec![1, 2, 3]);
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the reader could not be
+	/// opened. The default implementation returns an `Unsupported` error
+	/// for readers which can only be opened with a file.
+	fn from_reader<R>(_reader: R) -> io::Result<Self>
+	where
+		Self: Sized,
+		R: 'static + Read + Seek + Send,
+	{
+		Err(io::Error::new(
+			io::ErrorKind::Unsupported,
+			"the reader cannot be opened with a stream",
+		))
+	}
+
+	/// Returns the number of bytes in the opened file or stream at the
+	/// time it was opened. If the length of the stream could not be
+	/// determined, returns 0.
 	fn size(&self) -> u64;
 }
 
@@ -51,9 +108,137 @@ pub trait FileWriter {
 	fn from_file(file: File) -> io::Result<Self>
 	where
 		Self: Sized,
-	;
+	{
+		Self::from_writer(file)
+	}
+
+	/// Opens a writer to a temporary file next to the supplied path. The
+	/// temporary file replaces the file at the path only when the returned
+	/// writer is committed, so a crash or error mid-write leaves the
+	/// existing file untouched. If the writer is dropped without being
+	/// committed, the temporary file is removed.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::{
+	///     FileWriter,
+	///     text::TextWriter,
+	/// };
+	///
+	/// let mut writer = TextWriter::from_path_atomic("/path/to/file").unwrap();
+	///
+	/// writer.write_line(b"data").unwrap();
+	/// writer.commit().unwrap();
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the temporary file could not
+	/// be created.
+	fn from_path_atomic<P>(path: P) -> io::Result<AtomicWriter<Self>>
+	where
+		Self: Sized,
+		P: AsRef<Path>,
+	{
+		AtomicWriter::new(path)
+	}
+
+	/// Opens the writer with the supplied stream, such as an in-memory
+	/// `Cursor`.
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the writer could not be
+	/// opened. The default implementation returns an `Unsupported` error
+	/// for writers which can only be opened with a file.
+	fn from_writer<W>(_writer: W) -> io::Result<Self>
+	where
+		Self: Sized,
+		W: 'static + Write + Seek + Send,
+	{
+		Err(io::Error::new(
+			io::ErrorKind::Unsupported,
+			"the writer cannot be opened with a stream",
+		))
+	}
 
 	/// Flushes the current buffer to the file. If the buffer could not
 	/// be flushed, returns an error result.
 	fn flush(&mut self) -> io::Result<()>;
 }
+
+pub(crate) trait ReadStream: Read + Seek + Send {}
+impl<T> ReadStream for T where T: Read + Seek + Send {}
+
+pub(crate) trait WriteStream: Write + Seek + Send {}
+impl<T> WriteStream for T where T: Write + Seek + Send {}
+
+/// Returns the length of the supplied stream without changing its
+/// position, or `None` if the stream could not be seeked.
+fn stream_len(stream: &mut impl Seek) -> Option<u64> {
+	let position = stream.stream_position().ok()?;
+	let len = stream.seek(SeekFrom::End(0)).ok()?;
+
+	stream.seek(SeekFrom::Start(position)).ok()?;
+
+	Some(len)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		fs::File,
+		io::{self, Cursor},
+		path::Path,
+	};
+
+	use crate::file::{FileReader, FileWriter};
+
+	struct FileOnlyReader;
+	struct FileOnlyWriter;
+
+	impl FileReader for FileOnlyReader {
+		fn from_path<P>(path: P) -> io::Result<Self>
+		where
+			Self: Sized,
+			P: AsRef<Path>,
+		{
+			Self::from_file(File::open(path)?)
+		}
+
+		fn from_file(_file: File) -> io::Result<Self> {
+			Ok(FileOnlyReader)
+		}
+
+		fn size(&self) -> u64 {
+			0
+		}
+	}
+
+	impl FileWriter for FileOnlyWriter {
+		fn from_path<P>(path: P) -> io::Result<Self>
+		where
+			Self: Sized,
+			P: AsRef<Path>,
+		{
+			Self::from_file(File::create(path)?)
+		}
+
+		fn from_file(_file: File) -> io::Result<Self> {
+			Ok(FileOnlyWriter)
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn it_does_not_require_stream_constructors() {
+		let err = FileOnlyReader::from_reader(Cursor::new(Vec::<u8>::new())).err().unwrap();
+		assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+
+		let err = FileOnlyWriter::from_writer(Cursor::new(Vec::<u8>::new())).err().unwrap();
+		assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+	}
+}
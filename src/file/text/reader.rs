@@ -12,18 +12,28 @@ use std::{
 		self,
 		BufReader,
 		BufRead,
+		Read,
 		Seek,
 		SeekFrom,
 	},
 };
 
-use crate::file::FileReader;
+use crate::file::{
+	FileReader,
+	ReadStream,
+	stream_len,
+};
+
+const TAIL_CHUNK_SIZE: u64 = 8192;
 
 /// Reads a text file line-by-line.
 pub struct TextReader {
-	file: BufReader<File>,
+	file: BufReader<Box<dyn ReadStream>>,
+	size: u64,
 	buf: String,
+	bytes: Vec<u8>,
 	count: u64,
+	lossy_utf8: bool,
 }
 
 pub struct Iter<'a>
@@ -44,14 +54,20 @@ impl FileReader for TextReader {
 		TextReader::from_file(File::open(path)?)
 	}
 
-	fn from_file(file: File) -> io::Result<Self>
+	fn from_reader<R>(mut reader: R) -> io::Result<Self>
 	where
 		Self: Sized,
+		R: 'static + Read + Seek + Send,
 	{
+		let size = stream_len(&mut reader).unwrap_or(0);
+
 		let reader = TextReader {
-			file: BufReader::new(file),
+			file: BufReader::new(Box::new(reader) as Box<dyn ReadStream>),
+			size,
 			buf: String::new(),
+			bytes: Vec::new(),
 			count: 0,
+			lossy_utf8: false,
 		};
 
 		Ok(reader)
@@ -59,16 +75,51 @@ impl FileReader for TextReader {
 
 	#[inline]
 	fn size(&self) -> u64 {
-		let metadata = self.file
-			.get_ref()
-			.metadata()
-			.expect("Could not get text file's size");
-
-		metadata.len()
+		self.size
 	}
 }
 
 impl TextReader {
+	/// Decodes lines lossily, replacing invalid UTF-8 sequences with
+	/// `U+FFFD` rather than returning an error. By default, lines which are
+	/// not valid UTF-8 cannot be read.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::{
+	///     FileReader,
+	///     text::TextReader,
+	/// };
+	///
+	/// let mut reader = TextReader::from_path("/path/to/file").unwrap();
+	///
+	/// reader.set_lossy_utf8();
+	/// ```
+	#[inline]
+	pub fn set_lossy_utf8(&mut self) {
+		self.lossy_utf8 = true;
+	}
+
+	/// Decodes lines lossily, replacing invalid UTF-8 sequences with
+	/// `U+FFFD` rather than returning an error. By default, lines which are
+	/// not valid UTF-8 cannot be read.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::{
+	///     FileReader,
+	///     text::TextReader,
+	/// };
+	///
+	/// let reader = TextReader::from_path("/path/to/file").unwrap()
+	///     .with_lossy_utf8();
+	/// ```
+	#[inline]
+	pub fn with_lossy_utf8(mut self) -> Self {
+		self.set_lossy_utf8();
+		self
+	}
+
 	/// Reads one line of the text file and returns a `Result` containing
 	/// the line. If the end of the file is reached, an `io::Error` is returned.
 	///
@@ -90,13 +141,18 @@ impl TextReader {
 	///
 	/// # Errors
 	///
-	/// This function will return an error if the line could not be read.
+	/// This function will return an error if the line could not be read
+	/// or, unless lossy decoding is set, the line is not valid UTF-8.
 	#[inline]
 	pub fn read_line(&mut self) -> io::Result<String> {
 		self.buf.clear();
 
-		self.file
-			.read_line(&mut self.buf)
+		let result = match self.lossy_utf8 {
+			true => self.read_line_lossy(),
+			false => self.file.read_line(&mut self.buf),
+		};
+
+		result
 			.and_then(|buf_size| {
 				if buf_size == 0 {
 					return Err(io::Error::new(
@@ -119,6 +175,100 @@ impl TextReader {
 			})
 	}
 
+	fn read_line_lossy(&mut self) -> io::Result<usize> {
+		self.bytes.clear();
+
+		let buf_size = self.file.read_until(b'\n', &mut self.bytes)?;
+		self.buf.push_str(&String::from_utf8_lossy(&self.bytes));
+
+		Ok(buf_size)
+	}
+
+	/// Reads the last `n` lines of the text file and returns them in order.
+	/// The file is scanned backward from its end in chunks, so only the
+	/// tail of the file is read. Lines are split the same way as `read_line`.
+	/// After reading, the reader is positioned at the end of the file.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::file::{
+	///     FileReader,
+	///     text::TextReader,
+	/// };
+	///
+	/// let mut reader = TextReader::from_path("/path/to/file").unwrap();
+	///
+	/// for line in reader.read_lines_from_end(10).unwrap() {
+	///     // do something with the line
+	/// }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the file could not be read
+	/// or, unless lossy decoding is set, the lines are not valid UTF-8.
+	pub fn read_lines_from_end(&mut self, n: usize) -> io::Result<Vec<String>> {
+		let end = self.file.seek(SeekFrom::End(0))?;
+
+		if n == 0 || end == 0 {
+			return Ok(Vec::new());
+		}
+
+		let mut pos = end;
+		let mut tail = Vec::<u8>::new();
+		let mut newline_count = 0;
+
+		// a final newline terminates the last line rather than starting a
+		// new one, so it is not counted as a line separator
+		while pos > 0 && newline_count < n {
+			let chunk_size = TAIL_CHUNK_SIZE.min(pos);
+			pos -= chunk_size;
+
+			let mut chunk = vec![0u8; chunk_size as usize];
+
+			self.file.seek(SeekFrom::Start(pos))?;
+			self.file.read_exact(&mut chunk)?;
+
+			let content = match pos + chunk_size == end {
+				true => chunk.strip_suffix(b"\n").unwrap_or(&chunk),
+				false => &chunk,
+			};
+
+			newline_count += content.iter().filter(|byte| **byte == b'\n').count();
+
+			chunk.append(&mut tail);
+			tail = chunk;
+		}
+
+		self.file.seek(SeekFrom::End(0))?;
+
+		let tail = tail.strip_suffix(b"\n").unwrap_or(&tail);
+		let mut lines = tail.split(|byte| *byte == b'\n').collect::<Vec<_>>();
+
+		// the first line is only partially read if the start of the file
+		// was not reached
+		if pos > 0 {
+			lines.remove(0);
+		}
+
+		let skip = lines.len().saturating_sub(n);
+
+		lines
+			.into_iter()
+			.skip(skip)
+			.map(|line| {
+				let line = line.strip_suffix(b"\r").unwrap_or(line);
+
+				if self.lossy_utf8 {
+					return Ok(String::from_utf8_lossy(line).into_owned());
+				}
+
+				String::from_utf8(line.to_vec())
+					.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+			})
+			.collect()
+	}
+
 	/// Returns an iterator over the text file. The iterator takes a mutable
 	/// reference to `self` as it is iterating over a stream. This means performing
 	/// the iteration modifies the reader's position in the file.
@@ -139,7 +289,7 @@ impl TextReader {
 	/// }
 	/// ```
 	#[inline]
-	pub fn iter(&mut self) -> Iter {
+	pub fn iter(&mut self) -> Iter<'_> {
 		Iter {
 			reader: self
 		}
@@ -194,3 +344,85 @@ impl Iterator for IntoIter {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		env,
+		fs,
+		io,
+		path::PathBuf,
+	};
+
+	use crate::file::{
+		FileReader,
+		text::TextReader,
+	};
+
+	fn write_temp_file(name: &str, contents: &str) -> PathBuf {
+		let path = env::temp_dir().join(format!("kwik_{name}_{}.txt", std::process::id()));
+		fs::write(&path, contents).unwrap();
+
+		path
+	}
+
+	#[test]
+	fn it_reads_lines_from_end() {
+		let path = write_temp_file("tail", "one\ntwo\r\nthree\n\nfive\n");
+		let mut reader = TextReader::from_path(&path).unwrap();
+
+		assert_eq!(reader.read_lines_from_end(2).unwrap(), vec!["", "five"]);
+		assert_eq!(reader.read_lines_from_end(3).unwrap(), vec!["three", "", "five"]);
+		assert_eq!(reader.read_lines_from_end(10).unwrap(), vec!["one", "two", "three", "", "five"]);
+		assert!(reader.read_lines_from_end(0).unwrap().is_empty());
+		assert!(reader.read_line().is_err());
+
+		fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn it_reads_lines_from_end_across_chunks() {
+		let contents = (0..5000)
+			.map(|index| format!("line {index}"))
+			.collect::<Vec<_>>()
+			.join("\r\n");
+
+		let path = write_temp_file("tail_chunks", &contents);
+		let mut reader = TextReader::from_path(&path).unwrap();
+
+		let lines = reader.read_lines_from_end(2000).unwrap();
+
+		assert_eq!(lines.len(), 2000);
+		assert_eq!(lines[0], "line 3000");
+		assert_eq!(lines[1999], "line 4999");
+
+		assert_eq!(reader.read_lines_from_end(5001).unwrap().len(), 5000);
+
+		fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn it_reads_invalid_utf8_lossily() {
+		let path = env::temp_dir().join(format!("kwik_lossy_{}.txt", std::process::id()));
+		fs::write(&path, b"caf\xe9\r\nok\n").unwrap();
+
+		let mut reader = TextReader::from_path(&path).unwrap();
+
+		assert!(reader
+			.read_line()
+			.is_err_and(|err| err.kind() == io::ErrorKind::InvalidData));
+
+		let mut reader = TextReader::from_path(&path)
+			.unwrap()
+			.with_lossy_utf8();
+
+		assert_eq!(reader.read_line().unwrap(), "caf\u{fffd}");
+		assert_eq!(reader.read_line().unwrap(), "ok");
+		assert!(reader.read_line().is_err());
+
+		assert_eq!(reader.read_lines_from_end(1).unwrap(), vec!["ok"]);
+		assert_eq!(reader.read_lines_from_end(2).unwrap()[0], "caf\u{fffd}");
+
+		fs::remove_file(path).unwrap();
+	}
+}
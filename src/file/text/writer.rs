@@ -17,11 +17,14 @@ use std::{
 	},
 };
 
-use crate::file::FileWriter;
+use crate::file::{
+	FileWriter,
+	WriteStream,
+};
 
 /// Writes a text file line-by-line.
 pub struct TextWriter {
-	file: LineWriter<File>,
+	file: LineWriter<Box<dyn WriteStream>>,
 	count: u64,
 }
 
@@ -34,12 +37,13 @@ impl FileWriter for TextWriter {
 		TextWriter::from_file(File::create(path)?)
 	}
 
-	fn from_file(file: File) -> io::Result<Self>
+	fn from_writer<W>(writer: W) -> io::Result<Self>
 	where
 		Self: Sized,
+		W: 'static + Write + Seek + Send,
 	{
 		let writer = TextWriter {
-			file: LineWriter::new(file),
+			file: LineWriter::new(Box::new(writer) as Box<dyn WriteStream>),
 			count: 0,
 		};
 
@@ -80,6 +84,6 @@ impl TextWriter {
 
 impl Seek for TextWriter {
 	fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
-		self.file.get_ref().seek(pos)
+		self.file.get_mut().seek(pos)
 	}
 }
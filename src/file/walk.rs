@@ -0,0 +1,208 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::{
+	fs::{self, ReadDir},
+	path::{Path, PathBuf},
+	io,
+};
+
+/// Recursively walks a directory, yielding the paths of the files whose
+/// names match a glob pattern.
+pub struct Walk {
+	pattern: Vec<char>,
+
+	root: Option<PathBuf>,
+	stack: Vec<ReadDir>,
+}
+
+/// Returns an iterator over the paths of the files in the supplied
+/// directory and its subdirectories whose names match the supplied
+/// pattern. In the pattern, `*` matches any sequence of characters and
+/// `?` matches any single character. The pattern is matched against the
+/// file name only, not the full path. Paths are yielded in the order the
+/// directories are read, which is platform-dependent.
+///
+/// # Examples
+/// ```no_run
+/// use kwik::file::{
+///     self,
+///     FileReader,
+///     text::TextReader,
+/// };
+///
+/// for path in file::walk("/path/to/dir", "*.txt") {
+///     let reader = TextReader::from_path(path.unwrap()).unwrap();
+///     // do something with the reader
+/// }
+/// ```
+///
+/// # Errors
+///
+/// The iterator yields an error if a directory could not be read, and
+/// continues walking the remaining directories.
+pub fn walk<P>(dir: P, pattern: &str) -> Walk
+where
+	P: AsRef<Path>,
+{
+	Walk {
+		pattern: pattern.chars().collect(),
+
+		root: Some(dir.as_ref().to_path_buf()),
+		stack: Vec::new(),
+	}
+}
+
+impl Iterator for Walk {
+	type Item = io::Result<PathBuf>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if let Some(root) = self.root.take() {
+			match fs::read_dir(root) {
+				Ok(entries) => self.stack.push(entries),
+				Err(err) => return Some(Err(err)),
+			}
+		}
+
+		while let Some(entries) = self.stack.last_mut() {
+			let Some(entry) = entries.next() else {
+				self.stack.pop();
+				continue;
+			};
+
+			let entry = match entry {
+				Ok(entry) => entry,
+				Err(err) => return Some(Err(err)),
+			};
+
+			let file_type = match entry.file_type() {
+				Ok(file_type) => file_type,
+				Err(err) => return Some(Err(err)),
+			};
+
+			if file_type.is_dir() {
+				match fs::read_dir(entry.path()) {
+					Ok(entries) => self.stack.push(entries),
+					Err(err) => return Some(Err(err)),
+				}
+
+				continue;
+			}
+
+			let name = entry.file_name();
+
+			if matches_pattern(&self.pattern, &name.to_string_lossy()) {
+				return Some(Ok(entry.path()));
+			}
+		}
+
+		None
+	}
+}
+
+/// Checks if the supplied name matches the glob pattern, backtracking
+/// to the most recent `*` on a mismatch.
+fn matches_pattern(pattern: &[char], name: &str) -> bool {
+	let name = name.chars().collect::<Vec<_>>();
+
+	let mut pattern_index = 0;
+	let mut name_index = 0;
+	let mut backtrack: Option<(usize, usize)> = None;
+
+	while name_index < name.len() {
+		match pattern.get(pattern_index) {
+			Some('*') => {
+				backtrack = Some((pattern_index, name_index));
+				pattern_index += 1;
+				continue;
+			},
+
+			Some(c) if *c == '?' || *c == name[name_index] => {
+				pattern_index += 1;
+				name_index += 1;
+				continue;
+			},
+
+			_ => {},
+		}
+
+		// the last `*` consumes one more character and matching resumes
+		// after it
+		match backtrack {
+			Some((star_index, star_name_index)) => {
+				backtrack = Some((star_index, star_name_index + 1));
+				pattern_index = star_index + 1;
+				name_index = star_name_index + 1;
+			},
+
+			None => return false,
+		}
+	}
+
+	pattern[pattern_index..]
+		.iter()
+		.all(|c| *c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		env,
+		fs,
+		path::PathBuf,
+	};
+
+	use crate::file::{self, walk::matches_pattern};
+
+	#[test]
+	fn it_matches_patterns() {
+		let pattern = |pattern: &str| pattern.chars().collect::<Vec<_>>();
+
+		assert!(matches_pattern(&pattern("*.csv"), "trace.csv"));
+		assert!(matches_pattern(&pattern("*.csv"), ".csv"));
+		assert!(matches_pattern(&pattern("trace_?.*"), "trace_1.bin"));
+		assert!(matches_pattern(&pattern("*a*b"), "xaxxab"));
+		assert!(matches_pattern(&pattern("*"), ""));
+
+		assert!(!matches_pattern(&pattern("*.csv"), "trace.csv.gz"));
+		assert!(!matches_pattern(&pattern("trace_?.*"), "trace_10.bin"));
+		assert!(!matches_pattern(&pattern("*a*b"), "xaxxa"));
+	}
+
+	#[test]
+	fn it_walks_directories() {
+		let dir = env::temp_dir().join(format!("kwik_walk_{}", std::process::id()));
+
+		fs::create_dir_all(dir.join("nested/deeper")).unwrap();
+		fs::create_dir_all(dir.join("empty.csv")).unwrap();
+
+		for path in ["a.csv", "b.txt", "nested/c.csv", "nested/d.bin", "nested/deeper/e.csv"] {
+			fs::write(dir.join(path), "").unwrap();
+		}
+
+		let mut paths = file::walk(&dir, "*.csv")
+			.map(|path| path.unwrap())
+			.map(|path| path.strip_prefix(&dir).unwrap().to_path_buf())
+			.collect::<Vec<_>>();
+
+		paths.sort();
+
+		let expected_paths = ["a.csv", "nested/c.csv", "nested/deeper/e.csv"]
+			.into_iter()
+			.map(PathBuf::from)
+			.collect::<Vec<_>>();
+
+		assert_eq!(paths, expected_paths);
+
+		let mut walk = file::walk(dir.join("missing"), "*");
+
+		assert!(walk.next().unwrap().is_err());
+		assert!(walk.next().is_none());
+
+		fs::remove_dir_all(dir).unwrap();
+	}
+}
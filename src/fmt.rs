@@ -107,3 +107,87 @@ pub fn timespan(value: impl AsPrimitive<u64>) -> String {
 
 	formatted
 }
+
+/// Formats a number as an ordinal (e.g., 1st, 2nd, 3rd, 11th).
+///
+/// # Examples
+/// ```
+/// use kwik::fmt;
+///
+/// assert_eq!(fmt::ordinal(1), "1st");
+/// assert_eq!(fmt::ordinal(12), "12th");
+/// assert_eq!(fmt::ordinal(23), "23rd");
+/// ```
+#[must_use]
+pub fn ordinal(value: impl AsPrimitive<u64>) -> String {
+	let value = value.as_();
+
+	// 11, 12, and 13 are exceptions to the last digit rule
+	let suffix = match (value % 10, value % 100) {
+		(_, 11..=13) => "th",
+		(1, _) => "st",
+		(2, _) => "nd",
+		(3, _) => "rd",
+		_ => "th",
+	};
+
+	format!("{value}{suffix}")
+}
+
+/// Formats a count with the singular or plural form of a word, using
+/// the singular form only when the count is one.
+///
+/// # Examples
+/// ```
+/// use kwik::fmt;
+///
+/// assert_eq!(fmt::pluralize(1, "row", "rows"), "1 row");
+/// assert_eq!(fmt::pluralize(2, "row", "rows"), "2 rows");
+/// ```
+#[must_use]
+pub fn pluralize(value: impl AsPrimitive<u64>, singular: &str, plural: &str) -> String {
+	let value = value.as_();
+
+	match value {
+		1 => format!("{value} {singular}"),
+		_ => format!("{value} {plural}"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::fmt;
+
+	#[test]
+	fn it_formats_ordinals() {
+		let cases = [
+			(0, "0th"),
+			(1, "1st"),
+			(2, "2nd"),
+			(3, "3rd"),
+			(4, "4th"),
+			(11, "11th"),
+			(12, "12th"),
+			(13, "13th"),
+			(21, "21st"),
+			(22, "22nd"),
+			(101, "101st"),
+			(111, "111th"),
+			(113, "113th"),
+		];
+
+		for (value, expected) in cases {
+			assert_eq!(fmt::ordinal(value), expected);
+		}
+	}
+
+	#[test]
+	fn it_pluralizes() {
+		assert_eq!(fmt::pluralize(0, "item", "items"), "0 items");
+		assert_eq!(fmt::pluralize(1, "item", "items"), "1 item");
+		assert_eq!(fmt::pluralize(2, "item", "items"), "2 items");
+		assert_eq!(fmt::pluralize(11, "child", "children"), "11 children");
+		assert_eq!(fmt::pluralize(21, "row", "rows"), "21 rows");
+		assert_eq!(fmt::pluralize(113, "row", "rows"), "113 rows");
+	}
+}
@@ -130,12 +130,25 @@ where
 	#[must_use]
 	fn get(&self, index: usize) -> &Self::Gene;
 
-	/// Returns true if the chromosome is valid.
+	/// Returns true if the chromosome is valid. If variable length chromosomes
+	/// are enabled in the genetic runner, chromosomes of any length may be
+	/// created, so this should also reject unacceptable lengths.
 	#[must_use]
 	fn is_valid(&self) -> bool {
 		true
 	}
 
+	/// Returns the chromosome's fitness as a scalar, where a lower value is
+	/// more fit. If every chromosome in a population returns a value, the
+	/// population is sorted by these values, each computed once per
+	/// generation, rather than by repeatedly calling `fitness_cmp`. This
+	/// is worthwhile when `fitness_cmp` is expensive. The values must order
+	/// chromosomes the same way `fitness_cmp` does.
+	#[must_use]
+	fn fitness_value(&self) -> Option<f64> {
+		None
+	}
+
 	/// Returns true if the chromosome produces an optimal result.
 	/// This will stop the genetic algorithm.
 	#[must_use]
@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::ops::Range;
+use rand::Rng;
+
+/// The strategy used to combine the genes of two parents when mating.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum CrossoverStrategy {
+	/// Each gene is taken from either parent at random.
+	#[default]
+	Uniform,
+
+	/// Genes before a random index are taken from the first parent and
+	/// genes from the index onward are taken from the second parent.
+	SinglePoint,
+
+	/// Genes between two random indexes are taken from the second parent
+	/// and the remaining genes are taken from the first parent.
+	TwoPoint,
+}
+
+impl CrossoverStrategy {
+	/// Returns the range of gene indexes taken from the second parent,
+	/// or `None` if each gene's parent is chosen individually. With fewer
+	/// than two genes there is no point to cross over at, so every gene is
+	/// taken from the first parent.
+	pub(crate) fn gen_range(&self, rng: &mut impl Rng, len: usize) -> Option<Range<usize>> {
+		match self {
+			CrossoverStrategy::Uniform => None,
+			_ if len < 2 => Some(0..0),
+			CrossoverStrategy::SinglePoint => Some(rng.random_range(0..len)..len),
+
+			CrossoverStrategy::TwoPoint => {
+				let start = rng.random_range(0..=len);
+				let end = rng.random_range(0..=len);
+
+				Some(start.min(end)..start.max(end))
+			},
+		}
+	}
+}
@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -15,14 +16,44 @@ pub enum GeneticError {
 	#[error("empty initial chromosome")]
 	EmptyInitialChromosome,
 
+	#[error("invalid initial population")]
+	InvalidInitialPopulation,
+
 	#[error("invalid population size")]
 	InvalidPopulationSize,
 
-	#[error("could not create valid initial population")]
-	InitialPopulationTimeout,
+	#[error("invalid mutation probability")]
+	InvalidMutationProbability,
+
+	#[error("invalid parent bias")]
+	InvalidParentBias,
+
+	#[error("invalid tournament size")]
+	InvalidTournamentSize,
+
+	#[error("invalid island count")]
+	InvalidIslandCount,
+
+	#[error("invalid migration interval")]
+	InvalidMigrationInterval,
+
+	#[error("population has not been initialized")]
+	Uninitialized,
+
+	#[error("could not create valid initial population after {attempts} attempts in {elapsed:?}")]
+	InitialPopulationTimeout {
+		elapsed: Duration,
+		attempts: u64,
+	},
+
+	#[error("could not create valid offspring after {attempts} attempts in {elapsed:?}")]
+	MateTimeout {
+		elapsed: Duration,
+		attempts: u64,
+	},
 
-	#[error("could not create valid offspring")]
-	MateTimeout,
+	#[error("the run was cancelled")]
+	Cancelled,
 
 	#[error("an internal error occurred")]
 	Internal,
@@ -49,3 +49,29 @@ pub trait FitnessOrd {
 	/// ```
 	fn fitness_cmp(&self, other: &Self) -> Fitness;
 }
+
+/// This allows for ranking chromosomes by several competing objectives
+/// (e.g., cost and latency) rather than a single fitness. It is only used
+/// when Pareto ranking is enabled on the genetic runner.
+pub trait MultiFitness {
+	/// Returns the chromosome's value of each objective, where a lower
+	/// value is more fit. Every chromosome must return the same number
+	/// of objectives in the same order.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::genetic::MultiFitness;
+	///
+	/// struct MyConfig {
+	///     cost: u32,
+	///     latency: u32,
+	/// }
+	///
+	/// impl MultiFitness for MyConfig {
+	///     fn objectives(&self) -> Vec<f64> {
+	///         vec![self.cost as f64, self.latency as f64]
+	///     }
+	/// }
+	/// ```
+	fn objectives(&self) -> Vec<f64>;
+}
@@ -7,6 +7,8 @@
 
 use std::{
 	cmp::Ordering,
+	ops::Range,
+	sync::atomic::{self, AtomicBool},
 	time::{Duration, Instant},
 };
 
@@ -20,6 +22,7 @@ use crate::genetic::{
 	chromosome::{Chromosome, Gene},
 	fitness::Fitness,
 	offspring::Offspring,
+	crossover::CrossoverStrategy,
 };
 
 #[derive(Clone)]
@@ -50,36 +53,82 @@ where
 		self.chromosome.is_optimal()
 	}
 
+	/// Mates with the partner to create an offspring. If `variable_length`
+	/// is set, the offspring's length is between the lengths of the parents
+	/// and may shrink or grow by one gene through mutation. Otherwise, the
+	/// offspring has the same length as the parents. With uniform crossover,
+	/// each inherited gene is taken from this individual with probability
+	/// `parent_bias`. Offspring are created until one is valid, the max
+	/// runtime is reached, or the supplied cancel token is set.
+	#[allow(clippy::too_many_arguments)]
 	pub fn mate(
 		&self,
 		rng: &mut impl Rng,
 		partner: &Individual<C>,
+		crossover_strategy: CrossoverStrategy,
 		mutation_probability: f64,
+		parent_bias: f64,
+		variable_length: bool,
 		max_runtime: &Duration,
+		cancel: Option<&AtomicBool>,
 	) -> Result<Offspring<C>, GeneticError> {
 		let time = Instant::now();
 		let mut mutations = 0u64;
+		let mut attempts = 0u64;
 
 		let mut child_chromosome = self.chromosome.base();
-		let mut child_genes = vec![None; self.chromosome.len()];
+		let mut child_genes = Vec::<Option<C::Gene>>::new();
 
 		loop {
-			if time.elapsed().ge(max_runtime) {
-				return Err(GeneticError::MateTimeout);
+			if cancel.is_some_and(|cancel| cancel.load(atomic::Ordering::Relaxed)) {
+				return Err(GeneticError::Cancelled);
 			}
 
-			let mut gene_indexes = (0..self.chromosome.len()).collect::<Vec<_>>();
+			let elapsed = time.elapsed();
+
+			if elapsed.ge(max_runtime) {
+				return Err(GeneticError::MateTimeout {
+					elapsed,
+					attempts,
+				});
+			}
+
+			let child_len = match variable_length {
+				true => {
+					let min_len = self.chromosome.len().min(partner.chromosome.len());
+					let max_len = self.chromosome.len().max(partner.chromosome.len());
+
+					rng.random_range(min_len..=max_len)
+				},
+
+				false => self.chromosome.len(),
+			};
+
+			child_genes.clear();
+			child_genes.resize(child_len, None);
+
+			let crossover_range = crossover_strategy.gen_range(rng, child_len);
+
+			let mut gene_indexes = (0..child_len).collect::<Vec<_>>();
 			gene_indexes.shuffle(rng);
 
 			for index in gene_indexes {
-				let gene = match get_mate_result(rng, mutation_probability) {
-					MateResult::Parent1 => self.chromosome.get(index).clone(),
-					MateResult::Parent2 => partner.chromosome.get(index).clone(),
+				let mate_result = get_mate_result(
+					rng,
+					crossover_range.as_ref(),
+					index,
+					mutation_probability,
+					parent_bias,
+				);
+
+				let gene = match mate_result {
+					MateResult::Parent1 => self.gene_at(partner, index).clone(),
+					MateResult::Parent2 => partner.gene_at(self, index).clone(),
 
 					MateResult::Mutation => {
 						mutations += 1;
 
-						let mut gene = self.chromosome.get(index).clone();
+						let mut gene = self.gene_at(partner, index).clone();
 
 						gene.mutate(rng, &child_genes);
 						gene
@@ -89,6 +138,12 @@ where
 				child_genes[index] = Some(gene);
 			}
 
+			if variable_length {
+				mutations += resize_genes(rng, &mut child_genes, mutation_probability);
+			}
+
+			let child_len = child_genes.len();
+
 			for gene in child_genes.iter_mut() {
 				let gene = gene
 					.take()
@@ -97,7 +152,7 @@ where
 				child_chromosome.push(gene);
 			}
 
-			if child_chromosome.len() != self.chromosome.len() {
+			if child_chromosome.len() != child_len {
 				return Err(GeneticError::Internal);
 			}
 
@@ -105,10 +160,8 @@ where
 				break;
 			}
 
+			attempts += 1;
 			child_chromosome.clear();
-
-			child_genes.clear();
-			child_genes.resize(self.chromosome.len(), None);
 		}
 
 		let offspring = Offspring::new(
@@ -118,6 +171,15 @@ where
 
 		Ok(offspring)
 	}
+
+	/// Returns the gene at the supplied index, or the partner's gene if
+	/// this chromosome is too short to have one.
+	fn gene_at<'a>(&'a self, partner: &'a Individual<C>, index: usize) -> &'a C::Gene {
+		match index < self.chromosome.len() {
+			true => self.chromosome.get(index),
+			false => partner.chromosome.get(index),
+		}
+	}
 }
 
 impl<C> From<C> for Individual<C>
@@ -167,10 +229,61 @@ where
 	C: Chromosome,
 {}
 
-fn get_mate_result(rng: &mut impl Rng, mutation_probability: f64) -> MateResult {
+/// Randomly removes a gene from and inserts a mutated copy of a gene into
+/// the supplied genes, each with the mutation probability. A gene is not
+/// removed if it is the only one. Returns the number of genes removed or
+/// inserted.
+fn resize_genes<G>(
+	rng: &mut impl Rng,
+	genes: &mut Vec<Option<G>>,
+	mutation_probability: f64,
+) -> u64
+where
+	G: Gene,
+{
+	let mut mutations = 0u64;
+
+	if genes.len() > 1 && rng.random::<f64>() < mutation_probability {
+		genes.remove(rng.random_range(0..genes.len()));
+		mutations += 1;
+	}
+
+	if rng.random::<f64>() < mutation_probability {
+		let source_index = rng.random_range(0..genes.len());
+
+		if let Some(mut gene) = genes[source_index].clone() {
+			gene.mutate(rng, genes);
+			genes.insert(rng.random_range(0..=genes.len()), Some(gene));
+
+			mutations += 1;
+		}
+	}
+
+	mutations
+}
+
+fn get_mate_result(
+	rng: &mut impl Rng,
+	crossover_range: Option<&Range<usize>>,
+	index: usize,
+	mutation_probability: f64,
+	parent_bias: f64,
+) -> MateResult {
 	let random: f64 = rng.random();
 
-	if random < (1.0 - mutation_probability) / 2.0 {
+	if let Some(crossover_range) = crossover_range {
+		if random < mutation_probability {
+			return MateResult::Mutation;
+		}
+
+		if crossover_range.contains(&index) {
+			return MateResult::Parent2;
+		}
+
+		return MateResult::Parent1;
+	}
+
+	if random < (1.0 - mutation_probability) * parent_bias {
 		return MateResult::Parent1;
 	}
 
@@ -12,8 +12,18 @@ mod chromosome;
 mod fitness;
 mod offspring;
 mod solution;
-
-use std::time::{Duration, Instant};
+mod crossover;
+mod step;
+mod pareto;
+
+use std::{
+	slice,
+	sync::{
+		Arc,
+		atomic::{self, AtomicBool},
+	},
+	time::{Duration, Instant},
+};
 use rayon::prelude::*;
 pub use rand::Rng;
 
@@ -29,15 +39,18 @@ pub use crate::genetic::{
 	individual::Individual,
 	chromosome::Chromosome,
 	gene::Gene,
-	fitness::{Fitness, FitnessOrd},
+	fitness::{Fitness, FitnessOrd, MultiFitness},
 	offspring::Offspring,
-	solution::GeneticSolution,
+	solution::{GeneticSolution, TerminationReason},
+	crossover::CrossoverStrategy,
+	step::StepInfo,
 };
 
 const POPULATION_SIZE: usize = 100;
 const CONVERGENCE_LIMIT: u64 = 1_000;
 const MAX_RUNTIME: Duration = Duration::from_millis(10_000);
 const TOURNAMENT_SIZE: usize = 3;
+const PARENT_BIAS: f64 = 0.5;
 
 /// Finds the optimal values for a set of inputs using a genetic algorithm.
 ///
@@ -148,16 +161,33 @@ where
 	C: Chromosome + Send + Sync,
 {
 	initial_chromosome: C,
-	population: Vec<Individual<C>>,
+	initial_population: Vec<C>,
+	populations: Vec<Vec<Individual<C>>>,
+	generation: u64,
+
+	population_size: usize,
+	island_count: usize,
 
 	convergence_limit: u64,
 	max_runtime: Duration,
 	mutation_probability: f64,
+	parent_bias: f64,
 	tournament_size: usize,
+	crossover_strategy: CrossoverStrategy,
+	variable_length: bool,
+	record_history: bool,
+	cancel: Option<Arc<AtomicBool>>,
+
+	migration_interval: u64,
+	migrants: usize,
+
+	pareto_sort: Option<PopulationSort<C>>,
 
 	mating_dist: Uniform<usize>,
 }
 
+type PopulationSort<C> = fn(&mut Vec<Individual<C>>);
+
 impl<C> Genetic<C>
 where
 	C: Chromosome + Send + Sync,
@@ -173,25 +203,31 @@ where
 			return Err(GeneticError::InvalidInitialChromosome);
 		}
 
-		let mut population = vec![];
-
-		init_population(
-			&mut population,
-			POPULATION_SIZE,
-			&initial_chromosome,
-			&MAX_RUNTIME,
-		)?;
-
 		let mutation_probability = 1.0 / initial_chromosome.len() as f64;
 
 		let genetic = Genetic {
 			initial_chromosome,
-			population,
+			initial_population: Vec::new(),
+			populations: Vec::new(),
+			generation: 0,
+
+			population_size: POPULATION_SIZE,
+			island_count: 1,
 
 			convergence_limit: CONVERGENCE_LIMIT,
 			max_runtime: MAX_RUNTIME,
 			mutation_probability,
+			parent_bias: PARENT_BIAS,
 			tournament_size: TOURNAMENT_SIZE,
+			crossover_strategy: CrossoverStrategy::default(),
+			variable_length: false,
+			record_history: false,
+			cancel: None,
+
+			migration_interval: 1,
+			migrants: 0,
+
+			pareto_sort: None,
 
 			mating_dist: init_mating_dist(POPULATION_SIZE)?,
 		};
@@ -199,7 +235,9 @@ where
 		Ok(genetic)
 	}
 
-	/// Sets the population size and fills the population with individuals.
+	/// Sets the population size. When using islands, this is the size of
+	/// each island's population. Any populations created by `init` are
+	/// cleared, so `init` must be called again before `step`.
 	///
 	/// # Errors
 	///
@@ -210,19 +248,15 @@ where
 			return Err(GeneticError::InvalidPopulationSize);
 		}
 
-		init_population(
-			&mut self.population,
-			population_size,
-			&self.initial_chromosome,
-			&self.max_runtime,
-		)?;
-
+		self.population_size = population_size;
 		self.mating_dist = init_mating_dist(population_size)?;
+		self.populations.clear();
 
 		Ok(())
 	}
 
-	/// Sets the population size and fills the population with individuals.
+	/// Sets the population size. When using islands, this is the size of
+	/// each island's population.
 	///
 	/// # Errors
 	///
@@ -233,6 +267,53 @@ where
 		Ok(self)
 	}
 
+	/// Sets the chromosomes the population of each island starts with, in
+	/// place of the initial chromosome. If there are more chromosomes than
+	/// the population size, the extra chromosomes are ignored. If there are
+	/// fewer, the population is filled with mutations of the supplied
+	/// chromosomes.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if no chromosomes are supplied, or if
+	/// any chromosome is invalid or, unless variable length chromosomes are
+	/// enabled, has a different length than the initial chromosome.
+	pub fn set_initial_population(&mut self, chromosomes: Vec<C>) -> Result<(), GeneticError> {
+		let is_valid = !chromosomes.is_empty() && chromosomes
+			.iter()
+			.all(|chromosome| {
+				let is_valid_len = self.variable_length
+					|| chromosome.len() == self.initial_chromosome.len();
+
+				is_valid_len && chromosome.is_valid()
+			});
+
+		if !is_valid {
+			return Err(GeneticError::InvalidInitialPopulation);
+		}
+
+		self.initial_population = chromosomes;
+
+		Ok(())
+	}
+
+	/// Sets the chromosomes the population of each island starts with, in
+	/// place of the initial chromosome. If there are more chromosomes than
+	/// the population size, the extra chromosomes are ignored. If there are
+	/// fewer, the population is filled with mutations of the supplied
+	/// chromosomes.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if no chromosomes are supplied, or if
+	/// any chromosome is invalid or, unless variable length chromosomes are
+	/// enabled, has a different length than the initial chromosome.
+	#[inline]
+	pub fn with_initial_population(mut self, chromosomes: Vec<C>) -> Result<Self, GeneticError> {
+		self.set_initial_population(chromosomes)?;
+		Ok(self)
+	}
+
 	/// Sets the convergence.
 	#[inline]
 	pub fn set_convergence_limit(&mut self, convergence_limit: u64) {
@@ -262,90 +343,450 @@ where
 	}
 
 	/// Sets the mutation probability.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if the mutation probability is not
+	/// between 0 and 1.
 	#[inline]
-	pub fn set_mutation_probability(&mut self, mutation_probability: f64) {
+	pub fn set_mutation_probability(&mut self, mutation_probability: f64) -> Result<(), GeneticError> {
+		if !(0.0..=1.0).contains(&mutation_probability) {
+			return Err(GeneticError::InvalidMutationProbability);
+		}
+
 		self.mutation_probability = mutation_probability;
+
+		Ok(())
 	}
 
 	/// Sets the mutation probability.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if the mutation probability is not
+	/// between 0 and 1.
 	#[inline]
-	#[must_use]
-	pub fn with_mutation_probability(mut self, mutation_probability: f64) -> Self {
-		self.set_mutation_probability(mutation_probability);
-		self
+	pub fn with_mutation_probability(mut self, mutation_probability: f64) -> Result<Self, GeneticError> {
+		self.set_mutation_probability(mutation_probability)?;
+		Ok(self)
+	}
+
+	/// Sets the share of inherited genes taken from the fitter parent when
+	/// using uniform crossover. A bias of 0.5 takes genes from either
+	/// parent equally, while a bias of 1 takes every inherited gene from
+	/// the fitter parent. The default is 0.5.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if the parent bias is not between
+	/// 0 and 1.
+	#[inline]
+	pub fn set_parent_bias(&mut self, parent_bias: f64) -> Result<(), GeneticError> {
+		if !(0.0..=1.0).contains(&parent_bias) {
+			return Err(GeneticError::InvalidParentBias);
+		}
+
+		self.parent_bias = parent_bias;
+
+		Ok(())
+	}
+
+	/// Sets the share of inherited genes taken from the fitter parent when
+	/// using uniform crossover.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if the parent bias is not between
+	/// 0 and 1.
+	#[inline]
+	pub fn with_parent_bias(mut self, parent_bias: f64) -> Result<Self, GeneticError> {
+		self.set_parent_bias(parent_bias)?;
+		Ok(self)
 	}
 
 	/// Sets the tournament size.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if the tournament size is zero.
 	#[inline]
-	pub fn set_tournament_size(&mut self, tournament_size: usize) {
+	pub fn set_tournament_size(&mut self, tournament_size: usize) -> Result<(), GeneticError> {
+		if tournament_size == 0 {
+			return Err(GeneticError::InvalidTournamentSize);
+		}
+
 		self.tournament_size = tournament_size;
+
+		Ok(())
 	}
 
 	/// Sets the tournament size.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if the tournament size is zero.
+	#[inline]
+	pub fn with_tournament_size(mut self, tournament_size: usize) -> Result<Self, GeneticError> {
+		self.set_tournament_size(tournament_size)?;
+		Ok(self)
+	}
+
+	/// Sets the crossover strategy used when mating.
+	#[inline]
+	pub fn set_crossover_strategy(&mut self, crossover_strategy: CrossoverStrategy) {
+		self.crossover_strategy = crossover_strategy;
+	}
+
+	/// Sets the crossover strategy used when mating.
+	#[inline]
+	#[must_use]
+	pub fn with_crossover_strategy(mut self, crossover_strategy: CrossoverStrategy) -> Self {
+		self.set_crossover_strategy(crossover_strategy);
+		self
+	}
+
+	/// Enables or disables variable length chromosomes. When enabled, an
+	/// offspring's length is chosen between the lengths of its parents, and
+	/// mutations may also remove a gene or insert a mutated copy of a gene.
+	/// The chromosome's `is_valid` then defines the acceptable lengths, since
+	/// offspring of any length may be created. This is disabled by default,
+	/// in which case all chromosomes have the length of the initial chromosome.
+	///
+	/// Enable this before setting an initial population of chromosomes with
+	/// lengths different from the initial chromosome.
+	#[inline]
+	pub fn set_variable_length(&mut self, value: bool) {
+		self.variable_length = value;
+	}
+
+	/// Enables or disables variable length chromosomes. See
+	/// `set_variable_length` for details.
+	#[inline]
+	#[must_use]
+	pub fn with_variable_length(mut self, value: bool) -> Self {
+		self.set_variable_length(value);
+		self
+	}
+
+	/// Enables or disables recording the fittest chromosome of each
+	/// generation in the solution's history. Recording clones a
+	/// chromosome every generation, so it is disabled by default.
+	#[inline]
+	pub fn set_record_history(&mut self, value: bool) {
+		self.record_history = value;
+	}
+
+	/// Enables or disables recording the fittest chromosome of each
+	/// generation in the solution's history. Recording clones a
+	/// chromosome every generation, so it is disabled by default.
+	#[inline]
+	#[must_use]
+	pub fn with_record_history(mut self, value: bool) -> Self {
+		self.set_record_history(value);
+		self
+	}
+
+	/// Sets a token which cancels the run when it is set to `true`, such as
+	/// from another thread. The token is checked before each generation and
+	/// while retrying to create valid chromosomes. A cancelled run returns
+	/// the fittest chromosome found so far.
+	#[inline]
+	pub fn set_cancel(&mut self, token: Arc<AtomicBool>) {
+		self.cancel = Some(token);
+	}
+
+	/// Sets a token which cancels the run when it is set to `true`, such as
+	/// from another thread. The token is checked before each generation and
+	/// while retrying to create valid chromosomes. A cancelled run returns
+	/// the fittest chromosome found so far.
 	#[inline]
 	#[must_use]
-	pub fn with_tournament_size(mut self, tournament_size: usize) -> Self {
-		self.set_tournament_size(tournament_size);
+	pub fn with_cancel(mut self, token: Arc<AtomicBool>) -> Self {
+		self.set_cancel(token);
 		self
 	}
 
+	/// Splits the population into the supplied number of islands which
+	/// evolve independently. Every `migration_interval` generations, the
+	/// fittest `migrants` individuals of each island replace the weakest
+	/// individuals of the next island. Each island has a population of
+	/// the population size. Any populations created by `init` are cleared,
+	/// so `init` must be called again before `step`.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if the island count or migration
+	/// interval is zero.
+	pub fn set_islands(
+		&mut self,
+		count: usize,
+		migration_interval: u64,
+		migrants: usize,
+	) -> Result<(), GeneticError> {
+		if count == 0 {
+			return Err(GeneticError::InvalidIslandCount);
+		}
+
+		if migration_interval == 0 {
+			return Err(GeneticError::InvalidMigrationInterval);
+		}
+
+		self.island_count = count;
+		self.migration_interval = migration_interval;
+		self.migrants = migrants;
+		self.populations.clear();
+
+		Ok(())
+	}
+
+	/// Splits the population into the supplied number of islands which
+	/// evolve independently. Every `migration_interval` generations, the
+	/// fittest `migrants` individuals of each island replace the weakest
+	/// individuals of the next island. Each island has a population of
+	/// the population size.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if the island count or migration
+	/// interval is zero.
+	#[inline]
+	pub fn with_islands(
+		mut self,
+		count: usize,
+		migration_interval: u64,
+		migrants: usize,
+	) -> Result<Self, GeneticError> {
+		self.set_islands(count, migration_interval, migrants)?;
+		Ok(self)
+	}
+
+	/// Fills the population of each island with the initial population, or
+	/// the initial chromosome if none was set, and individuals mutated from
+	/// it, and resets the generation count. This must be
+	/// called before stepping through generations with `step`, and is done
+	/// at the start of `run`.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if a valid population could not be
+	/// created within the max runtime or the run was cancelled.
+	pub fn init(&mut self) -> Result<(), GeneticError> {
+		self.populations.resize_with(self.island_count, Vec::new);
+
+		let seeds = match self.initial_population.is_empty() {
+			true => slice::from_ref(&self.initial_chromosome),
+			false => &self.initial_population,
+		};
+
+		for population in &mut self.populations {
+			init_population(
+				population,
+				self.population_size,
+				seeds,
+				&self.max_runtime,
+				self.cancel.as_deref(),
+			)?;
+		}
+
+		self.generation = 0;
+
+		Ok(())
+	}
+
+	/// Creates the next generation of each island, migrating individuals
+	/// between islands if it is a migration generation. Returns the number
+	/// of mutations that occurred and the fittest chromosome across all
+	/// islands.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if the population has not been
+	/// initialized, a valid offspring could not be created, or the run
+	/// was cancelled. The populations are unchanged if an error is returned.
+	pub fn step(&mut self) -> Result<StepInfo<'_, C>, GeneticError> {
+		if self.populations.is_empty() {
+			return Err(GeneticError::Uninitialized);
+		}
+
+		let mutations = self.iterate()?;
+
+		self.generation += 1;
+		self.migrate(self.generation);
+
+		let info = StepInfo::new(
+			self.generation,
+			mutations,
+			self.fittest().chromosome(),
+		);
+
+		Ok(info)
+	}
+
+	/// Returns the fittest chromosome across all islands, or `None` if the
+	/// population has not been initialized. Together with `step`, this
+	/// allows the leader to be read between generations.
+	#[must_use]
+	pub fn best(&self) -> Option<&C> {
+		self.populations
+			.iter()
+			.filter_map(|population| population.first())
+			.min()
+			.map(Individual::chromosome)
+	}
+
+	/// Returns up to `n` of the fittest chromosomes across all islands,
+	/// fittest first. If the population has not been initialized, the
+	/// result is empty.
+	#[must_use]
+	pub fn best_n(&self, n: usize) -> Vec<&C> {
+		let mut individuals = self.populations
+			.iter()
+			.flatten()
+			.collect::<Vec<_>>();
+
+		individuals.sort();
+
+		individuals
+			.into_iter()
+			.take(n)
+			.map(Individual::chromosome)
+			.collect()
+	}
+
+	/// Returns the fraction of individuals across all islands which are
+	/// distinct, between (0, 1]. Individuals are considered equivalent if
+	/// their chromosomes are equally fit. A diversity near zero indicates
+	/// the population has stagnated, which may be countered by restarting
+	/// or increasing the mutation probability. If the population has not
+	/// been initialized, the diversity is zero.
+	#[must_use]
+	pub fn population_diversity(&self) -> f64 {
+		let mut individuals = self.populations
+			.iter()
+			.flatten()
+			.collect::<Vec<_>>();
+
+		if individuals.is_empty() {
+			return 0.0;
+		}
+
+		let total = individuals.len();
+
+		individuals.sort_unstable();
+		individuals.dedup();
+
+		individuals.len() as f64 / total as f64
+	}
+
 	/// Runs the genetic algorithm until either the most fit individual has a fitness
-	/// of 0 or the population has converged and is no longer changing.
+	/// of 0, the population has converged and is no longer changing, the max
+	/// runtime is reached, or the run is cancelled.
+	///
+	/// # Errors
+	///
+	/// This function returns an error if a valid population or offspring
+	/// could not be created, or the run was cancelled before the initial
+	/// population was created.
 	pub fn run(&mut self) -> Result<GeneticSolution<C>, GeneticError> {
 		let time = Instant::now();
 
-		let mut total_mutations = self.iterate()?;
+		self.init()?;
+
+		let mut total_mutations = 0u64;
 
-		let mut generation_count: u64 = 1;
 		let mut convergence_count: u64 = 0;
-		let mut last_fittest = self.population[0].clone();
+		let mut last_fittest = self.fittest().clone();
+		let mut history = Vec::<(u64, C)>::new();
+
+		let termination_reason = loop {
+			if self.is_cancelled() {
+				break TerminationReason::Cancelled;
+			}
 
-		while
-			!last_fittest.is_optimal()
-				&& convergence_count < self.convergence_limit
-				&& time.elapsed().lt(&self.max_runtime)
-		{
-			total_mutations += self.iterate()?;
+			total_mutations += match self.step() {
+				Ok(info) => info.mutations(),
+				Err(GeneticError::Cancelled) => break TerminationReason::Cancelled,
+				Err(err) => return Err(err),
+			};
 
-			let fittest = &self.population[0];
+			let fittest = self.fittest();
 
-			if fittest.eq(&last_fittest) {
+			if self.generation > 1 && fittest.eq(&last_fittest) {
 				convergence_count += 1;
 			} else {
 				last_fittest = fittest.clone();
 				convergence_count = 0;
 			}
 
-			generation_count += 1;
-		}
+			if self.record_history {
+				history.push((self.generation, fittest.chromosome().clone()));
+			}
+
+			if last_fittest.is_optimal() {
+				break TerminationReason::Optimal;
+			}
+
+			if convergence_count >= self.convergence_limit {
+				break TerminationReason::Converged;
+			}
+
+			if time.elapsed().ge(&self.max_runtime) {
+				break TerminationReason::Timeout;
+			}
+		};
 
 		let solution = GeneticSolution::new(
-			self.population[0].chromosome().clone(),
-			generation_count,
+			self.fittest().chromosome().clone(),
+			self.generation,
 			total_mutations,
 			time.elapsed(),
+			termination_reason,
+			history,
 		);
 
 		Ok(solution)
 	}
 
-	/// Performs one iteration of the genetic algorithm, creating a new generation
-	/// and overwriting the current population. Returns the total number of
-	/// mutations that occurred during the creation of the new generation.
+	/// Performs one iteration of the genetic algorithm on each island, creating
+	/// new generations and overwriting the current populations. Returns the total
+	/// number of mutations that occurred during the creation of the new generations.
 	fn iterate(&mut self) -> Result<u64, GeneticError> {
-		let population_size = self.population.len();
+		let new_generations = self.populations
+			.par_iter()
+			.map(|population| self.gen_generation(population))
+			.collect::<Result<Vec<_>, GeneticError>>()?;
+
+		let mut total_mutations = 0u64;
+
+		for (population, (new_generation, mutations)) in self.populations.iter_mut().zip(new_generations) {
+			*population = new_generation;
+			total_mutations += mutations;
+		}
 
-		let new_offpring = (0..population_size)
+		Ok(total_mutations)
+	}
+
+	/// Creates a new generation from the supplied population, returning it
+	/// sorted by fitness, or by Pareto front if enabled, along with the number of mutations that occurred.
+	fn gen_generation(
+		&self,
+		population: &[Individual<C>],
+	) -> Result<(Vec<Individual<C>>, u64), GeneticError> {
+		let new_offpring = (0..population.len())
 			.into_par_iter()
 			.map(|_| {
 				let mut rng = SmallRng::from_rng(&mut rand::rng());
-				let (parent1, parent2) = self.gen_mating_pair(&mut rng);
+				let (parent1, parent2) = self.gen_mating_pair(population, &mut rng);
 
 				parent1.mate(
 					&mut rng,
 					parent2,
+					self.crossover_strategy,
 					self.mutation_probability,
+					self.parent_bias,
+					self.variable_length,
 					&self.max_runtime,
+					self.cancel.as_deref(),
 				)
 			})
 			.collect::<Result<Vec<Offspring<C>>, GeneticError>>()?;
@@ -358,14 +799,65 @@ where
 			new_generation.push(offspring.into_individual());
 		}
 
-		new_generation.sort_unstable();
-		self.population = new_generation;
+		if self.pareto_sort.is_some() {
+			// parents compete with their offspring so individuals on the
+			// front are not lost between generations
+			new_generation.extend_from_slice(population);
+		}
+
+		sort_population(&mut new_generation, self.pareto_sort);
+		new_generation.truncate(population.len());
 
-		Ok(total_mutations)
+		Ok((new_generation, total_mutations))
+	}
+
+	/// Copies the fittest individuals of each island over the weakest
+	/// individuals of the next island if the supplied generation is a
+	/// migration generation.
+	fn migrate(&mut self, generation: u64) {
+		let island_count = self.populations.len();
+
+		if island_count < 2 || self.migrants == 0 || !generation.is_multiple_of(self.migration_interval) {
+			return;
+		}
+
+		let emigrants = self.populations
+			.iter()
+			.map(|population| {
+				let count = self.migrants.min(population.len());
+				population[..count].to_vec()
+			})
+			.collect::<Vec<_>>();
+
+		for (index, emigrants) in emigrants.into_iter().enumerate() {
+			let population = &mut self.populations[(index + 1) % island_count];
+			let start = population.len() - emigrants.len();
+
+			population.splice(start.., emigrants);
+			sort_population(population, self.pareto_sort);
+		}
+	}
+
+	/// Checks if the run has been cancelled through its cancel token.
+	fn is_cancelled(&self) -> bool {
+		is_cancelled(self.cancel.as_deref())
+	}
+
+	/// Returns the fittest individual across all islands.
+	fn fittest(&self) -> &Individual<C> {
+		self.populations
+			.iter()
+			.map(|population| &population[0])
+			.min()
+			.expect("Genetic has no populations.")
 	}
 
-	/// Selects two individuals to mate
-	fn gen_mating_pair(&self, rng: &mut impl Rng) -> (&Individual<C>, &Individual<C>) {
+	/// Selects two individuals to mate, the fitter of which is first.
+	fn gen_mating_pair<'a>(
+		&self,
+		population: &'a [Individual<C>],
+		rng: &mut impl Rng,
+	) -> (&'a Individual<C>, &'a Individual<C>) {
 		let index1 = self.gen_tournament_parent(rng);
 		let mut index2 = self.gen_tournament_parent(rng);
 
@@ -373,7 +865,8 @@ where
 			index2 = self.gen_tournament_parent(rng);
 		}
 
-		(&self.population[index1], &self.population[index2])
+		// the population is sorted, so the lower index is fitter
+		(&population[index1.min(index2)], &population[index1.max(index2)])
 	}
 
 	fn gen_tournament_parent(&self, rng: &mut impl Rng) -> usize {
@@ -385,24 +878,129 @@ where
 	}
 }
 
+impl<C> Genetic<C>
+where
+	C: Chromosome + MultiFitness + Send + Sync,
+{
+	/// Enables or disables ranking individuals by Pareto front using their
+	/// objectives, rather than by `fitness_cmp`. When enabled, each
+	/// generation is selected from the parents and their offspring by front,
+	/// preferring individuals in sparse regions of a front (NSGA-II). The
+	/// run's solution and convergence still compare the top ranked
+	/// individuals with `fitness_cmp`, so use `pareto_front` to get the
+	/// trade-offs found. This is disabled by default.
+	#[inline]
+	pub fn set_pareto_ranking(&mut self, value: bool) {
+		self.pareto_sort = match value {
+			true => Some(pareto::sort::<C>),
+			false => None,
+		};
+	}
+
+	/// Enables or disables ranking individuals by Pareto front. See
+	/// `set_pareto_ranking` for details.
+	#[inline]
+	#[must_use]
+	pub fn with_pareto_ranking(mut self, value: bool) -> Self {
+		self.set_pareto_ranking(value);
+		self
+	}
+
+	/// Returns the chromosomes of the individuals across all islands which
+	/// are not dominated by any other individual. Individuals with equal
+	/// objectives are only returned once. If the population has not been
+	/// initialized, this is empty.
+	#[must_use]
+	pub fn pareto_front(&self) -> Vec<C> {
+		let chromosomes = self.populations
+			.iter()
+			.flatten()
+			.map(|individual| individual.chromosome())
+			.collect::<Vec<_>>();
+
+		let objectives = chromosomes
+			.iter()
+			.map(|chromosome| chromosome.objectives())
+			.collect::<Vec<_>>();
+
+		let Some(front) = pareto::fronts(&objectives).into_iter().next() else {
+			return Vec::new();
+		};
+
+		let mut front_objectives = Vec::<&Vec<f64>>::new();
+		let mut front_chromosomes = Vec::<C>::new();
+
+		for index in front {
+			if front_objectives.contains(&&objectives[index]) {
+				continue;
+			}
+
+			front_objectives.push(&objectives[index]);
+			front_chromosomes.push(chromosomes[index].clone());
+		}
+
+		front_chromosomes
+	}
+}
+
+/// Sorts the population by fitness, or by Pareto front if the supplied
+/// Pareto sort is set. If every chromosome has a scalar fitness value,
+/// the values are computed once and used as the sort keys.
+fn sort_population<C>(
+	population: &mut Vec<Individual<C>>,
+	pareto_sort: Option<PopulationSort<C>>,
+)
+where
+	C: Chromosome,
+{
+	if let Some(sort) = pareto_sort {
+		return sort(population);
+	}
+
+	let values = population
+		.iter()
+		.map(|individual| individual.chromosome().fitness_value())
+		.collect::<Option<Vec<_>>>();
+
+	let Some(values) = values else {
+		return population.sort_unstable();
+	};
+
+	let mut keyed = values
+		.into_iter()
+		.zip(population.drain(..))
+		.collect::<Vec<_>>();
+
+	keyed.sort_unstable_by(|(value1, _), (value2, _)| value1.total_cmp(value2));
+	population.extend(keyed.into_iter().map(|(_, individual)| individual));
+}
+
+/// Fills the population with the supplied seed chromosomes, up to the
+/// population size, followed by mutations of the seeds in turn.
 fn init_population<C>(
 	population: &mut Vec<Individual<C>>,
 	population_size: usize,
-	initial_chromosome: &C,
+	seeds: &[C],
 	max_runtime: &Duration,
+	cancel: Option<&AtomicBool>,
 ) -> Result<(), GeneticError>
 where
 	C: Chromosome + Send + Sync,
 {
 	population.clear();
-	population.push(initial_chromosome.clone().into());
 
-	let mutated_population = (0..(population_size - 1))
+	population.extend(seeds
+		.iter()
+		.take(population_size)
+		.map(|chromosome| chromosome.clone().into()));
+
+	let mutated_population = (population.len()..population_size)
 		.into_par_iter()
-		.map(|_| {
+		.map(|index| {
 			let chromosome = init_mutated_chromosome(
-				initial_chromosome,
+				&seeds[index % seeds.len()],
 				max_runtime,
+				cancel,
 			)?;
 
 			Ok(chromosome.into())
@@ -417,6 +1015,7 @@ where
 fn init_mutated_chromosome<C>(
 	chromosome: &C,
 	max_runtime: &Duration,
+	cancel: Option<&AtomicBool>,
 ) -> Result<C, GeneticError>
 where
 	C: Chromosome,
@@ -425,8 +1024,13 @@ where
 
 	let mut rng = SmallRng::from_rng(&mut rand::rng());
 	let mut mutated_genes = vec![None; chromosome.len()];
+	let mut attempts = 0u64;
 
 	while time.elapsed().lt(max_runtime) {
+		if is_cancelled(cancel) {
+			return Err(GeneticError::Cancelled);
+		}
+
 		let mut gene_indexes = (0..chromosome.len()).collect::<Vec<_>>();
 		gene_indexes.shuffle(&mut rng);
 
@@ -455,11 +1059,20 @@ where
 			return Ok(mutated_chromosome);
 		}
 
+		attempts += 1;
+
 		mutated_genes.clear();
 		mutated_genes.resize(chromosome.len(), None);
 	}
 
-	Err(GeneticError::InitialPopulationTimeout)
+	Err(GeneticError::InitialPopulationTimeout {
+		elapsed: time.elapsed(),
+		attempts,
+	})
+}
+
+fn is_cancelled(cancel: Option<&AtomicBool>) -> bool {
+	cancel.is_some_and(|cancel| cancel.load(atomic::Ordering::Relaxed))
 }
 
 fn init_mating_dist(population_size: usize) -> Result<Uniform<usize>, GeneticError> {
@@ -469,12 +1082,32 @@ fn init_mating_dist(population_size: usize) -> Result<Uniform<usize>, GeneticErr
 
 #[cfg(test)]
 mod tests {
+	use std::{
+		cmp::Ordering,
+		collections::HashSet,
+		sync::{
+			Arc,
+			atomic::{AtomicBool, AtomicU64, Ordering as AtomicOrdering},
+		},
+		thread,
+		time::{Duration, Instant},
+	};
+
+	use rand::{SeedableRng, rngs::SmallRng};
+
 	use crate::genetic::{
 		Genetic,
+		GeneticError,
+		TerminationReason,
+		Individual,
+		init_mutated_chromosome,
+		sort_population,
+		CrossoverStrategy,
 		Gene,
 		Chromosome,
 		Fitness,
 		FitnessOrd,
+		MultiFitness,
 		Rng
 	};
 
@@ -553,21 +1186,189 @@ mod tests {
 		}
 	}
 
-	impl Gene for TestData {
-		fn mutate(&mut self, rng: &mut impl Rng, _genes: &[Option<Self>]) {
-			self.data = rng.random_range(0..50);
-		}
-	}
+	static CACHED_FITNESS_CMP_CALLS: AtomicU64 = AtomicU64::new(0);
 
-	#[test]
-	fn it_optimizes() {
-		let mut initial_chromosome = TestConfig::default();
+	#[derive(Clone)]
+	struct CachedTestConfig {
+		config: TestConfig,
+	}
 
-		initial_chromosome.push(TestData { data: 0 });
-		initial_chromosome.push(TestData { data: 0 });
-		initial_chromosome.push(TestData { data: 0 });
-		initial_chromosome.push(TestData { data: 0 });
-		initial_chromosome.push(TestData { data: 0 });
+	impl Chromosome for CachedTestConfig {
+		type Gene = TestData;
+
+		fn base(&self) -> Self {
+			CachedTestConfig {
+				config: self.config.base(),
+			}
+		}
+
+		fn is_empty(&self) -> bool {
+			self.config.is_empty()
+		}
+
+		fn len(&self) -> usize {
+			self.config.len()
+		}
+
+		fn push(&mut self, data: TestData) {
+			self.config.push(data);
+		}
+
+		fn get(&self, index: usize) -> &TestData {
+			self.config.get(index)
+		}
+
+		fn clear(&mut self) {
+			self.config.clear()
+		}
+
+		fn fitness_value(&self) -> Option<f64> {
+			Some((100 - self.config.sum() as i32).abs() as f64)
+		}
+
+		fn is_optimal(&self) -> bool {
+			self.config.is_optimal()
+		}
+	}
+
+	impl FitnessOrd for CachedTestConfig {
+		fn fitness_cmp(&self, other: &Self) -> Fitness {
+			CACHED_FITNESS_CMP_CALLS.fetch_add(1, AtomicOrdering::Relaxed);
+			self.config.fitness_cmp(&other.config)
+		}
+	}
+
+	#[derive(Clone)]
+	struct VariableTestConfig {
+		config: Vec<TestData>,
+	}
+
+	impl Chromosome for VariableTestConfig {
+		type Gene = TestData;
+
+		fn base(&self) -> Self {
+			VariableTestConfig {
+				config: Vec::new(),
+			}
+		}
+
+		fn is_empty(&self) -> bool {
+			self.config.is_empty()
+		}
+
+		fn len(&self) -> usize {
+			self.config.len()
+		}
+
+		fn push(&mut self, data: TestData) {
+			self.config.push(data);
+		}
+
+		fn get(&self, index: usize) -> &TestData {
+			&self.config[index]
+		}
+
+		fn clear(&mut self) {
+			self.config.clear()
+		}
+
+		fn is_valid(&self) -> bool {
+			(3..=8).contains(&self.len())
+		}
+
+		fn is_optimal(&self) -> bool {
+			false
+		}
+	}
+
+	impl FitnessOrd for VariableTestConfig {
+		fn fitness_cmp(&self, other: &Self) -> Fitness {
+			// longer chromosomes are fitter, so the population grows
+			match self.len().cmp(&other.len()) {
+				Ordering::Greater => Fitness::Stronger,
+				Ordering::Less => Fitness::Weaker,
+				Ordering::Equal => Fitness::Equal,
+			}
+		}
+	}
+
+	#[derive(Clone)]
+	struct ParetoTestConfig {
+		config: Vec<TestData>,
+	}
+
+	impl Chromosome for ParetoTestConfig {
+		type Gene = TestData;
+
+		fn base(&self) -> Self {
+			ParetoTestConfig {
+				config: Vec::new(),
+			}
+		}
+
+		fn is_empty(&self) -> bool {
+			self.config.is_empty()
+		}
+
+		fn len(&self) -> usize {
+			self.config.len()
+		}
+
+		fn push(&mut self, data: TestData) {
+			self.config.push(data);
+		}
+
+		fn get(&self, index: usize) -> &TestData {
+			&self.config[index]
+		}
+
+		fn clear(&mut self) {
+			self.config.clear()
+		}
+
+		fn is_optimal(&self) -> bool {
+			false
+		}
+	}
+
+	impl MultiFitness for ParetoTestConfig {
+		fn objectives(&self) -> Vec<f64> {
+			// the front is every chromosome whose second gene is zero
+			let first = self.config[0].data as f64;
+			let second = self.config[1].data as f64;
+
+			vec![first, 49.0 - first + second]
+		}
+	}
+
+	impl FitnessOrd for ParetoTestConfig {
+		fn fitness_cmp(&self, other: &Self) -> Fitness {
+			let self_sum = self.objectives().iter().sum::<f64>();
+			let other_sum = other.objectives().iter().sum::<f64>();
+
+			match self_sum.total_cmp(&other_sum) {
+				Ordering::Less => Fitness::Stronger,
+				Ordering::Greater => Fitness::Weaker,
+				Ordering::Equal => Fitness::Equal,
+			}
+		}
+	}
+
+	impl Gene for TestData {
+		fn mutate(&mut self, rng: &mut impl Rng, _genes: &[Option<Self>]) {
+			self.data = rng.random_range(0..50);
+		}
+	}
+
+	#[test]
+	fn it_optimizes() {
+		let mut initial_chromosome = TestConfig::default();
+
+		initial_chromosome.push(TestData { data: 0 });
+		initial_chromosome.push(TestData { data: 0 });
+		initial_chromosome.push(TestData { data: 0 });
+		initial_chromosome.push(TestData { data: 0 });
+		initial_chromosome.push(TestData { data: 0 });
 
 		let mut genetic = Genetic::<TestConfig>::new(initial_chromosome).unwrap();
 
@@ -577,4 +1378,651 @@ mod tests {
 		assert_ne!(result.mutations(), 0);
 		assert_eq!(result.chromosome().sum(), 100);
 	}
+
+	#[test]
+	fn it_optimizes_with_each_crossover_strategy() {
+		let strategies = [
+			CrossoverStrategy::Uniform,
+			CrossoverStrategy::SinglePoint,
+			CrossoverStrategy::TwoPoint,
+		];
+
+		for strategy in strategies {
+			let mut initial_chromosome = TestConfig::default();
+
+			for _ in 0..5 {
+				initial_chromosome.push(TestData { data: 0 });
+			}
+
+			let mut genetic = Genetic::<TestConfig>::new(initial_chromosome)
+				.unwrap()
+				.with_crossover_strategy(strategy);
+
+			let result = genetic.run().unwrap();
+
+			assert!(result.chromosome().is_valid());
+			assert_eq!(result.chromosome().len(), 5);
+			assert_eq!(result.chromosome().sum(), 100);
+		}
+	}
+
+	#[test]
+	fn it_crosses_over_parents_with_fewer_than_two_genes() {
+		let mut rng = SmallRng::seed_from_u64(0);
+		let max_runtime = Duration::from_secs(1);
+
+		for len in [0, 1] {
+			let mut first_chromosome = TestConfig::default();
+			let mut second_chromosome = TestConfig::default();
+
+			for _ in 0..len {
+				first_chromosome.push(TestData { data: 1 });
+				second_chromosome.push(TestData { data: 2 });
+			}
+
+			let first = Individual::from(first_chromosome);
+			let second = Individual::from(second_chromosome);
+
+			for strategy in [CrossoverStrategy::SinglePoint, CrossoverStrategy::TwoPoint] {
+				let child = first
+					.mate(&mut rng, &second, strategy, 0.0, 0.5, false, &max_runtime, None)
+					.unwrap()
+					.into_individual();
+
+				assert_eq!(child.chromosome().len(), len);
+				assert_eq!(child.chromosome().sum(), len as u32);
+			}
+		}
+	}
+
+	#[test]
+	fn it_records_history() {
+		let mut initial_chromosome = TestConfig::default();
+
+		for _ in 0..5 {
+			initial_chromosome.push(TestData { data: 0 });
+		}
+
+		let mut genetic = Genetic::<TestConfig>::new(initial_chromosome.clone()).unwrap();
+		let result = genetic.run().unwrap();
+
+		assert!(result.history().is_empty());
+
+		let mut genetic = Genetic::<TestConfig>::new(initial_chromosome)
+			.unwrap()
+			.with_record_history(true);
+
+		let result = genetic.run().unwrap();
+		let history = result.history();
+
+		assert_eq!(history.len() as u64, result.generations());
+
+		for (index, (generation, _)) in history.iter().enumerate() {
+			assert_eq!(*generation, index as u64 + 1);
+		}
+
+		let (_, last_chromosome) = history.last().unwrap();
+
+		assert_eq!(last_chromosome.sum(), result.chromosome().sum());
+
+		assert!(last_chromosome.config
+			.iter()
+			.zip(result.chromosome().config.iter())
+			.all(|(first, second)| first.data == second.data));
+	}
+
+	#[test]
+	fn it_rejects_invalid_parameters() {
+		let mut initial_chromosome = TestConfig::default();
+		initial_chromosome.push(TestData { data: 0 });
+
+		let mut genetic = Genetic::<TestConfig>::new(initial_chromosome).unwrap();
+
+		assert!(matches!(
+			genetic.set_mutation_probability(-0.1),
+			Err(GeneticError::InvalidMutationProbability),
+		));
+
+		assert!(matches!(
+			genetic.set_parent_bias(1.5),
+			Err(GeneticError::InvalidParentBias),
+		));
+
+		assert!(matches!(
+			genetic.set_mutation_probability(1.1),
+			Err(GeneticError::InvalidMutationProbability),
+		));
+
+		assert!(matches!(
+			genetic.set_mutation_probability(f64::NAN),
+			Err(GeneticError::InvalidMutationProbability),
+		));
+
+		assert!(matches!(
+			genetic.set_tournament_size(0),
+			Err(GeneticError::InvalidTournamentSize),
+		));
+
+		assert!(matches!(
+			genetic.set_population_size(0),
+			Err(GeneticError::InvalidPopulationSize),
+		));
+
+		assert!(genetic.set_mutation_probability(0.0).is_ok());
+		assert!(genetic.set_mutation_probability(1.0).is_ok());
+		assert!(genetic.set_tournament_size(1).is_ok());
+	}
+
+	#[test]
+	fn it_optimizes_with_islands() {
+		let mut initial_chromosome = TestConfig::default();
+
+		for _ in 0..5 {
+			initial_chromosome.push(TestData { data: 0 });
+		}
+
+		let mut genetic = Genetic::<TestConfig>::new(initial_chromosome)
+			.unwrap()
+			.with_population_size(25)
+			.unwrap()
+			.with_islands(4, 5, 2)
+			.unwrap()
+			.with_record_history(true);
+
+		genetic.init().unwrap();
+
+		assert_eq!(genetic.populations.len(), 4);
+		assert!(genetic.populations.iter().all(|population| population.len() == 25));
+
+		let result = genetic.run().unwrap();
+
+		assert_eq!(result.chromosome().sum(), 100);
+		assert_ne!(result.generations(), 0);
+		assert_eq!(result.history().len() as u64, result.generations());
+
+		assert!(matches!(
+			genetic.set_islands(0, 5, 2),
+			Err(GeneticError::InvalidIslandCount),
+		));
+
+		assert!(matches!(
+			genetic.set_islands(4, 0, 2),
+			Err(GeneticError::InvalidMigrationInterval),
+		));
+	}
+
+	#[test]
+	fn it_migrates_between_islands() {
+		let mut initial_chromosome = TestConfig::default();
+
+		for _ in 0..5 {
+			initial_chromosome.push(TestData { data: 0 });
+		}
+
+		let mut genetic = Genetic::<TestConfig>::new(initial_chromosome)
+			.unwrap()
+			.with_population_size(10)
+			.unwrap()
+			.with_islands(3, 2, 1)
+			.unwrap();
+
+		genetic.init().unwrap();
+
+		let fittest = genetic.populations
+			.iter()
+			.map(|population| population[0].chromosome().sum())
+			.collect::<Vec<_>>();
+
+		genetic.migrate(1);
+
+		for (index, population) in genetic.populations.iter().enumerate() {
+			assert_eq!(population[0].chromosome().sum(), fittest[index]);
+		}
+
+		genetic.migrate(2);
+
+		let diff = |sum: u32| (100 - sum as i32).abs();
+
+		for (index, population) in genetic.populations.iter().enumerate() {
+			let previous_fittest = fittest[(index + 2) % 3];
+
+			assert_eq!(population.len(), 10);
+			assert!(diff(population[0].chromosome().sum()) <= diff(previous_fittest));
+		}
+	}
+
+	#[test]
+	fn it_requires_init_after_resizing() {
+		let mut initial_chromosome = TestConfig::default();
+
+		for _ in 0..5 {
+			initial_chromosome.push(TestData { data: 0 });
+		}
+
+		let mut genetic = Genetic::<TestConfig>::new(initial_chromosome).unwrap();
+
+		genetic.init().unwrap();
+		genetic.set_population_size(200).unwrap();
+
+		assert!(matches!(genetic.step(), Err(GeneticError::Uninitialized)));
+
+		genetic.init().unwrap();
+		genetic.step().unwrap();
+
+		assert_eq!(genetic.populations[0].len(), 200);
+
+		genetic.set_islands(2, 5, 1).unwrap();
+
+		assert!(matches!(genetic.step(), Err(GeneticError::Uninitialized)));
+
+		genetic.init().unwrap();
+		genetic.step().unwrap();
+
+		assert_eq!(genetic.populations.len(), 2);
+	}
+
+	#[test]
+	fn it_steps_through_generations() {
+		let mut initial_chromosome = TestConfig::default();
+
+		for _ in 0..5 {
+			initial_chromosome.push(TestData { data: 0 });
+		}
+
+		let mut genetic = Genetic::<TestConfig>::new(initial_chromosome).unwrap();
+
+		assert!(matches!(genetic.step(), Err(GeneticError::Uninitialized)));
+		assert!(genetic.best().is_none());
+		assert!(genetic.best_n(3).is_empty());
+
+		genetic.init().unwrap();
+
+		let mut total_mutations = 0;
+
+		for generation in 1..=10 {
+			let info = genetic.step().unwrap();
+
+			assert_eq!(info.generation(), generation);
+			assert_eq!(info.chromosome().len(), 5);
+
+			total_mutations += info.mutations();
+		}
+
+		assert_ne!(total_mutations, 0);
+
+		// a run starts over from a new population
+		let result = genetic
+			.with_convergence_limit(0)
+			.run()
+			.unwrap();
+
+		assert_eq!(result.generations(), 1);
+	}
+
+	#[test]
+	fn it_measures_population_diversity() {
+		let mut initial_chromosome = TestConfig::default();
+
+		for _ in 0..5 {
+			initial_chromosome.push(TestData { data: 0 });
+		}
+
+		let mut genetic = Genetic::<TestConfig>::new(initial_chromosome).unwrap();
+
+		assert_eq!(genetic.population_diversity(), 0.0);
+
+		genetic.init().unwrap();
+		assert!(genetic.population_diversity() > 0.25);
+
+		// a converged population of identical individuals
+		let fittest = genetic.fittest().clone();
+
+		for population in &mut genetic.populations {
+			population.fill(fittest.clone());
+		}
+
+		assert_eq!(genetic.population_diversity(), 0.01);
+	}
+
+	#[test]
+	fn it_seeds_the_initial_population() {
+		let mut initial_chromosome = TestConfig::default();
+
+		for _ in 0..25 {
+			initial_chromosome.push(TestData { data: 0 });
+		}
+
+		// near-optimal chromosomes with sums of 99 and 101, each differing
+		// from the optimal chromosome of all 4s in one gene
+		let seeds = (0..50)
+			.map(|index| {
+				let mut chromosome = initial_chromosome.base();
+
+				for gene_index in 0..25 {
+					let data = match (gene_index == index % 25, index < 25) {
+						(true, true) => 3,
+						(true, false) => 5,
+						(false, _) => 4,
+					};
+
+					chromosome.push(TestData { data });
+				}
+
+				chromosome
+			})
+			.collect::<Vec<_>>();
+
+		let mut genetic = Genetic::<TestConfig>::new(initial_chromosome.clone()).unwrap();
+
+		assert!(matches!(
+			genetic.set_initial_population(Vec::new()),
+			Err(GeneticError::InvalidInitialPopulation),
+		));
+
+		assert!(matches!(
+			genetic.set_initial_population(vec![initial_chromosome.base()]),
+			Err(GeneticError::InvalidInitialPopulation),
+		));
+
+		let mut seeded_genetic = Genetic::<TestConfig>::new(initial_chromosome)
+			.unwrap()
+			.with_initial_population(seeds)
+			.unwrap();
+
+		seeded_genetic.init().unwrap();
+
+		assert_eq!(seeded_genetic.populations[0].len(), 100);
+		assert_eq!(seeded_genetic.populations[0][1].chromosome().sum(), 99);
+
+		// the mating is random, so only the seeded population itself is
+		// checked rather than how quickly it converges
+		assert!(seeded_genetic.populations[0][..50]
+			.iter()
+			.all(|individual| matches!(individual.chromosome().sum(), 99 | 101)));
+
+		assert_eq!(seeded_genetic.run().unwrap().chromosome().sum(), 100);
+	}
+
+	#[test]
+	fn it_optimizes_variable_length_chromosomes() {
+		let initial_chromosome = VariableTestConfig {
+			config: vec![TestData { data: 0 }; 4],
+		};
+
+		let mut genetic = Genetic::<VariableTestConfig>::new(initial_chromosome.clone())
+			.unwrap()
+			.with_variable_length(true)
+			.with_mutation_probability(0.5)
+			.unwrap();
+
+		genetic.init().unwrap();
+
+		let mut lengths = HashSet::<usize>::new();
+
+		for _ in 0..20 {
+			genetic.step().unwrap();
+
+			for individual in genetic.populations.iter().flatten() {
+				lengths.insert(individual.chromosome().len());
+			}
+		}
+
+		assert!(lengths.len() > 1);
+		assert!(lengths.iter().all(|len| (3..=8).contains(len)));
+		assert!((3..=8).contains(&genetic.fittest().chromosome().len()));
+
+		// without variable length chromosomes, the length never changes
+		let mut genetic = Genetic::<VariableTestConfig>::new(initial_chromosome)
+			.unwrap()
+			.with_mutation_probability(0.5)
+			.unwrap();
+
+		genetic.init().unwrap();
+
+		for _ in 0..20 {
+			genetic.step().unwrap();
+		}
+
+		assert!(genetic.populations
+			.iter()
+			.flatten()
+			.all(|individual| individual.chromosome().len() == 4));
+	}
+
+	#[test]
+	fn it_reports_timeout_attempts() {
+		// a single gene chromosome is never valid
+		let chromosome = VariableTestConfig {
+			config: vec![TestData { data: 0 }],
+		};
+
+		let max_runtime = Duration::from_millis(10);
+
+		let error = init_mutated_chromosome(&chromosome, &max_runtime, None)
+			.err()
+			.unwrap();
+
+		assert!(matches!(
+			error,
+			GeneticError::InitialPopulationTimeout { attempts, elapsed }
+				if attempts > 0 && elapsed >= max_runtime
+		));
+
+		let mut rng = SmallRng::from_rng(&mut rand::rng());
+		let individual = Individual::from(chromosome);
+
+		let error = individual
+			.mate(
+				&mut rng,
+				&individual,
+				CrossoverStrategy::default(),
+				0.5,
+				0.5,
+				false,
+				&max_runtime,
+				None,
+			)
+			.err()
+			.unwrap();
+
+		assert!(matches!(
+			error,
+			GeneticError::MateTimeout { attempts, elapsed }
+				if attempts > 0 && elapsed >= max_runtime
+		));
+
+		assert!(error.to_string().contains("attempts"));
+	}
+
+	#[test]
+	fn it_approximates_the_pareto_front() {
+		let initial_chromosome = ParetoTestConfig {
+			config: vec![TestData { data: 25 }, TestData { data: 25 }],
+		};
+
+		let mut genetic = Genetic::<ParetoTestConfig>::new(initial_chromosome)
+			.unwrap()
+			.with_pareto_ranking(true)
+			.with_convergence_limit(50);
+
+		assert!(genetic.pareto_front().is_empty());
+
+		genetic.run().unwrap();
+
+		let front = genetic.pareto_front();
+
+		let firsts = front
+			.iter()
+			.map(|chromosome| chromosome.config[0].data)
+			.collect::<HashSet<_>>();
+
+		assert!(front.iter().all(|chromosome| chromosome.config[1].data == 0));
+		assert_eq!(firsts.len(), front.len());
+		assert!(front.len() >= 25);
+	}
+
+	#[test]
+	fn it_cancels_runs() {
+		let initial_chromosome = VariableTestConfig {
+			config: vec![TestData { data: 0 }; 4],
+		};
+
+		let cancel = Arc::new(AtomicBool::new(false));
+
+		let mut genetic = Genetic::<VariableTestConfig>::new(initial_chromosome.clone())
+			.unwrap()
+			.with_convergence_limit(u64::MAX)
+			.with_max_runtime(Duration::from_secs(60))
+			.with_cancel(Arc::clone(&cancel));
+
+		let time = Instant::now();
+
+		let canceller = {
+			let cancel = Arc::clone(&cancel);
+
+			thread::spawn(move || {
+				thread::sleep(Duration::from_millis(100));
+				cancel.store(true, AtomicOrdering::Relaxed);
+			})
+		};
+
+		let solution = genetic.run().unwrap();
+		canceller.join().unwrap();
+
+		assert!(time.elapsed() < Duration::from_secs(5));
+		assert_eq!(solution.termination_reason(), TerminationReason::Cancelled);
+		assert_ne!(solution.generations(), 0);
+
+		// retrying to create valid chromosomes is also cancelled
+		let invalid_chromosome = VariableTestConfig {
+			config: vec![TestData { data: 0 }],
+		};
+
+		let max_runtime = Duration::from_secs(60);
+
+		assert!(matches!(
+			init_mutated_chromosome(&invalid_chromosome, &max_runtime, Some(&cancel)),
+			Err(GeneticError::Cancelled),
+		));
+
+		assert!(matches!(genetic.run(), Err(GeneticError::Cancelled)));
+	}
+
+	#[test]
+	fn it_sorts_by_cached_fitness_values() {
+		let mut rng = SmallRng::seed_from_u64(1);
+
+		let mut population = (0..64)
+			.map(|_| {
+				let config = (0..10)
+					.map(|_| TestData { data: rng.random_range(0..20) })
+					.collect::<Vec<_>>();
+
+				Individual::from(CachedTestConfig {
+					config: TestConfig { config },
+				})
+			})
+			.collect::<Vec<_>>();
+
+		let mut expected = population.clone();
+		expected.sort_unstable();
+
+		let uncached_calls = CACHED_FITNESS_CMP_CALLS.swap(0, AtomicOrdering::Relaxed);
+
+		sort_population(&mut population, None);
+
+		assert!(uncached_calls >= 63);
+		assert_eq!(CACHED_FITNESS_CMP_CALLS.load(AtomicOrdering::Relaxed), 0);
+
+		let values = population
+			.iter()
+			.map(|individual| individual.chromosome().fitness_value().unwrap())
+			.collect::<Vec<_>>();
+
+		let expected_values = expected
+			.iter()
+			.map(|individual| individual.chromosome().fitness_value().unwrap())
+			.collect::<Vec<_>>();
+
+		assert_eq!(values, expected_values);
+	}
+
+	#[test]
+	fn it_biases_offspring_toward_the_fitter_parent() {
+		let parent1 = Individual::from(TestConfig {
+			config: vec![TestData { data: 1 }; 10],
+		});
+
+		let parent2 = Individual::from(TestConfig {
+			config: vec![TestData { data: 9 }; 10],
+		});
+
+		let mut rng = SmallRng::seed_from_u64(0);
+		let max_runtime = Duration::from_secs(1);
+
+		let mut parent1_share = |parent_bias: f64| {
+			let mut parent1_genes = 0;
+
+			for _ in 0..200 {
+				let offspring = parent1
+					.mate(
+						&mut rng,
+						&parent2,
+						CrossoverStrategy::Uniform,
+						0.0,
+						parent_bias,
+						false,
+						&max_runtime,
+						None,
+					)
+					.unwrap();
+
+				parent1_genes += offspring
+					.into_individual()
+					.chromosome()
+					.config
+					.iter()
+					.filter(|gene| gene.data == 1)
+					.count();
+			}
+
+			parent1_genes as f64 / 2_000.0
+		};
+
+		let unbiased = parent1_share(0.5);
+		let biased = parent1_share(0.9);
+
+		assert!((unbiased - 0.5).abs() < 0.05);
+		assert!((biased - 0.9).abs() < 0.05);
+	}
+
+	#[test]
+	fn it_returns_the_best_chromosomes() {
+		let initial_chromosome = TestConfig {
+			config: vec![TestData { data: 0 }; 5],
+		};
+
+		let mut genetic = Genetic::<TestConfig>::new(initial_chromosome.clone())
+			.unwrap()
+			.with_islands(2, 1, 0)
+			.unwrap();
+
+		genetic.init().unwrap();
+		genetic.step().unwrap();
+
+		let best = genetic.best().unwrap();
+
+		assert!(!matches!(best.fitness_cmp(&initial_chromosome), Fitness::Weaker));
+
+		let best_n = genetic.best_n(5);
+
+		assert_eq!(best_n.len(), 5);
+		assert!(matches!(best_n[0].fitness_cmp(best), Fitness::Equal));
+
+		for index in 1..best_n.len() {
+			assert!(!matches!(best_n[index].fitness_cmp(best_n[index - 1]), Fitness::Stronger));
+		}
+
+		assert_eq!(genetic.best_n(usize::MAX).len(), 200);
+	}
 }
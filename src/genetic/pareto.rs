@@ -0,0 +1,186 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::genetic::{
+	chromosome::Chromosome,
+	fitness::MultiFitness,
+	individual::Individual,
+};
+
+/// Sorts the population by Pareto front, with the non-dominated front
+/// first. Within a front, individuals are sorted by descending crowding
+/// distance so those in sparse regions of the front are preferred.
+pub(crate) fn sort<C>(population: &mut Vec<Individual<C>>)
+where
+	C: Chromosome + MultiFitness,
+{
+	let objectives = population
+		.iter()
+		.map(|individual| individual.chromosome().objectives())
+		.collect::<Vec<_>>();
+
+	let mut ranks = Vec::<(usize, f64, usize)>::with_capacity(population.len());
+
+	for (rank, front) in fronts(&objectives).into_iter().enumerate() {
+		let distances = crowding_distances(&objectives, &front);
+
+		for (index, distance) in front.into_iter().zip(distances) {
+			ranks.push((rank, distance, index));
+		}
+	}
+
+	ranks.sort_by(|(rank1, distance1, _), (rank2, distance2, _)| {
+		rank1
+			.cmp(rank2)
+			.then(distance2.total_cmp(distance1))
+	});
+
+	let mut individuals = population
+		.drain(..)
+		.map(Some)
+		.collect::<Vec<_>>();
+
+	population.extend(ranks
+		.into_iter()
+		.filter_map(|(_, _, index)| individuals[index].take()));
+}
+
+/// Splits the supplied objective values into Pareto fronts, returning the
+/// indexes of the values in each front. The first front holds the values
+/// which are not dominated by any other, the second holds the values which
+/// are only dominated by those in the first, and so on.
+pub(crate) fn fronts(objectives: &[Vec<f64>]) -> Vec<Vec<usize>> {
+	let mut dominated = vec![Vec::<usize>::new(); objectives.len()];
+	let mut domination_counts = vec![0usize; objectives.len()];
+
+	for index1 in 0..objectives.len() {
+		for index2 in (index1 + 1)..objectives.len() {
+			if dominates(&objectives[index1], &objectives[index2]) {
+				dominated[index1].push(index2);
+				domination_counts[index2] += 1;
+			} else if dominates(&objectives[index2], &objectives[index1]) {
+				dominated[index2].push(index1);
+				domination_counts[index1] += 1;
+			}
+		}
+	}
+
+	let mut fronts = Vec::<Vec<usize>>::new();
+
+	let mut front = (0..objectives.len())
+		.filter(|index| domination_counts[*index] == 0)
+		.collect::<Vec<_>>();
+
+	while !front.is_empty() {
+		let mut next_front = Vec::<usize>::new();
+
+		for index in &front {
+			for dominated_index in &dominated[*index] {
+				domination_counts[*dominated_index] -= 1;
+
+				if domination_counts[*dominated_index] == 0 {
+					next_front.push(*dominated_index);
+				}
+			}
+		}
+
+		fronts.push(front);
+		front = next_front;
+	}
+
+	fronts
+}
+
+/// Checks if the first objective values dominate the second (i.e., are no
+/// worse in every objective and better in at least one).
+fn dominates(objectives1: &[f64], objectives2: &[f64]) -> bool {
+	let mut is_better = false;
+
+	for (value1, value2) in objectives1.iter().zip(objectives2) {
+		if value1 > value2 {
+			return false;
+		}
+
+		if value1 < value2 {
+			is_better = true;
+		}
+	}
+
+	is_better
+}
+
+/// Returns the crowding distance of each index in the front, which is the
+/// sum over the objectives of the normalized distance between its two
+/// neighbours. The values at the extremes of each objective have an
+/// infinite distance so they are always kept.
+fn crowding_distances(objectives: &[Vec<f64>], front: &[usize]) -> Vec<f64> {
+	let mut distances = vec![0.0; front.len()];
+
+	let Some(objective_count) = front
+		.first()
+		.map(|index| objectives[*index].len()) else {
+		return distances;
+	};
+
+	let mut positions = (0..front.len()).collect::<Vec<_>>();
+
+	let objective_values = (0..objective_count).map(|objective| {
+		front
+			.iter()
+			.map(|index| objectives[*index][objective])
+			.collect::<Vec<_>>()
+	});
+
+	for values in objective_values {
+		positions.sort_by(|position1, position2| {
+			values[*position1].total_cmp(&values[*position2])
+		});
+
+		let first = positions[0];
+		let last = positions[positions.len() - 1];
+
+		distances[first] = f64::INFINITY;
+		distances[last] = f64::INFINITY;
+
+		let range = values[last] - values[first];
+
+		if range <= 0.0 {
+			continue;
+		}
+
+		for window in positions.windows(3) {
+			distances[window[1]] += (values[window[2]] - values[window[0]]) / range;
+		}
+	}
+
+	distances
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::genetic::pareto::{fronts, crowding_distances};
+
+	#[test]
+	fn it_ranks_fronts() {
+		let objectives = vec![
+			vec![1.0, 5.0],
+			vec![2.0, 2.0],
+			vec![3.0, 3.0],
+			vec![5.0, 1.0],
+			vec![4.0, 4.0],
+			vec![2.0, 2.0],
+		];
+
+		assert_eq!(fronts(&objectives), vec![vec![0, 1, 3, 5], vec![2], vec![4]]);
+
+		let distances = crowding_distances(&objectives, &[0, 1, 3]);
+
+		assert!(distances[0].is_infinite());
+		assert!(distances[2].is_infinite());
+		assert_eq!(distances[1], 2.0);
+	}
+}
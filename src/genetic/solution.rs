@@ -8,9 +8,26 @@
 use std::time::Duration;
 use crate::genetic::chromosome::Chromosome;
 
+/// The reason a genetic run stopped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TerminationReason {
+	/// The fittest chromosome is optimal.
+	Optimal,
+
+	/// The fittest chromosome did not change for the convergence limit.
+	Converged,
+
+	/// The run reached its max runtime.
+	Timeout,
+
+	/// The run was cancelled through its cancel token.
+	Cancelled,
+}
+
 /// The solution of a genetic run. Holds the chromosome of the fittest individual,
-/// the number of generations processed during the run, and the total duration
-/// of the run.
+/// the number of generations processed during the run, the total duration
+/// of the run, the reason the run stopped, and, if recorded, the fittest
+/// chromosome of each generation.
 pub struct GeneticSolution<C>
 where
 	C: Chromosome,
@@ -21,6 +38,9 @@ where
 	mutations: u64,
 
 	runtime: Duration,
+	termination_reason: TerminationReason,
+
+	history: Vec<(u64, C)>,
 }
 
 impl<C> GeneticSolution<C>
@@ -32,6 +52,8 @@ where
 		generations: u64,
 		mutations: u64,
 		runtime: Duration,
+		termination_reason: TerminationReason,
+		history: Vec<(u64, C)>,
 	) -> Self {
 		GeneticSolution {
 			chromosome,
@@ -40,6 +62,9 @@ where
 			mutations,
 
 			runtime,
+			termination_reason,
+
+			history,
 		}
 	}
 
@@ -66,4 +91,18 @@ where
 	pub fn runtime(&self) -> Duration {
 		self.runtime
 	}
+
+	/// Returns the reason the run stopped.
+	#[inline]
+	pub fn termination_reason(&self) -> TerminationReason {
+		self.termination_reason
+	}
+
+	/// Returns the fittest chromosome of each generation, along with the
+	/// generation number. This is empty unless history recording was
+	/// enabled for the run.
+	#[inline]
+	pub fn history(&self) -> &[(u64, C)] {
+		&self.history
+	}
 }
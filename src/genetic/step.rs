@@ -0,0 +1,57 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use crate::genetic::chromosome::Chromosome;
+
+/// The result of a single genetic step. Holds the generation number, the
+/// number of mutations that occurred while creating the generation, and
+/// the chromosome of the fittest individual across all islands.
+pub struct StepInfo<'a, C>
+where
+	C: Chromosome,
+{
+	generation: u64,
+	mutations: u64,
+
+	chromosome: &'a C,
+}
+
+impl<'a, C> StepInfo<'a, C>
+where
+	C: Chromosome,
+{
+	pub fn new(
+		generation: u64,
+		mutations: u64,
+		chromosome: &'a C,
+	) -> Self {
+		StepInfo {
+			generation,
+			mutations,
+
+			chromosome,
+		}
+	}
+
+	/// Returns the number of the generation created by the step.
+	#[inline]
+	pub fn generation(&self) -> u64 {
+		self.generation
+	}
+
+	/// Returns the number of mutations that occurred during the step.
+	#[inline]
+	pub fn mutations(&self) -> u64 {
+		self.mutations
+	}
+
+	/// Returns a reference to the fittest individual's chromosome.
+	#[inline]
+	pub fn chromosome(&self) -> &C {
+		self.chromosome
+	}
+}
@@ -7,6 +7,7 @@
 
 pub mod set;
 pub mod zipf;
+pub mod stats;
 
 /// Returns a clone of the minimum value in the supplied splice.
 ///
@@ -65,3 +66,121 @@ where
 
 	Some(max_value)
 }
+
+/// Rounds the supplied value to the supplied number of decimal places.
+///
+/// # Examples
+/// ```
+/// use kwik::math;
+///
+/// assert_eq!(math::round_to(3.14159, 2), 3.14);
+/// assert_eq!(math::round_to(2.5, 0), 3.0);
+/// ```
+#[inline]
+#[must_use]
+pub fn round_to(value: f64, decimals: usize) -> f64 {
+	let factor = 10f64.powi(decimals as i32);
+	(value * factor).round() / factor
+}
+
+/// Restricts the supplied value to the range `[lo, hi]`. Unlike `Ord::clamp`,
+/// this only requires `PartialOrd`, so it can be used with floats.
+///
+/// # Examples
+/// ```
+/// use kwik::math;
+///
+/// assert_eq!(math::clamp(5, 0, 3), 3);
+/// assert_eq!(math::clamp(-1.5, 0.0, 1.0), 0.0);
+/// assert_eq!(math::clamp(0.5, 0.0, 1.0), 0.5);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `lo` is greater than `hi`.
+#[inline]
+pub fn clamp<T>(value: T, lo: T, hi: T) -> T
+where
+	T: PartialOrd,
+{
+	assert!(lo <= hi, "Lower bound cannot be greater than the upper bound.");
+
+	if value < lo {
+		return lo;
+	}
+
+	if value > hi {
+		return hi;
+	}
+
+	value
+}
+
+/// Returns a tick spacing of the form 1, 2, or 5 times a power of ten which
+/// splits the supplied range into at most about `target_ticks` intervals.
+/// If the range is zero or not finite, a spacing of 1 is returned.
+///
+/// # Examples
+/// ```
+/// use kwik::math;
+///
+/// assert_eq!(math::nice_step(97.0, 10), 10.0);
+/// assert_eq!(math::nice_step(1.3, 5), 0.5);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the target number of ticks is zero.
+#[must_use]
+pub fn nice_step(range: f64, target_ticks: usize) -> f64 {
+	assert_ne!(target_ticks, 0, "Target number of ticks cannot be zero.");
+
+	let raw_step = range.abs() / target_ticks as f64;
+
+	if raw_step == 0.0 || !raw_step.is_finite() {
+		return 1.0;
+	}
+
+	let magnitude = 10f64.powf(raw_step.log10().floor());
+	let residual = raw_step / magnitude;
+
+	let multiplier = [1.0, 2.0, 5.0]
+		.into_iter()
+		.find(|multiplier| residual <= *multiplier)
+		.unwrap_or(10.0);
+
+	multiplier * magnitude
+}
+
+#[cfg(test)]
+mod tests {
+	use approx::assert_relative_eq;
+
+	use crate::math;
+
+	#[test]
+	fn it_picks_nice_steps() {
+		assert_relative_eq!(math::nice_step(97.0, 10), 10.0);
+		assert_relative_eq!(math::nice_step(97.0, 5), 20.0);
+		assert_relative_eq!(math::nice_step(100.0, 10), 10.0);
+		assert_relative_eq!(math::nice_step(1.3, 5), 0.5);
+		assert_relative_eq!(math::nice_step(1.3, 10), 0.2);
+		assert_relative_eq!(math::nice_step(-1.3, 10), 0.2);
+		assert_relative_eq!(math::nice_step(0.0013, 4), 0.0005);
+		assert_relative_eq!(math::nice_step(45_000.0, 3), 20_000.0);
+
+		assert_eq!(math::nice_step(0.0, 10), 1.0);
+		assert_eq!(math::nice_step(f64::INFINITY, 10), 1.0);
+	}
+
+	#[test]
+	fn it_rounds_and_clamps() {
+		assert_relative_eq!(math::round_to(1.23456, 3), 1.235);
+		assert_relative_eq!(math::round_to(-1.25, 1), -1.3);
+		assert_relative_eq!(math::round_to(1234.5, 0), 1235.0);
+
+		assert!(math::clamp(f64::NAN, 0.0, 1.0).is_nan());
+		assert_eq!(math::clamp(2, 2, 2), 2);
+		assert_eq!(math::clamp('z', 'a', 'f'), 'f');
+	}
+}
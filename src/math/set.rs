@@ -35,6 +35,40 @@ pub trait Superset {
 	fn is_superset(&self, other: &Self) -> bool;
 }
 
+pub trait SetOps: Iterator {
+	/// Returns the elements of `self` which are also in `other`, in the
+	/// order they are first seen in `self`. Duplicates are removed.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::set::SetOps;
+	///
+	/// let a = [3, 1, 2, 1];
+	/// let b = [1, 3, 5];
+	///
+	/// let intersection = a.iter().intersection(b.iter());
+	///
+	/// assert_eq!(intersection, vec![&3, &1]);
+	/// ```
+	fn intersection(self, other: Self) -> Vec<Self::Item>;
+
+	/// Returns the elements of `self` followed by the elements of `other`,
+	/// in the order they are first seen. Duplicates are removed.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::set::SetOps;
+	///
+	/// let a = [3, 1, 2, 1];
+	/// let b = [1, 3, 5];
+	///
+	/// let union = a.iter().union(b.iter());
+	///
+	/// assert_eq!(union, vec![&3, &1, &2, &5]);
+	/// ```
+	fn union(self, other: Self) -> Vec<Self::Item>;
+}
+
 impl<T> Subset for &[T]
 where
 	T: PartialEq,
@@ -92,9 +126,39 @@ where
 	}
 }
 
+impl<I> SetOps for I
+where
+	I: Iterator + Clone,
+	I::Item: PartialEq,
+{
+	fn intersection(self, other: Self) -> Vec<Self::Item> {
+		let mut elements = Vec::<Self::Item>::new();
+
+		for element in self {
+			if !elements.contains(&element) && other.clone().any(|e| e == element) {
+				elements.push(element);
+			}
+		}
+
+		elements
+	}
+
+	fn union(self, other: Self) -> Vec<Self::Item> {
+		let mut elements = Vec::<Self::Item>::new();
+
+		for element in self.chain(other) {
+			if !elements.contains(&element) {
+				elements.push(element);
+			}
+		}
+
+		elements
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use crate::math::set::{Subset, Superset};
+	use crate::math::set::{Subset, Superset, SetOps};
 
 	#[test]
 	fn it_identifies_slice_subsets() {
@@ -167,4 +231,37 @@ mod tests {
 		assert!(a.is_superset(&c));
 		assert!(!a.is_superset(&d));
 	}
+
+	#[test]
+	fn it_intersects_overlapping_sets() {
+		let a = vec![4, 1, 2, 4, 3];
+		let b = vec![3, 4, 5, 3];
+
+		assert_eq!(a.clone().into_iter().intersection(b.clone().into_iter()), vec![4, 3]);
+		assert_eq!(b.into_iter().intersection(a.into_iter()), vec![3, 4]);
+	}
+
+	#[test]
+	fn it_intersects_disjoint_sets() {
+		let a = [1, 2, 3];
+		let b = [4, 5, 6];
+
+		assert!(a.iter().intersection(b.iter()).is_empty());
+	}
+
+	#[test]
+	fn it_unions_overlapping_sets() {
+		let a = vec![4, 1, 2, 4, 3];
+		let b = vec![3, 4, 5, 3];
+
+		assert_eq!(a.into_iter().union(b.into_iter()), vec![4, 1, 2, 3, 5]);
+	}
+
+	#[test]
+	fn it_unions_disjoint_sets() {
+		let a = [1, 2, 2];
+		let b = [4, 3, 4];
+
+		assert_eq!(a.iter().union(b.iter()), vec![&1, &2, &4, &3]);
+	}
 }
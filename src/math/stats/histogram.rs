@@ -0,0 +1,359 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use num_traits::AsPrimitive;
+
+use crate::plot::line_plot::Line;
+
+const DEFAULT_BIN_COUNT: usize = 10;
+
+/// Counts values into equal-width bins. The bins either span a fixed
+/// range, with values outside of it counted as underflow or overflow,
+/// or span the range of the added values, which is adjusted as values
+/// are added.
+#[derive(Clone)]
+pub struct Histogram {
+	range: Option<(f64, f64)>,
+	fixed: bool,
+
+	counts: Vec<u64>,
+	underflow: u64,
+	overflow: u64,
+
+	// only kept for automatic bins, to be rebinned when the range grows
+	values: Vec<f64>,
+}
+
+impl Histogram {
+	/// Sets the histogram to use the supplied number of bins spanning the
+	/// range from `min` to `max`, inclusive. Values less than `min` are
+	/// counted as underflow and values greater than `max` are counted as
+	/// overflow. Clears any previously added values.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bins is zero or if `min` is not less than
+	/// `max`.
+	pub fn set_bins(
+		&mut self,
+		min: impl AsPrimitive<f64>,
+		max: impl AsPrimitive<f64>,
+		count: usize,
+	) {
+		let (min, max) = (min.as_(), max.as_());
+
+		assert_ne!(count, 0, "Number of bins cannot be zero.");
+		assert!(min < max, "Histogram minimum must be less than its maximum.");
+
+		self.range = Some((min, max));
+		self.fixed = true;
+
+		self.reset(count);
+	}
+
+	/// Sets the histogram to use the supplied number of bins spanning the
+	/// range from `min` to `max`, inclusive.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::stats::Histogram;
+	///
+	/// let mut histogram = Histogram::default().with_bins(0, 10, 5);
+	///
+	/// histogram.add(1);
+	/// histogram.add(3);
+	/// histogram.add(12);
+	///
+	/// assert_eq!(histogram.counts(), &[1, 1, 0, 0, 0]);
+	/// assert_eq!(histogram.overflow(), 1);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bins is zero or if `min` is not less than
+	/// `max`.
+	#[must_use]
+	pub fn with_bins(
+		mut self,
+		min: impl AsPrimitive<f64>,
+		max: impl AsPrimitive<f64>,
+		count: usize,
+	) -> Self {
+		self.set_bins(min, max, count);
+		self
+	}
+
+	/// Sets the histogram to use the supplied number of bins spanning the
+	/// range of the added values. Clears any previously added values.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bins is zero.
+	pub fn set_auto_bins(&mut self, count: usize) {
+		assert_ne!(count, 0, "Number of bins cannot be zero.");
+
+		self.range = None;
+		self.fixed = false;
+
+		self.reset(count);
+	}
+
+	/// Sets the histogram to use the supplied number of bins spanning the
+	/// range of the added values.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::stats::Histogram;
+	///
+	/// let mut histogram = Histogram::default().with_auto_bins(2);
+	///
+	/// for value in [1, 2, 3, 4] {
+	///     histogram.add(value);
+	/// }
+	///
+	/// assert_eq!(histogram.counts(), &[2, 2]);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bins is zero.
+	#[must_use]
+	pub fn with_auto_bins(mut self, count: usize) -> Self {
+		self.set_auto_bins(count);
+		self
+	}
+
+	/// Returns true if no values have been added.
+	#[inline]
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns the number of values added, including underflow and
+	/// overflow.
+	#[inline]
+	#[must_use]
+	pub fn len(&self) -> u64 {
+		self.counts.iter().sum::<u64>() + self.underflow + self.overflow
+	}
+
+	/// Adds a value to its bin. NaN values are ignored.
+	pub fn add(&mut self, value: impl AsPrimitive<f64>) {
+		let value = value.as_();
+
+		if value.is_nan() {
+			return;
+		}
+
+		if self.fixed {
+			return self.bin(value);
+		}
+
+		self.values.push(value);
+
+		match self.range {
+			Some((min, max)) if value < min || value > max => {
+				self.range = Some((min.min(value), max.max(value)));
+				self.rebin();
+			},
+
+			Some(_) => self.bin(value),
+
+			None => {
+				self.range = Some((value, value));
+				self.bin(value);
+			},
+		}
+	}
+
+	/// Returns the number of values in each bin, in ascending order of
+	/// the bins' ranges.
+	#[inline]
+	#[must_use]
+	pub fn counts(&self) -> &[u64] {
+		&self.counts
+	}
+
+	/// Returns the number of values less than the minimum of fixed bins.
+	#[inline]
+	#[must_use]
+	pub fn underflow(&self) -> u64 {
+		self.underflow
+	}
+
+	/// Returns the number of values greater than the maximum of fixed bins.
+	#[inline]
+	#[must_use]
+	pub fn overflow(&self) -> u64 {
+		self.overflow
+	}
+
+	/// Returns the center of the bin at the supplied index, or `None` if
+	/// the index is out of range or the bins do not yet have a range.
+	#[must_use]
+	pub fn bin_center(&self, index: usize) -> Option<f64> {
+		if index >= self.counts.len() {
+			return None;
+		}
+
+		let (min, _) = self.range?;
+
+		Some(min + self.width()? * (index as f64 + 0.5))
+	}
+
+	/// Creates a line of the histogram, where each point is a bin's center
+	/// and the number of values in the bin. Underflow and overflow are not
+	/// included.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::stats::Histogram;
+	///
+	/// let mut histogram = Histogram::default().with_bins(0, 4, 2);
+	///
+	/// histogram.add(1);
+	///
+	/// let line = histogram.to_line();
+	/// assert!(!line.is_empty());
+	/// ```
+	#[must_use]
+	pub fn to_line(&self) -> Line {
+		let mut line = Line::default();
+
+		if self.range.is_none() {
+			return line;
+		}
+
+		for (index, count) in self.counts.iter().enumerate() {
+			if let Some(center) = self.bin_center(index) {
+				line.push(center, *count);
+			}
+		}
+
+		line
+	}
+
+	fn width(&self) -> Option<f64> {
+		let (min, max) = self.range?;
+		Some((max - min) / self.counts.len() as f64)
+	}
+
+	fn bin(&mut self, value: f64) {
+		let Some((min, max)) = self.range else {
+			return;
+		};
+
+		if value < min {
+			self.underflow += 1;
+			return;
+		}
+
+		if value > max {
+			self.overflow += 1;
+			return;
+		}
+
+		let bins = self.counts.len();
+
+		let index = match self.width() {
+			Some(width) if width > 0.0 => ((value - min) / width) as usize,
+			_ => 0,
+		};
+
+		self.counts[index.min(bins - 1)] += 1;
+	}
+
+	fn rebin(&mut self) {
+		self.counts.fill(0);
+
+		let values = std::mem::take(&mut self.values);
+
+		for value in &values {
+			self.bin(*value);
+		}
+
+		self.values = values;
+	}
+
+	fn reset(&mut self, count: usize) {
+		self.counts.clear();
+		self.counts.resize(count, 0);
+
+		self.underflow = 0;
+		self.overflow = 0;
+
+		self.values.clear();
+	}
+}
+
+impl Default for Histogram {
+	fn default() -> Self {
+		Histogram {
+			range: None,
+			fixed: false,
+
+			counts: vec![0; DEFAULT_BIN_COUNT],
+			underflow: 0,
+			overflow: 0,
+
+			values: Vec::new(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::math::stats::Histogram;
+
+	#[test]
+	fn it_bins_values_in_a_fixed_range() {
+		let mut histogram = Histogram::default().with_bins(0, 10, 5);
+
+		for value in [0.0, 1.5, 2.0, 3.9, 5.0, 9.99, 10.0, -0.1, 10.1, 100.0, f64::NAN] {
+			histogram.add(value);
+		}
+
+		assert_eq!(histogram.counts(), &[2, 2, 1, 0, 2]);
+		assert_eq!(histogram.underflow(), 1);
+		assert_eq!(histogram.overflow(), 2);
+		assert_eq!(histogram.len(), 10);
+		assert_eq!(histogram.bin_center(0), Some(1.0));
+		assert_eq!(histogram.bin_center(5), None);
+	}
+
+	#[test]
+	fn it_adjusts_automatic_bins() {
+		let mut histogram = Histogram::default().with_auto_bins(4);
+
+		histogram.add(5);
+
+		assert_eq!(histogram.counts(), &[1, 0, 0, 0]);
+
+		for value in [1, 2, 3, 4, 6, 7, 8, 9] {
+			histogram.add(value);
+		}
+
+		assert_eq!(histogram.counts(), &[2, 2, 2, 3]);
+		assert_eq!(histogram.underflow(), 0);
+		assert_eq!(histogram.overflow(), 0);
+		assert_eq!(histogram.bin_center(0), Some(2.0));
+	}
+
+	#[test]
+	fn it_converts_to_a_line() {
+		let mut histogram = Histogram::default().with_bins(0, 4, 2);
+
+		assert!(Histogram::default().to_line().is_empty());
+
+		histogram.add(1);
+		histogram.add(3);
+		histogram.add(3.5);
+
+		assert!(!histogram.to_line().is_empty());
+	}
+}
@@ -0,0 +1,18 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+mod running;
+mod regression;
+mod quantile;
+mod histogram;
+
+pub use crate::math::stats::{
+	running::RunningStats,
+	regression::{LinearRegression, RegressionError},
+	quantile::{percentile, quartiles},
+	histogram::Histogram,
+};
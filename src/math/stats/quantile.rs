@@ -0,0 +1,110 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+/// Returns the `p`th percentile of the values, where `p` is between 0
+/// and 100. Between ranks, the percentile is linearly interpolated using
+/// the same method as `statrs` (R-8), so it agrees with the quartiles of
+/// box plots. The values are sorted in place. If there are no values or
+/// `p` is out of range, `None` is returned.
+///
+/// # Examples
+/// ```
+/// use kwik::math::stats::percentile;
+///
+/// let mut values = [4.0, 1.0, 3.0, 2.0, 5.0];
+///
+/// assert_eq!(percentile(&mut values, 0.0), Some(1.0));
+/// assert_eq!(percentile(&mut values, 50.0), Some(3.0));
+/// assert_eq!(percentile(&mut values, 100.0), Some(5.0));
+/// assert_eq!(percentile(&mut [], 50.0), None);
+/// ```
+pub fn percentile(values: &mut [f64], p: f64) -> Option<f64> {
+	values.sort_unstable_by(f64::total_cmp);
+	sorted_quantile(values, p / 100.0)
+}
+
+/// Returns the lower quartile, median, and upper quartile of the values,
+/// interpolated the same way as `percentile`. The values are sorted in
+/// place. If there are no values, `None` is returned.
+///
+/// # Examples
+/// ```
+/// use kwik::math::stats::quartiles;
+///
+/// let mut values = [1.0, 2.0, 3.0, 4.0];
+/// let (q1, median, q3) = quartiles(&mut values).unwrap();
+///
+/// assert_eq!(median, 2.5);
+/// assert!(q1 < median && median < q3);
+/// ```
+pub fn quartiles(values: &mut [f64]) -> Option<(f64, f64, f64)> {
+	values.sort_unstable_by(f64::total_cmp);
+
+	let q1 = sorted_quantile(values, 0.25)?;
+	let median = sorted_quantile(values, 0.5)?;
+	let q3 = sorted_quantile(values, 0.75)?;
+
+	Some((q1, median, q3))
+}
+
+fn sorted_quantile(values: &[f64], tau: f64) -> Option<f64> {
+	if values.is_empty() || !(0.0..=1.0).contains(&tau) {
+		return None;
+	}
+
+	let len = values.len() as f64;
+	let rank = (len + 1.0 / 3.0) * tau + 1.0 / 3.0;
+	let index = rank.floor();
+
+	if index < 1.0 {
+		return values.first().copied();
+	}
+
+	if index >= len {
+		return values.last().copied();
+	}
+
+	let lower = values[index as usize - 1];
+	let upper = values[index as usize];
+
+	Some(lower + (rank - index) * (upper - lower))
+}
+
+#[cfg(test)]
+mod tests {
+	use approx::assert_relative_eq;
+	use statrs::statistics::{Data, OrderStatistics};
+	use crate::math::stats::{percentile, quartiles};
+
+	const VALUES: &[f64] = &[
+		12.0, 7.5, 3.0, 41.0, 18.25, 9.0, 27.0, 5.5, 14.0, 33.0, 1.0, 21.5,
+	];
+
+	#[test]
+	fn it_matches_statrs_percentiles() {
+		for p in [0, 1, 10, 25, 33, 50, 67, 75, 90, 99, 100] {
+			let mut values = VALUES.to_vec();
+			let expected = Data::new(VALUES.to_vec()).percentile(p);
+
+			assert_relative_eq!(percentile(&mut values, p as f64).unwrap(), expected);
+		}
+
+		let mut values = VALUES[..5].to_vec();
+		let mut data = Data::new(values.clone());
+
+		let (q1, median, q3) = quartiles(&mut values).unwrap();
+
+		assert_relative_eq!(q1, data.lower_quartile());
+		assert_relative_eq!(median, data.median());
+		assert_relative_eq!(q3, data.upper_quartile());
+
+		assert!(percentile(&mut [], 50.0).is_none());
+		assert!(percentile(&mut values, 101.0).is_none());
+		assert!(quartiles(&mut []).is_none());
+		assert_eq!(percentile(&mut [4.0], 75.0), Some(4.0));
+	}
+}
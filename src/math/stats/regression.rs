@@ -0,0 +1,164 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use thiserror::Error;
+use num_traits::AsPrimitive;
+use linreg::linear_regression;
+
+/// An ordinary least squares linear regression fit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearRegression {
+	slope: f64,
+	intercept: f64,
+	r_squared: f64,
+}
+
+#[derive(Debug, Error)]
+pub enum RegressionError {
+	#[error("x and y values have different lengths (`{0}` and `{1}`)")]
+	LengthMismatch(usize, usize),
+
+	#[error("at least two points are required")]
+	TooFewPoints,
+
+	#[error("x values have zero variance")]
+	ZeroVariance,
+}
+
+impl LinearRegression {
+	/// Fits a line to the supplied x and y values.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::stats::LinearRegression;
+	///
+	/// let regression = LinearRegression::fit(&[1.0, 2.0, 3.0], &[3.0, 5.0, 7.0]).unwrap();
+	///
+	/// assert_eq!(regression.slope(), 2.0);
+	/// assert_eq!(regression.intercept(), 1.0);
+	/// assert_eq!(regression.predict(4), 9.0);
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the x and y values have different lengths, if
+	/// there are fewer than two points, or if all x values are equal.
+	pub fn fit(xs: &[f64], ys: &[f64]) -> Result<Self, RegressionError> {
+		if xs.len() != ys.len() {
+			return Err(RegressionError::LengthMismatch(xs.len(), ys.len()));
+		}
+
+		if xs.len() < 2 {
+			return Err(RegressionError::TooFewPoints);
+		}
+
+		let (slope, intercept) = linear_regression::<f64, f64, f64>(xs, ys)
+			.map_err(|_| RegressionError::ZeroVariance)?;
+
+		let mean = ys.iter().sum::<f64>() / ys.len() as f64;
+
+		let mut residual_sum: f64 = 0.0;
+		let mut total_sum: f64 = 0.0;
+
+		for (x, y) in xs.iter().zip(ys.iter()) {
+			residual_sum += (y - (slope * x + intercept)).powi(2);
+			total_sum += (y - mean).powi(2);
+		}
+
+		// if every y value is equal, the fitted horizontal line
+		// explains all of the (zero) variance
+		let r_squared = match total_sum {
+			0.0 => 1.0,
+			total_sum => 1.0 - residual_sum / total_sum,
+		};
+
+		let regression = LinearRegression {
+			slope,
+			intercept,
+			r_squared,
+		};
+
+		Ok(regression)
+	}
+
+	/// Returns the slope of the fitted line.
+	#[inline]
+	#[must_use]
+	pub fn slope(&self) -> f64 {
+		self.slope
+	}
+
+	/// Returns the y-intercept of the fitted line.
+	#[inline]
+	#[must_use]
+	pub fn intercept(&self) -> f64 {
+		self.intercept
+	}
+
+	/// Returns the coefficient of determination (R²) of the fit.
+	#[inline]
+	#[must_use]
+	pub fn r_squared(&self) -> f64 {
+		self.r_squared
+	}
+
+	/// Returns the y-value of the fitted line at the supplied x-value.
+	#[inline]
+	#[must_use]
+	pub fn predict(&self, x: impl AsPrimitive<f64>) -> f64 {
+		self.slope * x.as_() + self.intercept
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use approx::assert_relative_eq;
+	use crate::math::stats::{LinearRegression, RegressionError};
+
+	#[test]
+	fn it_fits_a_perfect_line() {
+		let xs = (0..100).map(|x| x as f64).collect::<Vec<_>>();
+		let ys = xs.iter().map(|x| 3.0 * x - 7.0).collect::<Vec<_>>();
+
+		let regression = LinearRegression::fit(&xs, &ys).unwrap();
+
+		assert_relative_eq!(regression.slope(), 3.0);
+		assert_relative_eq!(regression.intercept(), -7.0);
+		assert_relative_eq!(regression.r_squared(), 1.0);
+		assert_relative_eq!(regression.predict(200), 593.0);
+	}
+
+	#[test]
+	fn it_fits_a_noisy_dataset() {
+		let xs = [1.0, 2.0, 3.0, 4.0, 5.0];
+		let ys = [2.0, 4.0, 5.0, 4.0, 5.0];
+
+		let regression = LinearRegression::fit(&xs, &ys).unwrap();
+
+		assert_relative_eq!(regression.slope(), 0.6, epsilon = 1e-10);
+		assert_relative_eq!(regression.intercept(), 2.2, epsilon = 1e-10);
+		assert_relative_eq!(regression.r_squared(), 0.6, epsilon = 1e-10);
+	}
+
+	#[test]
+	fn it_rejects_invalid_inputs() {
+		assert!(matches!(
+			LinearRegression::fit(&[1.0, 2.0], &[1.0]),
+			Err(RegressionError::LengthMismatch(2, 1)),
+		));
+
+		assert!(matches!(
+			LinearRegression::fit(&[1.0], &[1.0]),
+			Err(RegressionError::TooFewPoints),
+		));
+
+		assert!(matches!(
+			LinearRegression::fit(&[1.0, 1.0], &[1.0, 2.0]),
+			Err(RegressionError::ZeroVariance),
+		));
+	}
+}
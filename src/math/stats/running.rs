@@ -0,0 +1,193 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use num_traits::AsPrimitive;
+
+/// Calculates streaming mean and variance statistics using Welford's
+/// online algorithm. The samples themselves are never stored.
+#[derive(Default, Clone)]
+pub struct RunningStats {
+	count: u64,
+	mean: f64,
+	m2: f64,
+}
+
+impl RunningStats {
+	/// Adds a value to the statistics.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::stats::RunningStats;
+	///
+	/// let mut stats = RunningStats::default();
+	///
+	/// stats.push(1);
+	/// stats.push(2);
+	/// stats.push(3);
+	///
+	/// assert_eq!(stats.count(), 3);
+	/// ```
+	#[inline]
+	pub fn push(&mut self, value: impl AsPrimitive<f64>) {
+		let value = value.as_();
+
+		self.count += 1;
+
+		let delta = value - self.mean;
+		self.mean += delta / self.count as f64;
+		self.m2 += delta * (value - self.mean);
+	}
+
+	/// Returns the number of values that have been added.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::stats::RunningStats;
+	///
+	/// let mut stats = RunningStats::default();
+	/// assert_eq!(stats.count(), 0);
+	///
+	/// stats.push(1.5);
+	/// assert_eq!(stats.count(), 1);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn count(&self) -> u64 {
+		self.count
+	}
+
+	/// Returns the mean of the values that have been added. If no values
+	/// have been added, `None` is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::stats::RunningStats;
+	///
+	/// let mut stats = RunningStats::default();
+	/// assert_eq!(stats.mean(), None);
+	///
+	/// stats.push(1);
+	/// stats.push(2);
+	/// stats.push(3);
+	///
+	/// assert_eq!(stats.mean(), Some(2.0));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn mean(&self) -> Option<f64> {
+		match self.count {
+			0 => None,
+			_ => Some(self.mean),
+		}
+	}
+
+	/// Returns the sample variance of the values that have been added.
+	/// If fewer than two values have been added, `None` is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::stats::RunningStats;
+	///
+	/// let mut stats = RunningStats::default();
+	///
+	/// stats.push(1);
+	/// assert_eq!(stats.variance(), None);
+	///
+	/// stats.push(2);
+	/// stats.push(3);
+	///
+	/// assert_eq!(stats.variance(), Some(1.0));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn variance(&self) -> Option<f64> {
+		match self.count {
+			0 | 1 => None,
+			count => Some(self.m2 / (count - 1) as f64),
+		}
+	}
+
+	/// Returns the sample standard deviation of the values that have been
+	/// added. If fewer than two values have been added, `None` is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::stats::RunningStats;
+	///
+	/// let mut stats = RunningStats::default();
+	///
+	/// stats.push(2);
+	/// stats.push(4);
+	/// stats.push(6);
+	///
+	/// assert_eq!(stats.std_dev(), Some(2.0));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn std_dev(&self) -> Option<f64> {
+		self.variance().map(f64::sqrt)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::Rng;
+	use approx::assert_relative_eq;
+	use statrs::statistics::{Data, Distribution};
+	use crate::math::stats::RunningStats;
+
+	#[test]
+	fn it_matches_batch_statistics() {
+		let mut rng = rand::rng();
+		let mut stats = RunningStats::default();
+
+		let values = (0..10_000)
+			.map(|_| rng.random_range(-1_000.0..1_000.0))
+			.collect::<Vec<f64>>();
+
+		for value in &values {
+			stats.push(*value);
+		}
+
+		let data = Data::new(values);
+
+		assert_eq!(stats.count(), 10_000);
+
+		assert_relative_eq!(
+			stats.mean().unwrap(),
+			data.mean().unwrap(),
+			max_relative = 1e-9,
+		);
+
+		assert_relative_eq!(
+			stats.variance().unwrap(),
+			data.variance().unwrap(),
+			max_relative = 1e-9,
+		);
+
+		assert_relative_eq!(
+			stats.std_dev().unwrap(),
+			data.std_dev().unwrap(),
+			max_relative = 1e-9,
+		);
+	}
+
+	#[test]
+	fn it_handles_too_few_values() {
+		let mut stats = RunningStats::default();
+
+		assert_eq!(stats.mean(), None);
+		assert_eq!(stats.variance(), None);
+		assert_eq!(stats.std_dev(), None);
+
+		stats.push(5);
+
+		assert_eq!(stats.mean(), Some(5.0));
+		assert_eq!(stats.variance(), None);
+		assert_eq!(stats.std_dev(), None);
+	}
+}
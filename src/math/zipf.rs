@@ -7,15 +7,24 @@
 
 use std::{
 	hash::Hash,
+	sync::OnceLock,
 	collections::HashMap,
 };
 
+use rand::Rng;
 use nohash_hasher::{BuildNoHashHasher, IsEnabled};
 use linreg::linear_regression;
 
 /// Calculates streaming Zipf distribution statistics.
+///
+/// Values are ranked by their frequency, with rank 1 being the most
+/// frequent value. Ranks are 1-based to match the Zipf convention.
 pub struct Zipf<T> {
 	frequencies: HashMap<T, u64, BuildNoHashHasher<T>>,
+
+	// the cumulative frequencies of the ranks, which are computed on
+	// first use and cleared when a value is inserted
+	cumulative: OnceLock<Vec<u64>>,
 }
 
 impl<T> Zipf<T> {
@@ -39,6 +48,8 @@ impl<T> Zipf<T> {
 			.entry(value)
 			.and_modify(|frequency| *frequency += 1)
 			.or_insert(1);
+
+		self.cumulative.take();
 	}
 
 	/// Calculates the Zipf alpha parameter of the distribution.
@@ -77,6 +88,94 @@ impl<T> Zipf<T> {
 			.map(|(m, _)| -m)
 			.ok()
 	}
+
+	/// Returns the probability of the supplied rank, where rank 1 is the
+	/// most frequent value. Ranks outside of `1..=n`, where `n` is the
+	/// number of distinct values, have a probability of 0.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::zipf::Zipf;
+	///
+	/// let mut zipf = Zipf::<u64>::default();
+	///
+	/// zipf.insert(1);
+	/// zipf.insert(1);
+	/// zipf.insert(1);
+	/// zipf.insert(2);
+	///
+	/// assert_eq!(zipf.pmf(1), 0.75);
+	/// assert_eq!(zipf.pmf(2), 0.25);
+	/// assert_eq!(zipf.pmf(3), 0.0);
+	/// ```
+	#[must_use]
+	pub fn pmf(&self, rank: usize) -> f64 {
+		let cumulative = self.cumulative();
+
+		if rank == 0 || rank > cumulative.len() {
+			return 0.0;
+		}
+
+		let previous = match rank {
+			1 => 0,
+			_ => cumulative[rank - 2],
+		};
+
+		let total = cumulative[cumulative.len() - 1];
+
+		(cumulative[rank - 1] - previous) as f64 / total as f64
+	}
+
+	/// Samples a rank from the distribution using inverse transform
+	/// sampling, where rank 1 is the most frequent value.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::math::zipf::Zipf;
+	///
+	/// let mut zipf = Zipf::<u64>::default();
+	///
+	/// zipf.insert(1);
+	/// zipf.insert(1);
+	/// zipf.insert(2);
+	///
+	/// let rank = zipf.sample(&mut rand::rng());
+	/// assert!(rank == 1 || rank == 2);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if no values have been inserted.
+	pub fn sample(&self, rng: &mut impl Rng) -> usize {
+		let cumulative = self.cumulative();
+
+		let total = *cumulative
+			.last()
+			.expect("Cannot sample an empty Zipf distribution.");
+
+		let value = rng.random_range(0..total);
+
+		cumulative.partition_point(|frequency| *frequency <= value) + 1
+	}
+
+	fn cumulative(&self) -> &[u64] {
+		self.cumulative.get_or_init(|| {
+			let mut frequencies = self.frequencies
+				.values()
+				.copied()
+				.collect::<Vec<_>>();
+
+			frequencies.sort_unstable_by(|a, b| b.cmp(a));
+
+			frequencies
+				.into_iter()
+				.scan(0, |total, frequency| {
+					*total += frequency;
+					Some(*total)
+				})
+				.collect()
+		})
+	}
 }
 
 impl<T> Default for Zipf<T> {
@@ -85,6 +184,79 @@ impl<T> Default for Zipf<T> {
 
 		Zipf {
 			frequencies: HashMap::with_hasher(hasher),
+			cumulative: OnceLock::new(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{SeedableRng, rngs::SmallRng};
+	use approx::assert_relative_eq;
+
+	use crate::math::zipf::Zipf;
+
+	fn init_zipf() -> Zipf<u64> {
+		let mut zipf = Zipf::<u64>::default();
+
+		for (value, frequency) in [(7, 10), (3, 50), (9, 15), (1, 25)] {
+			for _ in 0..frequency {
+				zipf.insert(value);
+			}
 		}
+
+		zipf
+	}
+
+	#[test]
+	fn it_calculates_the_pmf() {
+		let zipf = init_zipf();
+
+		let total = (0..=5)
+			.map(|rank| zipf.pmf(rank))
+			.sum::<f64>();
+
+		assert_relative_eq!(total, 1.0);
+		assert_relative_eq!(zipf.pmf(1), 0.5);
+		assert_relative_eq!(zipf.pmf(4), 0.1);
+		assert_eq!(zipf.pmf(0), 0.0);
+		assert_eq!(zipf.pmf(5), 0.0);
+	}
+
+	#[test]
+	fn it_samples_ranks() {
+		let zipf = init_zipf();
+		let mut rng = SmallRng::seed_from_u64(1);
+
+		let sample_count = 100_000;
+		let mut counts = [0u64; 4];
+
+		for _ in 0..sample_count {
+			counts[zipf.sample(&mut rng) - 1] += 1;
+		}
+
+		let chi_square = counts
+			.iter()
+			.enumerate()
+			.map(|(index, count)| {
+				let expected = zipf.pmf(index + 1) * sample_count as f64;
+				(*count as f64 - expected).powi(2) / expected
+			})
+			.sum::<f64>();
+
+		// the 99.9th percentile of the chi-square distribution with
+		// 3 degrees of freedom
+		assert!(chi_square < 16.27);
+	}
+
+	#[test]
+	fn it_updates_the_pmf_on_insert() {
+		let mut zipf = Zipf::<u64>::default();
+
+		zipf.insert(1);
+		assert_relative_eq!(zipf.pmf(1), 1.0);
+
+		zipf.insert(2);
+		assert_relative_eq!(zipf.pmf(1), 0.5);
 	}
 }
@@ -16,7 +16,6 @@ use gnuplot::{
 	Major,
 	Fix,
 	LabelOption,
-	LineStyle,
 	Color,
 	LineWidth,
 	DashType,
@@ -27,11 +26,15 @@ use crate::{
 	math,
 	plot::{
 		Plot,
+		LegendPosition,
+		Grid,
+		Palette,
 		auto_option,
-		COLORS,
 	},
 };
 
+const STACKED_BAR_WIDTH: f64 = 0.3;
+
 /// A bar plot.
 #[derive(Default, Clone)]
 pub struct BarPlot {
@@ -48,6 +51,13 @@ pub struct BarPlot {
 	format_y_log: bool,
 	format_y_memory: bool,
 
+	stacked: bool,
+
+	legend_position: Option<LegendPosition>,
+
+	grid: Grid,
+	palette: Palette,
+
 	bar_groups: Vec<BarGroup>,
 }
 
@@ -133,6 +143,22 @@ impl Plot for BarPlot {
 		self
 	}
 
+	fn set_grid(&mut self, enabled: bool) {
+		self.grid.enabled = enabled;
+	}
+
+	fn set_grid_style(&mut self, color: &str, width: impl AsPrimitive<f64>, dash: DashType) {
+		self.grid.set_style(color, width.as_(), dash);
+	}
+
+	fn set_palette(&mut self, palette: Palette) {
+		self.palette = palette;
+	}
+
+	fn clear_data(&mut self) {
+		self.bar_groups.clear();
+	}
+
 	fn configure(&mut self, axes: &mut Axes2D) {
 		let font = LabelOption::Font(
 			self.font_type.as_deref().unwrap_or("Arial"),
@@ -144,6 +170,8 @@ impl Plot for BarPlot {
 			.map(|bar_group| bar_group.label.as_deref().unwrap_or("").into())
 			.collect::<Vec<String>>();
 
+		let (x_min, x_max) = self.effective_x_range();
+
 		let mut y_tick_options = vec![
 			TickOption::Mirror(false),
 			TickOption::Inward(false),
@@ -155,8 +183,8 @@ impl Plot for BarPlot {
 
 		axes
 			.set_x_range(
-				AutoOption::Fix(0.0),
-				AutoOption::Fix(self.bar_groups.len() as f64 + 1.0)
+				AutoOption::Fix(x_min),
+				AutoOption::Fix(x_max),
 			)
 			.set_y_range(
 				AutoOption::Fix(0.0),
@@ -182,13 +210,9 @@ impl Plot for BarPlot {
 				Some((AutoOption::Auto, 0)),
 				&y_tick_options,
 				&[font],
-			)
-			.set_grid_options(false, &[
-				Color("#bbbbbb"),
-				LineWidth(2.0),
-				LineStyle(DashType::Dot),
-			])
-			.set_y_grid(true);
+			);
+
+		self.grid.configure(axes, false, true);
 
 		if let Some(title) = &self.title {
 			axes.set_title(title, &[font]);
@@ -206,32 +230,54 @@ impl Plot for BarPlot {
 			axes.set_y_log(Some(10.0));
 		}
 
+		if let Some(legend_position) = &self.legend_position {
+			legend_position.configure(axes, font);
+		}
+
 		if self.bar_groups.is_empty() {
 			return;
 		}
 
-		for bar_index in 0..self.bar_groups[0].bars.len() {
+		let num_bars = self.bar_groups[0].bars.len();
+
+		for offset in 0..num_bars {
+			// stacked bars are drawn from the top of the stack down, each
+			// as tall as its cumulative value, so that every bar covers
+			// the lower portion of the one drawn before it
+			let bar_index = match self.stacked {
+				true => num_bars - offset - 1,
+				false => offset,
+			};
+
 			let x_values = self.bar_groups
 				.iter()
 				.enumerate()
-				.map(|(bar_group_index, bar_group)| {
-					bar_group.bar_x_value(
+				.map(|(bar_group_index, bar_group)| match self.stacked {
+					true => bar_group_index as f64 + 1.0,
+
+					false => bar_group.bar_x_value(
 						bar_group_index,
 						bar_group.bars.len(),
 						bar_index,
-					)
+					),
 				});
 
 			let y_values = self.bar_groups
 				.iter()
-				.map(|bar_group| bar_group.bars[bar_index].value);
+				.map(|bar_group| match self.stacked {
+					true => bar_group.stacked_value(bar_index),
+					false => bar_group.bars[bar_index].value,
+				});
 
 			let widths = self.bar_groups
 				.iter()
-				.map(|bar_group| bar_group.bar_width());
+				.map(|bar_group| match self.stacked {
+					true => STACKED_BAR_WIDTH,
+					false => bar_group.bar_width(),
+				});
 
 			let mut bar_config = vec![
-				Color(COLORS[bar_index % COLORS.len()]),
+				Color(self.palette.color(bar_index)),
 				LineWidth(1.25),
 			];
 
@@ -283,10 +329,64 @@ impl BarPlot {
 		self
 	}
 
+	/// Enables or disables stacking the bars in each group on top of
+	/// each other rather than side by side.
+	pub fn set_stacked(&mut self, stacked: bool) {
+		self.stacked = stacked;
+	}
+
+	/// Enables or disables stacking the bars in each group on top of
+	/// each other rather than side by side.
+	pub fn with_stacked(mut self, stacked: bool) -> Self {
+		self.set_stacked(stacked);
+		self
+	}
+
+	/// Sets the position of the plot's legend.
+	pub fn set_legend_position(&mut self, legend_position: LegendPosition) {
+		self.legend_position = Some(legend_position);
+	}
+
+	/// Sets the position of the plot's legend.
+	pub fn with_legend_position(mut self, legend_position: LegendPosition) -> Self {
+		self.set_legend_position(legend_position);
+		self
+	}
+
 	/// Adds a bar group to the plot.
 	pub fn add(&mut self, bar_group: BarGroup) {
 		self.bar_groups.push(bar_group);
 	}
+
+	/// Returns the `(min, max)` x-axis range of the plot. Each bar group
+	/// occupies one unit along the x-axis.
+	pub fn effective_x_range(&self) -> (f64, f64) {
+		(0.0, self.bar_groups.len() as f64 + 1.0)
+	}
+
+	/// Returns the `(min, max)` y-axis range of the plot. The explicitly
+	/// set maximum is used if present, otherwise the tallest bar.
+	pub fn effective_y_range(&self) -> (f64, f64) {
+		(0.0, self.y_max.unwrap_or_else(|| self.max_y_value()))
+	}
+
+	fn max_y_value(&self) -> f64 {
+		if self.stacked {
+			return self.bar_groups
+				.iter()
+				.filter(|bar_group| !bar_group.bars.is_empty())
+				.map(|bar_group| bar_group.stacked_value(bar_group.bars.len() - 1))
+				.max_by(|a, b| a.total_cmp(b))
+				.unwrap_or(0.0);
+		}
+
+		self.bar_groups
+			.iter()
+			.flat_map(|bar_group| &bar_group.bars)
+			.map(|bar| bar.value)
+			.max_by(|a, b| a.total_cmp(b))
+			.unwrap_or(0.0)
+	}
 }
 
 impl BarGroup {
@@ -316,6 +416,13 @@ impl BarGroup {
 		*math::min(&[1.0 / self.bars.len() as f64, 0.15]).unwrap()
 	}
 
+	fn stacked_value(&self, bar_index: usize) -> f64 {
+		self.bars[..=bar_index]
+			.iter()
+			.map(|bar| bar.value)
+			.sum()
+	}
+
 	fn bar_x_value(
 		&self,
 		bar_group_index: usize,
@@ -356,3 +463,80 @@ impl Bar {
 		self
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::plot::{
+		Plot,
+		LegendPosition,
+		render,
+		bar_plot::{BarPlot, BarGroup, Bar},
+	};
+
+	#[test]
+	fn it_configures_legend_positions() {
+		let mut plot = BarPlot::default()
+			.with_legend_position(LegendPosition::TopLeft);
+
+		for label in ["a", "b"] {
+			let mut bar_group = BarGroup::default().with_label(label);
+
+			bar_group.push(Bar::new(1).with_label("first"));
+			bar_group.push(Bar::new(2).with_label("second"));
+
+			plot.add(bar_group);
+		}
+
+		assert!(!plot.is_empty());
+
+		let script = render(&mut plot);
+
+		assert!(script.contains("set key"));
+		assert!(script.contains("t \"first\""));
+		assert!(script.contains("t \"second\""));
+	}
+
+	#[test]
+	fn it_computes_effective_ranges() {
+		let mut plot = BarPlot::default();
+		let mut bar_group = BarGroup::default();
+
+		bar_group.push(Bar::new(3));
+		bar_group.push(Bar::new(7));
+
+		plot.add(bar_group);
+
+		assert_eq!(plot.effective_x_range(), (0.0, 2.0));
+		assert_eq!(plot.effective_y_range(), (0.0, 7.0));
+
+		plot.set_y_max(10);
+
+		assert_eq!(plot.effective_y_range(), (0.0, 10.0));
+	}
+
+	#[test]
+	fn it_computes_stacked_ranges() {
+		let mut plot = BarPlot::default().with_stacked(true);
+
+		for values in [[3, 4], [2, 6]] {
+			let mut bar_group = BarGroup::default();
+
+			bar_group.push(Bar::new(values[0]).with_label("first"));
+			bar_group.push(Bar::new(values[1]).with_label("second"));
+
+			plot.add(bar_group);
+		}
+
+		assert_eq!(plot.effective_y_range(), (0.0, 8.0));
+
+		plot.set_stacked(false);
+		assert_eq!(plot.effective_y_range(), (0.0, 6.0));
+
+		plot.set_stacked(true);
+
+		let script = render(&mut plot);
+
+		assert!(script.contains("t \"first\""));
+		assert!(script.contains("t \"second\""));
+	}
+}
@@ -21,15 +21,12 @@ use gnuplot::{
 	TickOption,
 	LabelOption,
 	PlotOption,
-	LineStyle,
-	Color,
-	LineWidth,
 	DashType,
 };
 
 use indexmap::IndexMap;
 use statrs::statistics::{Data, Min, Max, Distribution, OrderStatistics};
-use crate::plot::{Plot, auto_option};
+use crate::plot::{Plot, LegendPosition, Grid, auto_option};
 
 /// A box plot.
 #[derive(Default, Clone)]
@@ -50,9 +47,14 @@ pub struct BoxPlot {
 	format_y_log: bool,
 	format_y_memory: bool,
 
+	legend_position: Option<LegendPosition>,
+
+	grid: Grid,
+
 	map: IndexMap<String, Vec<f64>>,
 
 	colors: HashMap<String, String>,
+	captions: HashMap<String, String>,
 }
 
 struct Stats {
@@ -134,6 +136,18 @@ impl Plot for BoxPlot {
 		self
 	}
 
+	fn set_grid(&mut self, enabled: bool) {
+		self.grid.enabled = enabled;
+	}
+
+	fn set_grid_style(&mut self, color: &str, width: impl AsPrimitive<f64>, dash: DashType) {
+		self.grid.set_style(color, width.as_(), dash);
+	}
+
+	fn clear_data(&mut self) {
+		self.map.clear();
+	}
+
 	fn configure(&mut self, axes: &mut Axes2D) {
 		let font = LabelOption::Font(
 			self.font_type.as_deref().unwrap_or("Arial"),
@@ -145,6 +159,8 @@ impl Plot for BoxPlot {
 			.map(|label| label.into())
 			.collect::<Vec<String>>();
 
+		let (x_min, x_max) = self.effective_x_range();
+
 		let mut y_tick_options = vec![
 			TickOption::Mirror(false),
 			TickOption::Inward(false),
@@ -156,8 +172,8 @@ impl Plot for BoxPlot {
 
 		axes
 			.set_x_range(
-				AutoOption::Fix(0.0),
-				AutoOption::Fix(self.map.len() as f64 + 1.0)
+				AutoOption::Fix(x_min),
+				AutoOption::Fix(x_max),
 			)
 			.set_y_range(
 				auto_option(self.y_min),
@@ -183,13 +199,9 @@ impl Plot for BoxPlot {
 				Some((auto_option(self.y_tick), 0)),
 				&y_tick_options,
 				&[font]
-			)
-			.set_grid_options(false, &[
-				Color("#bbbbbb"),
-				LineWidth(2.0),
-				LineStyle(DashType::Dot),
-			])
-			.set_y_grid(true);
+			);
+
+		self.grid.configure(axes, false, true);
 
 		if let Some(title) = &self.title {
 			axes.set_title(title, &[font]);
@@ -207,6 +219,10 @@ impl Plot for BoxPlot {
 			axes.set_y_log(Some(10.0));
 		}
 
+		if let Some(legend_position) = &self.legend_position {
+			legend_position.configure(axes, font);
+		}
+
 		for (index, label) in labels.iter().enumerate() {
 			let x_value = index as f64 + 1.0;
 			let stats = self.get_stats(label);
@@ -216,6 +232,17 @@ impl Plot for BoxPlot {
 					.map(|color| color.as_str())
 					.unwrap_or("red");
 
+			let mut box_config = vec![
+				PlotOption::Color("white"),
+				PlotOption::BorderColor(color),
+				PlotOption::WhiskerBars(0.5),
+				PlotOption::LineWidth(1.25),
+			];
+
+			if let Some(caption) = self.captions.get(label) {
+				box_config.push(PlotOption::Caption(caption));
+			}
+
 			axes
 				.box_and_whisker_set_width(
 					[x_value],
@@ -224,12 +251,7 @@ impl Plot for BoxPlot {
 					[stats.max()],
 					[stats.q3()],
 					[0.25],
-					&[
-						PlotOption::Color("white"),
-						PlotOption::BorderColor(color),
-						PlotOption::WhiskerBars(0.5),
-						PlotOption::LineWidth(1.25),
-					]
+					&box_config,
 				)
 				.points(
 					[x_value],
@@ -328,6 +350,36 @@ impl BoxPlot {
 		self
 	}
 
+	/// Sets an individual box's caption in the plot's legend.
+	pub fn set_caption<T1, T2>(&mut self, label: T1, caption: T2)
+	where
+		T1: Display,
+		T2: Display,
+	{
+		self.captions.insert(label.to_string(), caption.to_string());
+	}
+
+	/// Sets an individual box's caption in the plot's legend.
+	pub fn with_caption<T1, T2>(mut self, label: T1, caption: T2) -> Self
+	where
+		T1: Display,
+		T2: Display,
+	{
+		self.set_caption(label, caption);
+		self
+	}
+
+	/// Sets the position of the plot's legend.
+	pub fn set_legend_position(&mut self, legend_position: LegendPosition) {
+		self.legend_position = Some(legend_position);
+	}
+
+	/// Sets the position of the plot's legend.
+	pub fn with_legend_position(mut self, legend_position: LegendPosition) -> Self {
+		self.set_legend_position(legend_position);
+		self
+	}
+
 	/// Adds a data point to a box if it exists. Otherwise, creates a new
 	/// box with the supplied label.
 	pub fn add<T>(&mut self, label: T, value: impl AsPrimitive<f64>)
@@ -340,6 +392,23 @@ impl BoxPlot {
 			.or_insert(vec![value.as_()]);
 	}
 
+	/// Returns the `(min, max)` x-axis range of the plot. Each box
+	/// occupies one unit along the x-axis.
+	pub fn effective_x_range(&self) -> (f64, f64) {
+		(0.0, self.map.len() as f64 + 1.0)
+	}
+
+	/// Returns the `(min, max)` y-axis range of the plot. Explicitly set
+	/// bounds are used if present, otherwise the bounds of the data.
+	pub fn effective_y_range(&self) -> (f64, f64) {
+		let values = || self.map.values().flatten().copied();
+
+		(
+			self.y_min.unwrap_or_else(|| values().min_by(|a, b| a.total_cmp(b)).unwrap_or(0.0)),
+			self.y_max.unwrap_or_else(|| values().max_by(|a, b| a.total_cmp(b)).unwrap_or(0.0)),
+		)
+	}
+
 	fn get_stats(&mut self, label: &str) -> Stats {
 		let values = self.map.get_mut(label)
 			.expect("Could not get stats");
@@ -373,3 +442,49 @@ impl Stats {
 	fn q1(&self) -> f64 { self.q1 }
 	fn q3(&self) -> f64 { self.q3 }
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::plot::{
+		Plot,
+		LegendPosition,
+		render,
+		box_plot::BoxPlot,
+	};
+
+	#[test]
+	fn it_configures_legend_positions() {
+		let mut plot = BoxPlot::default()
+			.with_legend_position(LegendPosition::BottomRight)
+			.with_caption("a", "first");
+
+		for value in [1, 2, 3, 4, 5] {
+			plot.add("a", value);
+			plot.add("b", value * 2);
+		}
+
+		assert!(!plot.is_empty());
+
+		let script = render(&mut plot);
+
+		assert!(script.contains("set key"));
+		assert!(script.contains("t \"first\""));
+	}
+
+	#[test]
+	fn it_computes_effective_ranges() {
+		let mut plot = BoxPlot::default();
+
+		for value in [1, 2, 3, 4, 5] {
+			plot.add("a", value);
+			plot.add("b", value * 2);
+		}
+
+		assert_eq!(plot.effective_x_range(), (0.0, 3.0));
+		assert_eq!(plot.effective_y_range(), (1.0, 10.0));
+
+		plot.set_y_min(0);
+
+		assert_eq!(plot.effective_y_range(), (0.0, 10.0));
+	}
+}
@@ -12,25 +12,48 @@ use std::{
 };
 
 use num_traits::AsPrimitive;
-use gnuplot::Figure as GnuplotFigure;
-use crate::plot::Plot;
+use gnuplot::{Figure as GnuplotFigure, Axes2D};
+use crate::plot::{Plot, Palette};
 
-/// A figure which may hold one or more plots.
-pub struct Figure {
-	figure: GnuplotFigure,
+/// A figure which may hold one or more plots. The plots are rendered when
+/// the figure is saved, so the figure borrows for as long as its plots do.
+pub struct Figure<'a> {
+	plots: Vec<Box<dyn FigurePlot + Send + 'a>>,
+
+	title: Option<String>,
+	palette: Option<Palette>,
 
 	columns: usize,
 	count: usize,
+	shared_x_axis: bool,
 
 	plot_width_px: f32,
 	plot_height_px: f32,
 }
 
+/// The object-safe subset of `Plot` which is needed to render the
+/// plots of a figure when it is saved.
+trait FigurePlot {
+	fn x_range(&self) -> Option<(f64, f64)>;
+	fn set_palette(&mut self, palette: Palette);
+	fn configure(&mut self, axes: &mut Axes2D);
+	fn configure_shared_x_axis(&mut self, axes: &mut Axes2D, x_min: f64, x_max: f64, show_tick_labels: bool);
+}
+
+/// The file format of a saved figure.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+	Pdf,
+	Png,
+	Svg,
+}
+
 pub const DPI: f32 = 72.0;
 pub const DEFAULT_WIDTH_PX: f32 = 323.0;
 pub const DEFAULT_HEIGHT_PX: f32 = 150.0;
+pub const TITLE_HEIGHT_PX: f32 = 24.0;
 
-impl Figure {
+impl<'a> Figure<'a> {
 	/// Constructs a new figure with one column.
 	///
 	/// # Examples
@@ -41,16 +64,72 @@ impl Figure {
 	/// ```
 	pub fn new() -> Self {
 		Figure {
-			figure: GnuplotFigure::new(),
+			plots: Vec::new(),
+
+			title: None,
+			palette: None,
 
 			columns: 1,
 			count: 0,
+			shared_x_axis: false,
 
 			plot_width_px: DEFAULT_WIDTH_PX,
 			plot_height_px: DEFAULT_HEIGHT_PX,
 		}
 	}
 
+	/// Sets the figure's title, which is shown above all of its plots.
+	/// Each plot's own title is still shown above that plot. The figure
+	/// is made taller by `TITLE_HEIGHT_PX` to fit the title.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::plot::Figure;
+	///
+	/// let mut figure = Figure::default();
+	/// figure.set_title("Results");
+	/// ```
+	pub fn set_title(&mut self, title: &str) {
+		self.title = Some(title.into());
+	}
+
+	/// Sets the figure's title, which is shown above all of its plots.
+	/// Each plot's own title is still shown above that plot. The figure
+	/// is made taller by `TITLE_HEIGHT_PX` to fit the title.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::plot::Figure;
+	///
+	/// let figure = Figure::default()
+	///     .with_title("Results");
+	/// ```
+	pub fn with_title(mut self, title: &str) -> Self {
+		self.set_title(title);
+		self
+	}
+
+	/// Sets the colors cycled through by every plot in the figure when it
+	/// is saved, replacing any palette set on the plots themselves.
+	pub fn set_palette(&mut self, palette: Palette) {
+		self.palette = Some(palette);
+	}
+
+	/// Sets the colors cycled through by every plot in the figure when it
+	/// is saved, replacing any palette set on the plots themselves.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::plot::{Figure, Palette};
+	///
+	/// let figure = Figure::default()
+	///     .with_palette(Palette::ColorblindSafe);
+	/// ```
+	pub fn with_palette(mut self, palette: Palette) -> Self {
+		self.set_palette(palette);
+		self
+	}
+
 	/// Sets the maximum number of columns in the figure. The number of
 	/// rows can grow, though the number of columns will be limited to
 	/// the supplied amount. The default number of columns is one.
@@ -91,6 +170,39 @@ impl Figure {
 		self
 	}
 
+	/// Sets whether stacked plots share an x-axis. When enabled and the
+	/// plots are in a single column, the x-axis of each plot is fixed to
+	/// the union of all of the plots' x-axis ranges, and only the bottom
+	/// plot shows x tick labels and its x-axis label.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::plot::Figure;
+	///
+	/// let mut figure = Figure::default();
+	/// figure.set_shared_x_axis(true);
+	/// ```
+	pub fn set_shared_x_axis(&mut self, value: bool) {
+		self.shared_x_axis = value;
+	}
+
+	/// Sets whether stacked plots share an x-axis. When enabled and the
+	/// plots are in a single column, the x-axis of each plot is fixed to
+	/// the union of all of the plots' x-axis ranges, and only the bottom
+	/// plot shows x tick labels and its x-axis label.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::plot::Figure;
+	///
+	/// let figure = Figure::default()
+	///     .with_shared_x_axis(true);
+	/// ```
+	pub fn with_shared_x_axis(mut self, value: bool) -> Self {
+		self.set_shared_x_axis(value);
+		self
+	}
+
 	/// Sets the width (in pixels) of an individual plot in the figure.
 	/// By default, this value is initially set the `DEFAULT_WIDTH_PX`.
 	///
@@ -163,28 +275,59 @@ impl Figure {
 	}
 
 	/// Adds a plot to the figure, appending it to the end of the current plots.
-	pub fn add(&mut self, mut plot: impl Plot) {
+	/// The plot is rendered when the figure is saved. Plots must be `Send`
+	/// so that the figure can be moved between threads.
+	pub fn add(&mut self, plot: impl Plot + Send + 'a) {
 		if plot.is_empty() {
 			return;
 		}
 
 		self.count += 1;
-
-		self.figure.set_multiplot_layout(
-			(self.count as f32 / self.columns as f32).ceil() as usize,
-			*cmp::min(&self.count, &self.columns)
-		);
-
-		plot.configure(self.figure.axes2d());
+		self.plots.push(Box::new(plot));
 	}
 
-	/// Saves the figure to a file at the supplied path.
+	/// Saves the figure to a PDF file at the supplied path.
 	///
 	/// # Errors
 	///
 	/// This function will return an error if the figure could not be
 	/// saved to the file at the supplied path.
 	pub fn save<P>(&mut self, path: P) -> io::Result<()>
+	where
+		P: AsRef<Path>,
+	{
+		self.save_as(path, OutputFormat::Pdf)
+	}
+
+	/// Saves the figure to a file of the supplied format at the supplied path.
+	///
+	/// # Examples
+	/// ```no_run
+	/// use kwik::plot::{
+	///     Figure,
+	///     OutputFormat,
+	///     Plot,
+	///     line_plot::{LinePlot, Line},
+	/// };
+	///
+	/// let mut figure = Figure::default();
+	/// let mut plot = LinePlot::default();
+	/// let mut line = Line::default();
+	///
+	/// line.push(1, 1);
+	/// line.push(2, 4);
+	///
+	/// plot.line(line);
+	/// figure.add(plot);
+	///
+	/// figure.save_as("/path/to/file.png", OutputFormat::Png).unwrap();
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if the figure is empty or could
+	/// not be saved to the file at the supplied path.
+	pub fn save_as<P>(&mut self, path: P, format: OutputFormat) -> io::Result<()>
 	where
 		P: AsRef<Path>,
 	{
@@ -195,16 +338,30 @@ impl Figure {
 			));
 		}
 
-		let columns = cmp::min(&self.count, &self.columns);
-		let rows = (self.count as f32 / self.columns as f32).ceil() as u32;
+		let (width_px, height_px) = self.size_px();
+		let mut figure = self.render();
+
+		let result = match format {
+			OutputFormat::Pdf => figure.save_to_pdf(
+				path,
+				width_px / DPI,
+				height_px / DPI,
+			),
 
-		let plot_width_in = self.plot_width_px / DPI;
-		let plot_height_in = self.plot_height_px / DPI;
+			OutputFormat::Png => figure.save_to_png(
+				path,
+				width_px as u32,
+				height_px as u32,
+			),
 
-		let width = *columns as f32 * plot_width_in;
-		let height = rows as f32 * plot_height_in;
+			OutputFormat::Svg => figure.save_to_svg(
+				path,
+				width_px as u32,
+				height_px as u32,
+			),
+		};
 
-		match self.figure.save_to_pdf(path, width, height) {
+		match result {
 			Ok(_) => Ok(()),
 
 			Err(_) => Err(io::Error::new(
@@ -213,10 +370,300 @@ impl Figure {
 			)),
 		}
 	}
+
+	fn render(&mut self) -> GnuplotFigure {
+		let mut figure = GnuplotFigure::new();
+
+		figure.set_multiplot_layout(
+			(self.count as f32 / self.columns as f32).ceil() as usize,
+			*cmp::min(&self.count, &self.columns)
+		);
+
+		if let Some(title) = &self.title {
+			figure.set_title(title);
+		}
+
+		let shared_x_range = self.shared_x_range();
+		let last_index = self.plots.len() - 1;
+
+		for (index, plot) in self.plots.iter_mut().enumerate() {
+			if let Some(palette) = &self.palette {
+				plot.set_palette(palette.clone());
+			}
+
+			match shared_x_range {
+				Some((x_min, x_max)) => plot.configure_shared_x_axis(
+					figure.axes2d(),
+					x_min,
+					x_max,
+					index == last_index,
+				),
+
+				None => plot.configure(figure.axes2d()),
+			}
+		}
+
+		figure
+	}
+
+	/// Returns the union of the plots' x-axis ranges if the plots share
+	/// an x-axis.
+	fn shared_x_range(&self) -> Option<(f64, f64)> {
+		if !self.shared_x_axis || self.columns != 1 {
+			return None;
+		}
+
+		self.plots
+			.iter()
+			.filter_map(|plot| plot.x_range())
+			.reduce(|(min, max), (x_min, x_max)| (min.min(x_min), max.max(x_max)))
+	}
+
+	fn size_px(&self) -> (f32, f32) {
+		let columns = cmp::min(&self.count, &self.columns);
+		let rows = (self.count as f32 / self.columns as f32).ceil();
+
+		let width = *columns as f32 * self.plot_width_px;
+		let mut height = rows * self.plot_height_px;
+
+		if self.title.is_some() {
+			height += TITLE_HEIGHT_PX;
+		}
+
+		(width, height)
+	}
 }
 
-impl Default for Figure {
+impl Default for Figure<'_> {
 	fn default() -> Self {
 		Figure::new()
 	}
 }
+
+impl<T> FigurePlot for T
+where
+	T: Plot,
+{
+	fn x_range(&self) -> Option<(f64, f64)> {
+		Plot::x_range(self)
+	}
+
+	fn set_palette(&mut self, palette: Palette) {
+		Plot::set_palette(self, palette);
+	}
+
+	fn configure(&mut self, axes: &mut Axes2D) {
+		Plot::configure(self, axes);
+	}
+
+	fn configure_shared_x_axis(&mut self, axes: &mut Axes2D, x_min: f64, x_max: f64, show_tick_labels: bool) {
+		Plot::configure_shared_x_axis(self, axes, x_min, x_max, show_tick_labels);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		env,
+		fs,
+		io,
+		process::Command,
+	};
+
+	use crate::plot::{
+		Figure,
+		OutputFormat,
+		Palette,
+		Plot,
+		line_plot::{LinePlot, Line},
+	};
+
+	const FORMATS: &[(OutputFormat, &str)] = &[
+		(OutputFormat::Pdf, "pdf"),
+		(OutputFormat::Png, "png"),
+		(OutputFormat::Svg, "svg"),
+	];
+
+	fn line_plot() -> LinePlot {
+		let mut plot = LinePlot::default();
+		let mut line = Line::default();
+
+		line.push(1, 1);
+		line.push(2, 4);
+		line.push(3, 9);
+
+		plot.line(line);
+		plot
+	}
+
+	#[test]
+	fn it_errors_saving_empty_figures() {
+		let mut figure = Figure::default();
+		let dir = env::temp_dir();
+
+		for (format, extension) in FORMATS {
+			let path = dir.join(format!("kwik_empty_figure.{extension}"));
+			let err = figure.save_as(&path, *format).unwrap_err();
+
+			assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+			assert!(!path.exists());
+		}
+	}
+
+	#[test]
+	fn it_sizes_figures_by_layout() {
+		let mut figure = Figure::default()
+			.with_columns(2)
+			.with_plot_width(100)
+			.with_plot_height(50);
+
+		figure.add(line_plot());
+		assert_eq!(figure.size_px(), (100.0, 50.0));
+
+		figure.add(line_plot());
+		figure.add(line_plot());
+		assert_eq!(figure.size_px(), (200.0, 100.0));
+	}
+
+	#[test]
+	fn it_saves_figures_in_each_format() {
+		if Command::new("gnuplot").arg("--version").output().is_err() {
+			// saving requires gnuplot to be installed
+			return;
+		}
+
+		let dir = env::temp_dir().join(format!("kwik_figure_{}", std::process::id()));
+		fs::create_dir_all(&dir).unwrap();
+
+		for (format, extension) in FORMATS {
+			let mut figure = Figure::default();
+			let path = dir.join(format!("figure.{extension}"));
+
+			figure.add(line_plot());
+			figure.save_as(&path, *format).unwrap();
+
+			assert!(path.exists());
+		}
+
+		fs::remove_dir_all(&dir).unwrap();
+	}
+
+	#[test]
+	fn it_shares_the_x_axis_of_stacked_plots() {
+		let mut figure = Figure::default()
+			.with_shared_x_axis(true);
+
+		let mut line = Line::default();
+		line.push(5, 1);
+		line.push(8, 2);
+
+		let mut bottom_plot = LinePlot::default()
+			.with_x_label("time");
+
+		bottom_plot.line(line);
+
+		figure.add(line_plot().with_x_label("time"));
+		figure.add(bottom_plot);
+
+		let mut buf = Vec::<u8>::new();
+		figure.render().echo(&mut buf);
+
+		let script = String::from_utf8_lossy(&buf);
+		let plots = script.split("set multiplot").nth(1).unwrap();
+
+		assert_eq!(plots.matches("set xrange [1.000000000000e0:8.000000000000e0]").count(), 2);
+		assert_eq!(plots.matches("format \"\"").count(), 1);
+		assert_eq!(plots.matches("set xlabel \"time\"").count(), 1);
+
+		let hidden_index = plots.find("format \"\"").unwrap();
+		let label_index = plots.find("set xlabel \"time\"").unwrap();
+
+		assert!(hidden_index < label_index);
+	}
+
+	#[test]
+	fn it_does_not_keep_the_shared_x_axis_in_its_plots() {
+		let mut figure = Figure::default()
+			.with_shared_x_axis(true);
+
+		let mut line = Line::default();
+		line.push(5, 1);
+		line.push(8, 2);
+
+		let mut plot = LinePlot::default();
+		plot.line(line);
+
+		figure.add(line_plot());
+		figure.add(plot);
+
+		figure.render();
+		figure.set_shared_x_axis(false);
+
+		let mut buf = Vec::<u8>::new();
+		figure.render().echo(&mut buf);
+
+		let script = String::from_utf8_lossy(&buf);
+
+		assert!(!script.contains("set xrange [1.000000000000e0:8.000000000000e0]"));
+		assert!(!script.contains("format \"\""));
+	}
+
+	#[test]
+	fn it_is_send() {
+		fn assert_send<T: Send>() {}
+
+		assert_send::<Figure>();
+	}
+
+	#[test]
+	fn it_saves_figures_with_titles() {
+		let mut figure = Figure::default()
+			.with_columns(2)
+			.with_plot_width(100)
+			.with_plot_height(50)
+			.with_title("Results");
+
+		figure.add(line_plot().with_title("first"));
+		figure.add(line_plot().with_title("second"));
+
+		assert_eq!(figure.size_px(), (200.0, 74.0));
+
+		let mut buf = Vec::<u8>::new();
+		figure.render().echo(&mut buf);
+
+		let script = String::from_utf8_lossy(&buf);
+
+		assert!(script.contains("set multiplot layout 1,2 title \"Results\""));
+		assert!(script.contains("set title \"first\""));
+		assert!(script.contains("set title \"second\""));
+
+		if Command::new("gnuplot").arg("--version").output().is_err() {
+			// saving requires gnuplot to be installed
+			return;
+		}
+
+		let path = env::temp_dir().join(format!("kwik_titled_figure_{}.pdf", std::process::id()));
+
+		figure.save(&path).unwrap();
+		assert!(path.exists());
+
+		fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn it_applies_its_palette_to_each_plot() {
+		let mut figure = Figure::default()
+			.with_palette(Palette::ColorblindSafe);
+
+		figure.add(line_plot());
+		figure.add(line_plot().with_palette(Palette::Custom(vec!["#111111".into()])));
+
+		let mut buf = Vec::<u8>::new();
+		figure.render().echo(&mut buf);
+
+		let script = String::from_utf8_lossy(&buf);
+
+		assert_eq!(script.matches("\"#e69f00\"").count(), 2);
+		assert!(!script.contains("\"#111111\""));
+	}
+}
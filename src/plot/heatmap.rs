@@ -0,0 +1,340 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::fmt::Display;
+use num_traits::AsPrimitive;
+
+use gnuplot::{
+	Axes2D,
+	AxesCommon,
+	BorderLocation2D,
+	TickOption,
+	LabelOption,
+	PaletteType,
+	AutoOption,
+};
+
+use crate::plot::{Plot, auto_option};
+
+/// A heatmap of a grid of values.
+#[derive(Default, Clone)]
+pub struct HeatMap {
+	font_type: Option<String>,
+	font_size: Option<f64>,
+
+	title: Option<String>,
+
+	x_label: Option<String>,
+	y_label: Option<String>,
+	z_label: Option<String>,
+
+	z_min: Option<f64>,
+	z_max: Option<f64>,
+
+	gradient: Option<[(f32, f32, f32, f32); 2]>,
+
+	rows: usize,
+	cols: usize,
+	values: Vec<f64>,
+}
+
+impl Plot for HeatMap {
+	fn is_empty(&self) -> bool {
+		self.values.is_empty()
+	}
+
+	fn set_font_type(&mut self, font_type: &str) {
+		self.font_type = Some(font_type.into());
+	}
+
+	fn with_font_type(mut self, font_type: &str) -> Self {
+		self.set_font_type(font_type);
+		self
+	}
+
+	fn set_font_size(&mut self, font_size: impl AsPrimitive<f64>) {
+		self.font_size = Some(font_size.as_());
+	}
+
+	fn with_font_size(mut self, font_size: impl AsPrimitive<f64>) -> Self {
+		self.set_font_size(font_size);
+		self
+	}
+
+	fn set_title<T>(&mut self, title: T)
+	where
+		T: Display,
+	{
+		self.title = Some(title.to_string());
+	}
+
+	fn with_title<T>(mut self, title: T) -> Self
+	where
+		T: Display,
+	{
+		self.set_title(title);
+		self
+	}
+
+	fn set_x_label<T>(&mut self, label: T)
+	where
+		T: Display,
+	{
+		self.x_label = Some(label.to_string());
+	}
+
+	fn with_x_label<T>(mut self, label: T) -> Self
+	where
+		T: Display,
+	{
+		self.set_x_label(label);
+		self
+	}
+
+	fn set_y_label<T>(&mut self, label: T)
+	where
+		T: Display,
+	{
+		self.y_label = Some(label.to_string());
+	}
+
+	fn with_y_label<T>(mut self, label: T) -> Self
+	where
+		T: Display,
+	{
+		self.set_y_label(label);
+		self
+	}
+
+	fn clear_data(&mut self) {
+		self.rows = 0;
+		self.cols = 0;
+		self.values.clear();
+	}
+
+	fn configure(&mut self, axes: &mut Axes2D) {
+		let font = LabelOption::Font(
+			self.font_type.as_deref().unwrap_or("Arial"),
+			self.font_size.unwrap_or(16.0),
+		);
+
+		let tick_options = [
+			TickOption::Mirror(false),
+			TickOption::Inward(false),
+		];
+
+		axes
+			.set_border(
+				false,
+				&[
+					BorderLocation2D::Top,
+					BorderLocation2D::Right,
+					BorderLocation2D::Bottom,
+					BorderLocation2D::Left,
+				],
+				&[]
+			)
+			.set_x_ticks(
+				Some((AutoOption::Auto, 0)),
+				&tick_options,
+				&[font],
+			)
+			.set_y_ticks(
+				Some((AutoOption::Auto, 0)),
+				&tick_options,
+				&[font],
+			)
+			.set_cb_ticks(
+				Some((AutoOption::Auto, 0)),
+				&tick_options,
+				&[font],
+			)
+			.set_cb_range(
+				auto_option(self.z_min),
+				auto_option(self.z_max),
+			);
+
+		if let Some(title) = &self.title {
+			axes.set_title(title, &[font]);
+		}
+
+		if let Some(x_label) = &self.x_label {
+			axes.set_x_label(x_label, &[font]);
+		}
+
+		if let Some(y_label) = &self.y_label {
+			axes.set_y_label(y_label, &[font]);
+		}
+
+		if let Some(z_label) = &self.z_label {
+			axes.set_cb_label(z_label, &[font]);
+		}
+
+		if let Some(gradient) = &self.gradient {
+			axes.set_palette(PaletteType::Custom(gradient));
+		}
+
+		if !self.values.is_empty() {
+			axes.image(
+				&self.values,
+				self.rows,
+				self.cols,
+				None,
+				&[],
+			);
+		}
+	}
+}
+
+impl HeatMap {
+	/// Sets the label of the plot's color scale.
+	pub fn set_z_label<T>(&mut self, label: T)
+	where
+		T: Display,
+	{
+		self.z_label = Some(label.to_string());
+	}
+
+	/// Sets the label of the plot's color scale.
+	pub fn with_z_label<T>(mut self, label: T) -> Self
+	where
+		T: Display,
+	{
+		self.set_z_label(label);
+		self
+	}
+
+	/// Sets the value mapped to the low end of the plot's color scale.
+	pub fn set_z_min(&mut self, z_min: impl AsPrimitive<f64>) {
+		self.z_min = Some(z_min.as_());
+	}
+
+	/// Sets the value mapped to the low end of the plot's color scale.
+	pub fn with_z_min(mut self, z_min: impl AsPrimitive<f64>) -> Self {
+		self.set_z_min(z_min);
+		self
+	}
+
+	/// Sets the value mapped to the high end of the plot's color scale.
+	pub fn set_z_max(&mut self, z_max: impl AsPrimitive<f64>) {
+		self.z_max = Some(z_max.as_());
+	}
+
+	/// Sets the value mapped to the high end of the plot's color scale.
+	pub fn with_z_max(mut self, z_max: impl AsPrimitive<f64>) -> Self {
+		self.set_z_max(z_max);
+		self
+	}
+
+	/// Sets the plot's color gradient from the color of the lowest value
+	/// to the color of the highest value, as `#rrggbb` hex strings.
+	///
+	/// # Panics
+	///
+	/// Panics if either color is not a valid hex color.
+	pub fn set_gradient(&mut self, low_color: &str, high_color: &str) {
+		let (low_r, low_g, low_b) = parse_hex_color(low_color);
+		let (high_r, high_g, high_b) = parse_hex_color(high_color);
+
+		self.gradient = Some([
+			(0.0, low_r, low_g, low_b),
+			(1.0, high_r, high_g, high_b),
+		]);
+	}
+
+	/// Sets the plot's color gradient from the color of the lowest value
+	/// to the color of the highest value, as `#rrggbb` hex strings.
+	///
+	/// # Panics
+	///
+	/// Panics if either color is not a valid hex color.
+	pub fn with_gradient(mut self, low_color: &str, high_color: &str) -> Self {
+		self.set_gradient(low_color, high_color);
+		self
+	}
+
+	/// Sets the plot's grid of values. The values are supplied row by row,
+	/// with the first row drawn at the bottom of the plot.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of values does not equal `rows * cols`.
+	pub fn set_data(&mut self, rows: usize, cols: usize, values: Vec<f64>) {
+		assert_eq!(
+			values.len(),
+			rows * cols,
+			"Heatmap data must have {rows} * {cols} values.",
+		);
+
+		self.rows = rows;
+		self.cols = cols;
+		self.values = values;
+	}
+
+	/// Sets the plot's grid of values. The values are supplied row by row,
+	/// with the first row drawn at the bottom of the plot.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of values does not equal `rows * cols`.
+	pub fn with_data(mut self, rows: usize, cols: usize, values: Vec<f64>) -> Self {
+		self.set_data(rows, cols, values);
+		self
+	}
+}
+
+fn parse_hex_color(color: &str) -> (f32, f32, f32) {
+	// checking the digits first ensures the slices below are on character
+	// boundaries, since every hex digit is a single byte
+	let hex = color
+		.strip_prefix('#')
+		.filter(|hex| hex.len() == 6 && hex.bytes().all(|byte| byte.is_ascii_hexdigit()))
+		.unwrap_or_else(|| panic!("Invalid hex color {color}."));
+
+	let component = |index: usize| {
+		let value = u8::from_str_radix(&hex[index..index + 2], 16)
+			.unwrap_or_else(|_| panic!("Invalid hex color {color}."));
+
+		value as f32 / 255.0
+	};
+
+	(component(0), component(2), component(4))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::plot::{
+		Plot,
+		render,
+		heatmap::HeatMap,
+	};
+
+	#[test]
+	fn it_configures_a_grid() {
+		let mut plot = HeatMap::default();
+
+		assert!(plot.is_empty());
+
+		plot.set_data(2, 3, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+		plot.set_gradient("#ffffff", "#c4342b");
+		plot.set_z_label("count");
+
+		assert!(!plot.is_empty());
+
+		let script = render(&mut plot);
+
+		assert!(script.contains("with image"));
+		assert!(script.contains("set palette defined"));
+		assert!(script.contains("\"count\""));
+	}
+
+	#[test]
+	#[should_panic(expected = "Invalid hex color")]
+	fn it_rejects_multibyte_gradient_colors() {
+		HeatMap::default().set_gradient("#aé000", "#ffffff");
+	}
+}
@@ -5,7 +5,11 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use std::fmt::Display;
+use std::{
+	fmt::Display,
+	sync::Arc,
+};
+
 use num_traits::AsPrimitive;
 
 use gnuplot::{
@@ -20,13 +24,31 @@ use gnuplot::{
 	TickOption,
 	PointSymbol,
 	PointSize,
+	FillAlpha,
 	LabelOption,
 	PlotOption,
 	XAxis,
 	YAxis,
+	Coordinate,
+	ArrowheadType,
+	Tick,
+	AutoOption,
 };
 
-use crate::plot::{Plot, auto_option, COLORS, DASH_TYPES};
+use crate::{
+	math::{self, stats::Histogram},
+	plot::{
+		Plot,
+		LegendPosition,
+		Grid,
+		Palette,
+		auto_option,
+		has_nonpositive,
+		DASH_TYPES,
+	},
+};
+
+const DEFAULT_CUSTOM_TICK_COUNT: usize = 10;
 
 /// A line plot.
 #[derive(Default, Clone)]
@@ -60,13 +82,27 @@ pub struct LinePlot {
 	format_y_memory: bool,
 	format_y2_memory: bool,
 
+	hide_x_tick_labels: bool,
+
+	x_tick_formatter: Option<Arc<dyn Fn(f64) -> String + Send + Sync>>,
+
+	legend_position: Option<LegendPosition>,
+
+	grid: Grid,
+	palette: Palette,
+
 	y1_lines: Vec<Line>,
 	y2_lines: Vec<Line>,
 
+	bands: Vec<Band>,
+
 	vlines: Vec<f64>,
 	hlines: Vec<f64>,
 
 	points: Vec<(f64, f64)>,
+
+	annotations: Vec<(f64, f64, String)>,
+	arrows: Vec<((f64, f64), (f64, f64))>,
 }
 
 /// An individual line on a line plot.
@@ -74,15 +110,51 @@ pub struct LinePlot {
 pub struct Line {
 	label: Option<String>,
 	width: f64,
+	render_style: RenderStyle,
 
 	x_values: Vec<f64>,
 	y_values: Vec<f64>,
+	y_errors: Vec<f64>,
 
 	y2_axis: bool,
+	fill_alpha: Option<f64>,
+}
+
+/// How a line's data points are drawn.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RenderStyle {
+	/// The points are connected by straight lines.
+	#[default]
+	Lines,
+
+	/// The points are connected by horizontal then vertical steps, where
+	/// each value holds until the next point.
+	Steps,
+
+	/// Only the points are drawn, as markers.
+	Points,
+
+	/// The points are drawn as markers connected by straight lines.
+	LinesPoints,
+}
+
+/// A filled area between two lines on a line plot.
+#[derive(Clone)]
+struct Band {
+	x_values: Vec<f64>,
+	lower_values: Vec<f64>,
+	upper_values: Vec<f64>,
+
+	color: String,
+	alpha: f64,
 }
 
 impl Plot for LinePlot {
 	fn is_empty(&self) -> bool {
+		if self.bands.iter().any(|band| !band.x_values.is_empty()) {
+			return false;
+		}
+
 		if self.y1_lines.is_empty() && self.y2_lines.is_empty() {
 			// there are no lines in the plot
 			return true;
@@ -163,6 +235,51 @@ impl Plot for LinePlot {
 		self
 	}
 
+	fn x_range(&self) -> Option<(f64, f64)> {
+		Some(self.effective_x_range())
+	}
+
+	fn configure_shared_x_axis(
+		&mut self,
+		axes: &mut Axes2D,
+		x_min: f64,
+		x_max: f64,
+		show_tick_labels: bool,
+	) {
+		let settings = (self.x_min, self.x_max, self.hide_x_tick_labels);
+
+		self.x_min = Some(x_min);
+		self.x_max = Some(x_max);
+		self.hide_x_tick_labels = !show_tick_labels;
+
+		self.configure(axes);
+
+		(self.x_min, self.x_max, self.hide_x_tick_labels) = settings;
+	}
+
+	fn set_grid(&mut self, enabled: bool) {
+		self.grid.enabled = enabled;
+	}
+
+	fn set_grid_style(&mut self, color: &str, width: impl AsPrimitive<f64>, dash: DashType) {
+		self.grid.set_style(color, width.as_(), dash);
+	}
+
+	fn set_palette(&mut self, palette: Palette) {
+		self.palette = palette;
+	}
+
+	fn clear_data(&mut self) {
+		self.y1_lines.clear();
+		self.y2_lines.clear();
+		self.bands.clear();
+		self.vlines.clear();
+		self.hlines.clear();
+		self.points.clear();
+		self.annotations.clear();
+		self.arrows.clear();
+	}
+
 	fn configure(&mut self, axes: &mut Axes2D) {
 		let font = LabelOption::Font(
 			self.font_type.as_deref().unwrap_or("Arial"),
@@ -179,7 +296,9 @@ impl Plot for LinePlot {
 			TickOption::Inward(false),
 		];
 
-		if self.format_x_memory {
+		if self.hide_x_tick_labels {
+			x_tick_options.push(TickOption::Format(""));
+		} else if self.format_x_memory {
 			x_tick_options.push(TickOption::Format("%.1s %cB"));
 		}
 
@@ -213,20 +332,33 @@ impl Plot for LinePlot {
 				Some((auto_option(self.y_tick), 0)),
 				&y_tick_options,
 				&[font],
-			)
-			.set_grid_options(false, &[
-				Color("#bbbbbb"),
-				LineWidth(2.0),
-				LineStyle(DashType::Dot),
-			])
-			.set_x_grid(true)
-			.set_y_grid(true);
+			);
+
+		self.grid.configure(axes, true, true);
+
+		if let Some(formatter) = self.x_tick_formatter.as_ref().filter(|_| !self.hide_x_tick_labels) {
+			// gnuplot treats tick labels as format strings, so any percent
+			// signs produced by the formatter are escaped
+			let ticks = self.custom_x_tick_positions()
+				.into_iter()
+				.map(|x| Tick::Major(x, AutoOption::Fix(formatter(x).replace('%', "%%"))))
+				.collect::<Vec<_>>();
+
+			axes.set_x_ticks_custom(
+				ticks,
+				&[
+					TickOption::Mirror(false),
+					TickOption::Inward(false),
+				],
+				&[font],
+			);
+		}
 
 		if let Some(title) = &self.title {
 			axes.set_title(title, &[font]);
 		}
 
-		if let Some(x_label) = &self.x_label {
+		if let Some(x_label) = self.x_label.as_ref().filter(|_| !self.hide_x_tick_labels) {
 			axes.set_x_label(x_label, &[font]);
 		}
 
@@ -242,6 +374,10 @@ impl Plot for LinePlot {
 			axes.set_y_log(Some(10.0));
 		}
 
+		if let Some(legend_position) = &self.legend_position {
+			legend_position.configure(axes, font);
+		}
+
 		if !self.y2_lines.is_empty() {
 			let mut y2_tick_options = vec![
 				TickOption::Mirror(false),
@@ -272,10 +408,25 @@ impl Plot for LinePlot {
 			}
 		}
 
+		// bands are drawn first so that they are beneath the lines
+		for band in &self.bands {
+			axes.fill_between(&band.x_values, &band.lower_values, &band.upper_values, &[
+				Color(&band.color),
+				FillAlpha(band.alpha),
+			]);
+		}
+
 		for (index, line) in self.y1_lines.iter().enumerate() {
+			if let Some(alpha) = line.fill_alpha {
+				axes.fill_between(&line.x_values, vec![0.0; line.len()], &line.y_values, &[
+					Color(self.palette.color(index)),
+					FillAlpha(alpha),
+				]);
+			}
+
 			let mut line_config = vec![
 				LineWidth(line.width),
-				Color(COLORS[index % COLORS.len()]),
+				Color(self.palette.color(index)),
 				LineStyle(DASH_TYPES[index % DASH_TYPES.len()]),
 			];
 
@@ -283,15 +434,31 @@ impl Plot for LinePlot {
 				line_config.push(Caption(label));
 			}
 
-			axes.lines(&line.x_values, &line.y_values, &line_config);
+			line.draw(axes, line_config);
+
+			if line.has_errors() {
+				axes.y_error_bars(&line.x_values, &line.y_values, &line.y_errors, &[
+					LineWidth(line.width),
+					Color(self.palette.color(index)),
+					PointSize(0.0),
+				]);
+			}
 		}
 
 		for (index, line) in self.y2_lines.iter().enumerate() {
 			let global_index = self.y1_lines.len() + index;
 
+			if let Some(alpha) = line.fill_alpha {
+				axes.fill_between(&line.x_values, vec![0.0; line.len()], &line.y_values, &[
+					Color(self.palette.color(global_index)),
+					FillAlpha(alpha),
+					PlotOption::Axes(XAxis::X1, YAxis::Y2),
+				]);
+			}
+
 			let mut line_config = vec![
 				LineWidth(line.width),
-				Color(COLORS[global_index % COLORS.len()]),
+				Color(self.palette.color(global_index)),
 				LineStyle(DASH_TYPES[global_index % DASH_TYPES.len()]),
 				PlotOption::Axes(XAxis::X1, YAxis::Y2),
 			];
@@ -300,7 +467,16 @@ impl Plot for LinePlot {
 				line_config.push(Caption(label));
 			}
 
-			axes.lines(&line.x_values, &line.y_values, &line_config);
+			line.draw(axes, line_config);
+
+			if line.has_errors() {
+				axes.y_error_bars(&line.x_values, &line.y_values, &line.y_errors, &[
+					LineWidth(line.width),
+					Color(self.palette.color(global_index)),
+					PointSize(0.0),
+					PlotOption::Axes(XAxis::X1, YAxis::Y2),
+				]);
+			}
 		}
 
 		for vline_x in &self.vlines {
@@ -333,6 +509,29 @@ impl Plot for LinePlot {
 				Color("#009600"),
 			]);
 		}
+
+		for (x_value, y_value, text) in &self.annotations {
+			axes.label(
+				text,
+				Coordinate::Axis(*x_value),
+				Coordinate::Axis(*y_value),
+				&[font],
+			);
+		}
+
+		for ((x_from, y_from), (x_to, y_to)) in &self.arrows {
+			axes.arrow(
+				Coordinate::Axis(*x_from),
+				Coordinate::Axis(*y_from),
+				Coordinate::Axis(*x_to),
+				Coordinate::Axis(*y_to),
+				&[
+					PlotOption::ArrowType(ArrowheadType::Filled),
+					LineWidth(2.0),
+					Color("black"),
+				],
+			);
+		}
 	}
 }
 
@@ -411,7 +610,7 @@ impl LinePlot {
 
 	/// Sets the plot's maximum y2-value.
 	pub fn set_y2_max(&mut self, y2_max: impl AsPrimitive<f64>) {
-		self.y_max = Some(y2_max.as_());
+		self.y2_max = Some(y2_max.as_());
 	}
 
 	/// Sets the plot's maximum y2-value.
@@ -519,6 +718,42 @@ impl LinePlot {
 		self
 	}
 
+	/// Sets a function which formats the labels of the x-axis ticks. This
+	/// overrides any other formatting of the x-axis tick labels. Ticks are
+	/// placed at the multiples of the x-tick value (see `set_x_tick`) within
+	/// the x-axis range, or of a round spacing which yields about 10 ticks
+	/// if no x-tick value is set.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::plot::line_plot::LinePlot;
+	///
+	/// let mut plot = LinePlot::default().with_x_tick(1);
+	///
+	/// plot.set_x_tick_formatter(|x| format!("day {x}"));
+	/// ```
+	pub fn set_x_tick_formatter(&mut self, formatter: impl Fn(f64) -> String + Send + Sync + 'static) {
+		self.x_tick_formatter = Some(Arc::new(formatter));
+	}
+
+	/// Sets a function which formats the labels of the x-axis ticks. This
+	/// overrides any other formatting of the x-axis tick labels.
+	pub fn with_x_tick_formatter(mut self, formatter: impl Fn(f64) -> String + Send + Sync + 'static) -> Self {
+		self.set_x_tick_formatter(formatter);
+		self
+	}
+
+	/// Sets the position of the plot's legend.
+	pub fn set_legend_position(&mut self, legend_position: LegendPosition) {
+		self.legend_position = Some(legend_position);
+	}
+
+	/// Sets the position of the plot's legend.
+	pub fn with_legend_position(mut self, legend_position: LegendPosition) -> Self {
+		self.set_legend_position(legend_position);
+		self
+	}
+
 	/// Adds a line to the plot.
 	pub fn line(&mut self, line: Line) {
 		if !line.y2_axis {
@@ -528,6 +763,54 @@ impl LinePlot {
 		}
 	}
 
+	/// Fills the area between the supplied lines with the supplied color
+	/// and opacity, between 0 (transparent) and 1 (opaque). The lines are
+	/// not drawn, so they can be added separately if their edges should be
+	/// visible. Bands are drawn on the y-axis beneath all lines.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::plot::line_plot::{LinePlot, Line};
+	///
+	/// let mut plot = LinePlot::default();
+	///
+	/// let mut lower = Line::default();
+	/// let mut upper = Line::default();
+	///
+	/// lower.push(0, 1);
+	/// lower.push(1, 2);
+	///
+	/// upper.push(0, 3);
+	/// upper.push(1, 5);
+	///
+	/// plot.fill_between(lower, upper, "#0060ad", 0.3);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the lines do not have the same x-values.
+	pub fn fill_between(
+		&mut self,
+		lower: Line,
+		upper: Line,
+		color: impl Into<String>,
+		alpha: impl AsPrimitive<f64>,
+	) {
+		assert!(
+			lower.x_values == upper.x_values,
+			"Lower and upper lines must have the same x-values.",
+		);
+
+		self.bands.push(Band {
+			x_values: lower.x_values,
+			lower_values: lower.y_values,
+			upper_values: upper.y_values,
+
+			color: color.into(),
+			alpha: math::clamp(alpha.as_(), 0.0, 1.0),
+		});
+	}
+
 	/// Adds a vertical line to the plot at the supplied x-value.
 	pub fn vline(&mut self, x_value: impl AsPrimitive<f64>) {
 		self.vlines.push(x_value.as_());
@@ -543,6 +826,89 @@ impl LinePlot {
 		self.points.push((x_value.as_(), y_value.as_()));
 	}
 
+	/// Adds a text label to the plot at the supplied coordinates.
+	pub fn annotate(
+		&mut self,
+		x_value: impl AsPrimitive<f64>,
+		y_value: impl AsPrimitive<f64>,
+		text: impl Into<String>,
+	) {
+		self.annotations.push((x_value.as_(), y_value.as_(), text.into()));
+	}
+
+	/// Adds an arrow to the plot pointing from the first supplied `(x, y)`
+	/// coordinates to the second.
+	pub fn annotate_arrow(
+		&mut self,
+		from: (impl AsPrimitive<f64>, impl AsPrimitive<f64>),
+		to: (impl AsPrimitive<f64>, impl AsPrimitive<f64>),
+	) {
+		self.arrows.push(((from.0.as_(), from.1.as_()), (to.0.as_(), to.1.as_())));
+	}
+
+	/// Returns the `(min, max)` x-axis range of the plot. Explicitly set
+	/// bounds are used if present, otherwise the bounds of the data.
+	pub fn effective_x_range(&self) -> (f64, f64) {
+		(
+			self.x_min.unwrap_or_else(|| self.min_x_value()),
+			self.x_max.unwrap_or_else(|| self.max_x_value()),
+		)
+	}
+
+	/// Returns the `(min, max)` y-axis range of the plot. Explicitly set
+	/// bounds are used if present, otherwise the bounds of the data.
+	pub fn effective_y_range(&self) -> (f64, f64) {
+		(
+			self.y_min.unwrap_or_else(|| self.min_y_value()),
+			self.y_max.unwrap_or_else(|| self.max_y_value()),
+		)
+	}
+
+	/// Returns true if any line or point has a zero or negative value on
+	/// an axis with logarithmic formatting enabled. Such values cannot be
+	/// drawn on a logarithmic scale and are dropped when rendering.
+	pub fn has_nonpositive_on_log_axis(&self) -> bool {
+		let x_values = self.y1_lines
+			.iter()
+			.chain(&self.y2_lines)
+			.flat_map(|line| &line.x_values)
+			.chain(self.points.iter().map(|(x_value, _)| x_value));
+
+		let y_values = self.y1_lines
+			.iter()
+			.flat_map(|line| &line.y_values)
+			.chain(self.points.iter().map(|(_, y_value)| y_value));
+
+		let y2_values = self.y2_lines
+			.iter()
+			.flat_map(|line| &line.y_values);
+
+		(self.format_x_log && has_nonpositive(x_values))
+			|| (self.format_y_log && has_nonpositive(y_values))
+			|| (self.format_y2_log && has_nonpositive(y2_values))
+	}
+
+	fn custom_x_tick_positions(&self) -> Vec<f64> {
+		let (x_min, x_max) = self.effective_x_range();
+
+		let step = self.x_tick
+			.filter(|x_tick| *x_tick > 0.0)
+			.unwrap_or_else(|| math::nice_step(x_max - x_min, DEFAULT_CUSTOM_TICK_COUNT));
+
+		// the positions are computed from the tick index rather than by
+		// accumulating the step to avoid drifting from floating point error
+		let first_index = (x_min / step - 1e-9).ceil();
+		let last_index = (x_max / step + 1e-9).floor();
+
+		if !first_index.is_finite() || !last_index.is_finite() || first_index > last_index {
+			return vec![x_min];
+		}
+
+		(0..=(last_index - first_index) as usize)
+			.map(|index| (first_index + index as f64) * step)
+			.collect()
+	}
+
 	fn min_x_value(&self) -> f64 {
 		let mut min = self.x_min;
 
@@ -570,6 +936,18 @@ impl LinePlot {
 			}
 		}
 
+		for band in &self.bands {
+			let band_min = band.x_values
+				.iter()
+				.min_by(|a, b| a.total_cmp(b))
+				.copied()
+				.unwrap_or(0.0);
+
+			if min.is_none() || min.is_some_and(|value| value > band_min) {
+				min = Some(band_min);
+			}
+		}
+
 		for vline_x in &self.vlines {
 			if min.is_none() || min.is_some_and(|value| value > *vline_x) {
 				min = Some(*vline_x);
@@ -606,6 +984,18 @@ impl LinePlot {
 			}
 		}
 
+		for band in &self.bands {
+			let band_max = band.x_values
+				.iter()
+				.max_by(|a, b| a.total_cmp(b))
+				.copied()
+				.unwrap_or(0.0);
+
+			if max.is_none() || max.is_some_and(|value| value < band_max) {
+				max = Some(band_max);
+			}
+		}
+
 		for vline_x in &self.vlines {
 			if max.is_none() || max.is_some_and(|value| value < *vline_x) {
 				max = Some(*vline_x);
@@ -630,6 +1020,18 @@ impl LinePlot {
 			}
 		}
 
+		for band in &self.bands {
+			let band_min = band.lower_values
+				.iter()
+				.min_by(|a, b| a.total_cmp(b))
+				.copied()
+				.unwrap_or(0.0);
+
+			if min.is_none() || min.is_some_and(|value| value > band_min) {
+				min = Some(band_min);
+			}
+		}
+
 		for hline_y in &self.hlines {
 			if min.is_none() || min.is_some_and(|value| value > *hline_y) {
 				min = Some(*hline_y);
@@ -654,6 +1056,18 @@ impl LinePlot {
 			}
 		}
 
+		for band in &self.bands {
+			let band_max = band.upper_values
+				.iter()
+				.max_by(|a, b| a.total_cmp(b))
+				.copied()
+				.unwrap_or(0.0);
+
+			if max.is_none() || max.is_some_and(|value| value < band_max) {
+				max = Some(band_max);
+			}
+		}
+
 		for hline_y in &self.hlines {
 			if max.is_none() || max.is_some_and(|value| value < *hline_y) {
 				max = Some(*hline_y);
@@ -665,6 +1079,39 @@ impl LinePlot {
 }
 
 impl Line {
+	/// Creates a line of the cumulative distribution of the supplied
+	/// samples. Each point is a sample value and the fraction of samples
+	/// less than or equal to it.
+	pub fn from_cdf(samples: &[f64]) -> Self {
+		let mut line = Line::default();
+
+		let mut sorted = samples.to_vec();
+		sorted.sort_unstable_by(|a, b| a.total_cmp(b));
+
+		for (index, value) in sorted.iter().enumerate() {
+			line.push(*value, (index + 1) as f64 / sorted.len() as f64);
+		}
+
+		line
+	}
+
+	/// Creates a line of the histogram of the supplied samples using the
+	/// supplied number of equal-width bins. Each point is a bin's center
+	/// and the number of samples in the bin.
+	///
+	/// # Panics
+	///
+	/// Panics if the number of bins is zero.
+	pub fn from_histogram(samples: &[f64], bins: usize) -> Self {
+		let mut histogram = Histogram::default().with_auto_bins(bins);
+
+		for sample in samples {
+			histogram.add(*sample);
+		}
+
+		histogram.to_line()
+	}
+
 	/// Checks if the line is empty.
 	pub fn is_empty(&self) -> bool {
 		self.x_values.is_empty()
@@ -698,6 +1145,41 @@ impl Line {
 		self
 	}
 
+	/// Sets how the line's data points are drawn. Lines are drawn with
+	/// connected points by default.
+	pub fn set_render_style(&mut self, render_style: RenderStyle) {
+		self.render_style = render_style;
+	}
+
+	/// Sets how the line's data points are drawn.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::plot::line_plot::{Line, RenderStyle};
+	///
+	/// let mut line = Line::default().with_render_style(RenderStyle::Steps);
+	///
+	/// line.push(0, 1);
+	/// line.push(1, 3);
+	/// ```
+	pub fn with_render_style(mut self, render_style: RenderStyle) -> Self {
+		self.set_render_style(render_style);
+		self
+	}
+
+	/// Fills the area between the line and zero with the line's color at
+	/// the supplied opacity, between 0 (transparent) and 1 (opaque).
+	pub fn set_fill(&mut self, alpha: impl AsPrimitive<f64>) {
+		self.fill_alpha = Some(math::clamp(alpha.as_(), 0.0, 1.0));
+	}
+
+	/// Fills the area between the line and zero with the line's color at
+	/// the supplied opacity, between 0 (transparent) and 1 (opaque).
+	pub fn with_fill(mut self, alpha: impl AsPrimitive<f64>) -> Self {
+		self.set_fill(alpha);
+		self
+	}
+
 	/// Assigns the line to the y2-axis.
 	pub fn set_y2_axis(&mut self) {
 		self.y2_axis = true;
@@ -713,6 +1195,104 @@ impl Line {
 	pub fn push(&mut self, x: impl AsPrimitive<f64>, y: impl AsPrimitive<f64>) {
 		self.x_values.push(x.as_());
 		self.y_values.push(y.as_());
+
+		if self.has_errors() {
+			self.y_errors.push(0.0);
+		}
+	}
+
+	/// Adds the supplied data points to the end of the line.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::plot::line_plot::Line;
+	///
+	/// let mut line = Line::default();
+	///
+	/// line.extend(&[1.0, 2.0], &[10.0, 20.0]);
+	/// line.extend(&[3.0], &[30.0]);
+	///
+	/// assert!(!line.is_empty());
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if `xs` and `ys` have different lengths.
+	pub fn extend(&mut self, xs: &[f64], ys: &[f64]) {
+		assert_eq!(xs.len(), ys.len(), "Line x and y values must have the same length.");
+
+		self.x_values.extend_from_slice(xs);
+		self.y_values.extend_from_slice(ys);
+
+		if self.has_errors() {
+			self.y_errors.resize(self.x_values.len(), 0.0);
+		}
+	}
+
+	/// Adds a data point to the line with an error bar of the supplied
+	/// magnitude in the y-direction.
+	pub fn push_with_error(
+		&mut self,
+		x: impl AsPrimitive<f64>,
+		y: impl AsPrimitive<f64>,
+		y_err: impl AsPrimitive<f64>,
+	) {
+		// points pushed without errors before this one have no error bars
+		self.y_errors.resize(self.x_values.len(), 0.0);
+
+		self.x_values.push(x.as_());
+		self.y_values.push(y.as_());
+		self.y_errors.push(y_err.as_());
+	}
+
+	fn has_errors(&self) -> bool {
+		!self.y_errors.is_empty()
+	}
+
+	fn draw<'a>(&'a self, axes: &mut Axes2D, mut config: Vec<PlotOption<&'a str>>) {
+		match self.render_style {
+			RenderStyle::Lines => {
+				axes.lines(&self.x_values, &self.y_values, &config);
+			},
+
+			RenderStyle::Steps => {
+				let (x_values, y_values) = self.step_values();
+				axes.lines(x_values, y_values, &config);
+			},
+
+			RenderStyle::Points => {
+				config.extend([PointSymbol('o'), PointSize(1.0)]);
+				axes.points(&self.x_values, &self.y_values, &config);
+			},
+
+			RenderStyle::LinesPoints => {
+				config.extend([PointSymbol('o'), PointSize(1.0)]);
+				axes.lines_points(&self.x_values, &self.y_values, &config);
+			},
+		}
+	}
+
+	/// Returns the line's values with a corner inserted before each point,
+	/// so that the previous value holds until the point's x-value.
+	fn step_values(&self) -> (Vec<f64>, Vec<f64>) {
+		let mut x_values = Vec::with_capacity(self.len() * 2);
+		let mut y_values = Vec::with_capacity(self.len() * 2);
+
+		for (x, y) in self.x_values.iter().zip(&self.y_values) {
+			if let Some(previous_y) = y_values.last().copied() {
+				x_values.push(*x);
+				y_values.push(previous_y);
+			}
+
+			x_values.push(*x);
+			y_values.push(*y);
+		}
+
+		(x_values, y_values)
+	}
+
+	fn len(&self) -> usize {
+		self.x_values.len()
 	}
 }
 
@@ -721,11 +1301,377 @@ impl Default for Line {
 		Line {
 			label: None,
 			width: 2.0,
+			render_style: RenderStyle::default(),
 
 			x_values: Vec::new(),
 			y_values: Vec::new(),
+			y_errors: Vec::new(),
 
 			y2_axis: false,
+			fill_alpha: None,
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use rand::Rng;
+
+	use crate::plot::{
+		Plot,
+		DashType,
+		Palette,
+		render,
+		line_plot::{LinePlot, Line, RenderStyle},
+	};
+
+	#[test]
+	fn it_creates_monotonic_cdfs() {
+		let mut rng = rand::rng();
+
+		let samples = (0..1_000)
+			.map(|_| rng.random_range(-50.0..50.0))
+			.collect::<Vec<f64>>();
+
+		let line = Line::from_cdf(&samples);
+
+		assert_eq!(line.x_values.len(), samples.len());
+		assert_eq!(*line.y_values.last().unwrap(), 1.0);
+
+		for index in 1..line.x_values.len() {
+			assert!(line.x_values[index - 1] <= line.x_values[index]);
+			assert!(line.y_values[index - 1] < line.y_values[index]);
+		}
+
+		assert!(line.y_values.iter().all(|y| *y > 0.0 && *y <= 1.0));
+	}
+
+	#[test]
+	fn it_creates_histograms() {
+		let samples = &[1.0, 2.0, 2.5, 3.0, 6.0, 9.0, 10.0, 10.0];
+		let line = Line::from_histogram(samples, 3);
+
+		assert_eq!(line.x_values, vec![2.5, 5.5, 8.5]);
+		assert_eq!(line.y_values, vec![4.0, 1.0, 3.0]);
+
+		let total = line.y_values.iter().sum::<f64>();
+		assert_eq!(total as usize, samples.len());
+	}
+
+	#[test]
+	fn it_creates_histograms_of_constant_samples() {
+		let line = Line::from_histogram(&[5.0, 5.0, 5.0], 4);
+
+		assert_eq!(line.y_values, vec![3.0, 0.0, 0.0, 0.0]);
+		assert!(Line::from_histogram(&[], 4).is_empty());
+	}
+
+	#[test]
+	fn it_configures_error_bars() {
+		let mut plot = LinePlot::default();
+		let mut line = Line::default();
+
+		line.push(0, 1);
+		line.push_with_error(1, 2, 0.5);
+		line.push_with_error(2, 3, 0.25);
+		line.push(3, 4);
+
+		assert_eq!(line.y_errors, vec![0.0, 0.5, 0.25, 0.0]);
+
+		plot.line(line);
+		assert!(!plot.is_empty());
+
+		let script = render(&mut plot);
+		assert!(script.contains("yerrorbars"));
+	}
+
+	#[test]
+	fn it_configures_lines_without_error_bars() {
+		let mut plot = LinePlot::default();
+		let mut line = Line::default();
+
+		line.push(0, 1);
+		line.push(1, 2);
+
+		assert!(line.y_errors.is_empty());
+
+		plot.line(line);
+
+		let script = render(&mut plot);
+		assert!(!script.contains("yerrorbars"));
+	}
+
+	#[test]
+	fn it_keeps_separate_y_and_y2_maximums() {
+		let mut plot = LinePlot::default()
+			.with_y_max(10)
+			.with_y2_max(20);
+
+		let mut y1_line = Line::default();
+		let mut y2_line = Line::default().with_y2_axis();
+
+		y1_line.push(0, 1);
+		y2_line.push(0, 2);
+
+		plot.line(y1_line);
+		plot.line(y2_line);
+
+		assert_eq!(plot.y_max, Some(10.0));
+		assert_eq!(plot.y2_max, Some(20.0));
+
+		let script = render(&mut plot);
+
+		assert!(script.contains("set yrange [*:1.000000000000e1]"));
+		assert!(script.contains("set y2range [*:2.000000000000e1]"));
+	}
+
+	#[test]
+	fn it_computes_effective_ranges() {
+		let mut plot = LinePlot::default();
+		let mut line = Line::default();
+
+		line.push(1, -2);
+		line.push(4, 8);
+		line.push(2, 3);
+
+		plot.line(line);
+		plot.hline(10);
+
+		assert_eq!(plot.effective_x_range(), (1.0, 4.0));
+		assert_eq!(plot.effective_y_range(), (-2.0, 10.0));
+
+		plot.set_x_min(0);
+		plot.set_y_max(5);
+
+		assert_eq!(plot.effective_x_range(), (0.0, 4.0));
+		assert_eq!(plot.effective_y_range(), (-2.0, 5.0));
+	}
+
+	#[test]
+	fn it_detects_nonpositive_values_on_log_axes() {
+		let mut plot = LinePlot::default();
+		let mut line = Line::default();
+
+		line.push(1, 0);
+		line.push(2, 10);
+
+		plot.line(line);
+
+		assert!(!plot.has_nonpositive_on_log_axis());
+
+		plot.set_format_x_log(true);
+		assert!(!plot.has_nonpositive_on_log_axis());
+
+		plot.set_format_y_log(true);
+		assert!(plot.has_nonpositive_on_log_axis());
+	}
+
+	#[test]
+	fn it_configures_annotations() {
+		let mut plot = LinePlot::default();
+		let mut line = Line::default();
+
+		line.push(0, 1);
+		line.push(10, 5);
+
+		plot.line(line);
+		plot.annotate(4, 3, "deploy");
+		plot.annotate_arrow((2, 4), (4, 3));
+
+		assert!(!plot.is_empty());
+
+		let script = render(&mut plot);
+
+		assert!(script.contains("\"deploy\""));
+		assert!(script.contains("set arrow"));
+	}
+
+	#[test]
+	fn it_configures_custom_x_tick_labels() {
+		let days = ["Mon", "Tue", "Wed"];
+
+		let mut plot = LinePlot::default()
+			.with_x_tick(1)
+			.with_format_x_memory(true)
+			.with_x_tick_formatter(move |x| days[x as usize].to_string());
+
+		let mut line = Line::default();
+
+		line.push(0, 3);
+		line.push(1, 5);
+		line.push(2, 4);
+
+		plot.line(line);
+
+		let script = render(&mut plot);
+
+		assert!(script.contains("\"Mon\" 0"));
+		assert!(script.contains("\"Tue\" 1"));
+		assert!(script.contains("\"Wed\" 2"));
+		assert!(!script.contains("%cB"));
+	}
+
+	#[test]
+	fn it_configures_filled_bands() {
+		let mut plot = LinePlot::default();
+
+		let mut lower = Line::default();
+		let mut upper = Line::default();
+		let mut mean = Line::default().with_fill(1.5);
+
+		for x in 0..5 {
+			lower.push(x, x - 1);
+			upper.push(x, x + 2);
+			mean.push(x, x);
+		}
+
+		assert_eq!(mean.fill_alpha, Some(1.0));
+
+		plot.fill_between(lower, upper, "#0060ad", 0.25);
+
+		assert!(!plot.is_empty());
+		assert_eq!(plot.effective_y_range(), (-1.0, 6.0));
+
+		plot.line(mean);
+
+		let script = render(&mut plot);
+
+		assert_eq!(script.matches("filledcurves").count(), 2);
+		assert!(script.contains("transparent solid 2.500000000000e-1"));
+		assert!(script.contains("\"#0060ad\""));
+	}
+
+	#[test]
+	fn it_clears_streamed_data() {
+		let mut plot = LinePlot::default()
+			.with_title("Streaming")
+			.with_x_max(10);
+
+		assert!(plot.is_empty());
+
+		let mut line = Line::default();
+
+		line.extend(&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]);
+		line.push_with_error(4, 7, 1);
+		line.extend(&[5.0], &[8.0]);
+
+		assert_eq!(line.len(), 5);
+		assert_eq!(line.y_errors, vec![0.0, 0.0, 0.0, 1.0, 0.0]);
+
+		plot.line(line);
+		plot.hline(5);
+
+		assert!(!plot.is_empty());
+
+		plot.clear_data();
+
+		assert!(plot.is_empty());
+		assert!(plot.hlines.is_empty());
+		assert_eq!(plot.title.as_deref(), Some("Streaming"));
+		assert_eq!(plot.x_max, Some(10.0));
+	}
+
+	#[test]
+	fn it_configures_the_grid() {
+		let mut line = Line::default();
+
+		line.push(1, 1);
+		line.push(2, 4);
+
+		let mut plot = LinePlot::default();
+		plot.line(line.clone());
+
+		let script = render(&mut plot);
+		assert!(script.contains("set grid xtics ytics"));
+		assert!(script.contains("\"#bbbbbb\""));
+
+		let mut plot = LinePlot::default()
+			.with_grid_style("#333333", 0.5, DashType::Dash);
+
+		plot.line(line.clone());
+
+		let script = render(&mut plot);
+		assert!(script.contains("set grid xtics ytics"));
+		assert!(script.contains("\"#333333\""));
+
+		let mut plot = LinePlot::default()
+			.with_grid(false);
+
+		plot.line(line);
+
+		let script = render(&mut plot);
+		assert!(!script.contains("set grid"));
+		assert!(script.contains("plot"));
+	}
+
+	#[test]
+	fn it_configures_each_render_style() {
+		let mut plot = LinePlot::default();
+
+		for style in [RenderStyle::Lines, RenderStyle::Steps, RenderStyle::Points, RenderStyle::LinesPoints] {
+			let mut line = Line::default().with_render_style(style);
+
+			line.push(0, 1);
+			line.push(1, 3);
+			line.push(2, 2);
+
+			plot.line(line);
+		}
+
+		let script = render(&mut plot);
+
+		assert_eq!(script.matches(" with lines ").count(), 2);
+		assert!(script.contains(" with points "));
+		assert!(script.contains(" with linespoints "));
+
+		let mut steps = Line::default().with_render_style(RenderStyle::Steps);
+
+		steps.extend(&[0.0, 1.0, 2.0], &[1.0, 3.0, 2.0]);
+
+		assert_eq!(
+			steps.step_values(),
+			(vec![0.0, 1.0, 1.0, 2.0, 2.0], vec![1.0, 1.0, 3.0, 3.0, 2.0]),
+		);
+	}
+
+	#[test]
+	fn it_colors_lines_from_the_palette() {
+		let colors = ["#111111", "#222222", "#333333"];
+
+		let mut plot = LinePlot::default()
+			.with_palette(Palette::Custom(colors.iter().map(|color| color.to_string()).collect()));
+
+		for index in 0..4 {
+			let mut line = Line::default();
+
+			line.push(0, index);
+			line.push(1, index + 1);
+
+			plot.line(line);
+		}
+
+		let script = render(&mut plot);
+
+		let indexes = colors
+			.iter()
+			.map(|color| script.find(&format!("\"{color}\"")).unwrap())
+			.collect::<Vec<_>>();
+
+		assert!(indexes.is_sorted());
+		assert_eq!(script.matches("\"#111111\"").count(), 2);
+		assert!(!script.contains("\"#c4342b\""));
+
+		plot.set_palette(Palette::ColorblindSafe);
+
+		let script = render(&mut plot);
+		assert!(script.contains("\"#e69f00\""));
+	}
+
+	#[test]
+	fn it_is_send_and_sync() {
+		fn assert_send_sync<T: Send + Sync>() {}
+
+		assert_send_sync::<LinePlot>();
+		assert_send_sync::<Line>();
+	}
+}
@@ -10,10 +10,22 @@ pub mod line_plot;
 pub mod box_plot;
 pub mod scatter_plot;
 pub mod bar_plot;
+pub mod heatmap;
 
 use std::fmt::Display;
 use num_traits::AsPrimitive;
-use gnuplot::{Axes2D, AutoOption, DashType};
+use gnuplot::{
+	Axes2D,
+	AxesCommon,
+	AutoOption,
+	Coordinate,
+	LegendOption,
+	LabelOption,
+	AlignType,
+	PlotOption::{Color, LineWidth, LineStyle},
+};
+
+pub use gnuplot::DashType;
 
 const COLORS: &[&str] = &[
 	"#c4342b",
@@ -25,6 +37,19 @@ const COLORS: &[&str] = &[
 	"#47a8bd",
 ];
 
+// the Okabe-Ito palette, with black last since it is reserved for
+// axes and text
+const COLORBLIND_SAFE_COLORS: &[&str] = &[
+	"#e69f00",
+	"#56b4e9",
+	"#009e73",
+	"#f0e442",
+	"#0072b2",
+	"#d55e00",
+	"#cc79a7",
+	"#000000",
+];
+
 const DASH_TYPES: &[DashType] = &[
 	DashType::Solid,
 	DashType::Dash,
@@ -33,6 +58,31 @@ const DASH_TYPES: &[DashType] = &[
 	DashType::Dot,
 ];
 
+/// The corner of a plot in which its legend is placed.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum LegendPosition {
+	#[default]
+	TopRight,
+	TopLeft,
+	BottomRight,
+	BottomLeft,
+}
+
+/// The colors cycled through by the lines, bars, or series of a plot.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub enum Palette {
+	#[default]
+	Default,
+
+	/// The Okabe-Ito palette, which remains distinguishable with the
+	/// common forms of color blindness.
+	ColorblindSafe,
+
+	/// The supplied colors (e.g., `"#0060ad"`), in order. An empty list
+	/// uses the default colors.
+	Custom(Vec<String>),
+}
+
 /// Implementing this trait allows the struct to be added to a
 /// plot figure.
 pub trait Plot {
@@ -87,11 +137,135 @@ pub trait Plot {
 		T: Display,
 	;
 
+	/// Returns the `(min, max)` range of the plot's x-axis, or `None` if
+	/// the plot does not have a numeric x-axis.
+	fn x_range(&self) -> Option<(f64, f64)> {
+		None
+	}
+
+	/// Configures the supplied `Gnuplot` `Axes2D` with the plot's data as
+	/// `configure` does, but with the x-axis fixed to the supplied range and
+	/// the x tick labels and x-axis label shown or hidden. The plot's own
+	/// settings are left unchanged. This is used by figures which share an
+	/// x-axis across stacked plots.
+	fn configure_shared_x_axis(
+		&mut self,
+		axes: &mut Axes2D,
+		_x_min: f64,
+		_x_max: f64,
+		_show_tick_labels: bool,
+	) {
+		self.configure(axes);
+	}
+
+	/// Shows or hides the plot's grid lines. The grid is shown by default.
+	/// Plots without a grid (e.g., heatmaps) ignore this.
+	fn set_grid(&mut self, _enabled: bool) {}
+
+	/// Shows or hides the plot's grid lines. The grid is shown by default.
+	/// Plots without a grid (e.g., heatmaps) ignore this.
+	fn with_grid(mut self, enabled: bool) -> Self
+	where
+		Self: Sized,
+	{
+		self.set_grid(enabled);
+		self
+	}
+
+	/// Sets the color, line width, and dash type of the plot's grid lines.
+	/// By default, the grid is drawn with dotted `#bbbbbb` lines of width 2.
+	/// Plots without a grid (e.g., heatmaps) ignore this.
+	fn set_grid_style(&mut self, _color: &str, _width: impl AsPrimitive<f64>, _dash: DashType) {}
+
+	/// Sets the color, line width, and dash type of the plot's grid lines.
+	/// By default, the grid is drawn with dotted `#bbbbbb` lines of width 2.
+	/// Plots without a grid (e.g., heatmaps) ignore this.
+	fn with_grid_style(mut self, color: &str, width: impl AsPrimitive<f64>, dash: DashType) -> Self
+	where
+		Self: Sized,
+	{
+		self.set_grid_style(color, width, dash);
+		self
+	}
+
+	/// Sets the colors cycled through by the plot's lines, bars, or series.
+	/// Plots which are not colored by series (e.g., box plots) ignore this.
+	fn set_palette(&mut self, _palette: Palette) {}
+
+	/// Sets the colors cycled through by the plot's lines, bars, or series.
+	/// Plots which are not colored by series (e.g., box plots) ignore this.
+	fn with_palette(mut self, palette: Palette) -> Self
+	where
+		Self: Sized,
+	{
+		self.set_palette(palette);
+		self
+	}
+
+	/// Removes all of the plot's data while keeping its styling (e.g.,
+	/// labels, ranges, and fonts), so the plot can be refilled with new
+	/// data and redrawn.
+	fn clear_data(&mut self) {}
+
 	/// Configures the supplied `Gnuplot` `Axes2D` with the
 	/// plot's data.
 	fn configure(&mut self, axes: &mut Axes2D);
 }
 
+/// The style of a plot's grid lines.
+#[derive(Clone)]
+struct Grid {
+	enabled: bool,
+
+	color: String,
+	width: f64,
+	dash: DashType,
+}
+
+impl Grid {
+	fn set_style(&mut self, color: &str, width: f64, dash: DashType) {
+		self.color = color.into();
+		self.width = width;
+		self.dash = dash;
+	}
+
+	/// Configures the grid lines of the supplied axes, if the grid is enabled.
+	fn configure(&self, axes: &mut Axes2D, x_grid: bool, y_grid: bool) {
+		axes
+			.set_grid_options(false, &[
+				Color(self.color.as_str()),
+				LineWidth(self.width),
+				LineStyle(self.dash),
+			])
+			.set_x_grid(self.enabled && x_grid)
+			.set_y_grid(self.enabled && y_grid);
+	}
+}
+
+impl Default for Grid {
+	fn default() -> Self {
+		Grid {
+			enabled: true,
+
+			color: "#bbbbbb".into(),
+			width: 2.0,
+			dash: DashType::Dot,
+		}
+	}
+}
+
+impl Palette {
+	/// Returns the color of the series at the supplied index, cycling
+	/// through the palette's colors.
+	fn color(&self, index: usize) -> &str {
+		match self {
+			Palette::Custom(colors) if !colors.is_empty() => &colors[index % colors.len()],
+			Palette::ColorblindSafe => COLORBLIND_SAFE_COLORS[index % COLORBLIND_SAFE_COLORS.len()],
+			_ => COLORS[index % COLORS.len()],
+		}
+	}
+}
+
 fn auto_option(value: Option<f64>) -> AutoOption<f64> {
 	match value {
 		Some(value) => AutoOption::Fix(value),
@@ -99,4 +273,40 @@ fn auto_option(value: Option<f64>) -> AutoOption<f64> {
 	}
 }
 
-pub use crate::plot::figure::Figure;
+fn has_nonpositive<'a>(mut values: impl Iterator<Item = &'a f64>) -> bool {
+	values.any(|value| *value <= 0.0)
+}
+
+impl LegendPosition {
+	fn configure(&self, axes: &mut Axes2D, font: LabelOption<&str>) {
+		let (x, horizontal_align) = match self {
+			LegendPosition::TopLeft | LegendPosition::BottomLeft => (0.02, AlignType::AlignLeft),
+			LegendPosition::TopRight | LegendPosition::BottomRight => (0.98, AlignType::AlignRight),
+		};
+
+		let (y, vertical_align) = match self {
+			LegendPosition::TopLeft | LegendPosition::TopRight => (0.98, AlignType::AlignTop),
+			LegendPosition::BottomLeft | LegendPosition::BottomRight => (0.02, AlignType::AlignBottom),
+		};
+
+		axes.set_legend(
+			Coordinate::Graph(x),
+			Coordinate::Graph(y),
+			&[LegendOption::Placement(horizontal_align, vertical_align)],
+			&[font],
+		);
+	}
+}
+
+#[cfg(test)]
+fn render(plot: &mut impl Plot) -> String {
+	let mut figure = gnuplot::Figure::new();
+	let mut buf = Vec::<u8>::new();
+
+	plot.configure(figure.axes2d());
+	figure.echo(&mut buf);
+
+	String::from_utf8_lossy(&buf).into()
+}
+
+pub use crate::plot::figure::{Figure, OutputFormat};
@@ -12,16 +12,23 @@ use gnuplot::{
 	Axes2D,
 	AxesCommon,
 	PlotOption,
-	Color,
-	LineWidth,
-	LineStyle,
 	DashType,
 	BorderLocation2D,
 	TickOption,
 	LabelOption,
+	Caption,
 };
 
-use crate::plot::{Plot, auto_option};
+use crate::plot::{
+	Plot,
+	LegendPosition,
+	Grid,
+	Palette,
+	auto_option,
+	has_nonpositive,
+};
+
+const POINT_SYMBOLS: &[char] = &['o', 's', 't', 'd', 'x', '+'];
 
 /// A scatter plot.
 #[derive(Default, Clone)]
@@ -49,12 +56,30 @@ pub struct ScatterPlot {
 	format_x_memory: bool,
 	format_y_memory: bool,
 
+	hide_x_tick_labels: bool,
+
+	legend_position: Option<LegendPosition>,
+
+	grid: Grid,
+	palette: Palette,
+
+	points: Vec<(f64, f64)>,
+	series: Vec<ScatterSeries>,
+}
+
+/// A named group of points on a scatter plot which share a color and
+/// symbol, and are shown in the plot's legend.
+#[derive(Clone)]
+pub struct ScatterSeries {
+	label: String,
+	color: Option<String>,
+
 	points: Vec<(f64, f64)>,
 }
 
 impl Plot for ScatterPlot {
 	fn is_empty(&self) -> bool {
-		self.points.is_empty()
+		self.points.is_empty() && self.series.iter().all(|series| series.points.is_empty())
 	}
 
 	fn set_font_type(&mut self, font_type: &str) {
@@ -120,6 +145,45 @@ impl Plot for ScatterPlot {
 		self
 	}
 
+	fn x_range(&self) -> Option<(f64, f64)> {
+		Some(self.effective_x_range())
+	}
+
+	fn configure_shared_x_axis(
+		&mut self,
+		axes: &mut Axes2D,
+		x_min: f64,
+		x_max: f64,
+		show_tick_labels: bool,
+	) {
+		let settings = (self.x_min, self.x_max, self.hide_x_tick_labels);
+
+		self.x_min = Some(x_min);
+		self.x_max = Some(x_max);
+		self.hide_x_tick_labels = !show_tick_labels;
+
+		self.configure(axes);
+
+		(self.x_min, self.x_max, self.hide_x_tick_labels) = settings;
+	}
+
+	fn set_grid(&mut self, enabled: bool) {
+		self.grid.enabled = enabled;
+	}
+
+	fn set_grid_style(&mut self, color: &str, width: impl AsPrimitive<f64>, dash: DashType) {
+		self.grid.set_style(color, width.as_(), dash);
+	}
+
+	fn set_palette(&mut self, palette: Palette) {
+		self.palette = palette;
+	}
+
+	fn clear_data(&mut self) {
+		self.points.clear();
+		self.series.clear();
+	}
+
 	fn configure(&mut self, axes: &mut Axes2D) {
 		let font = LabelOption::Font(
 			self.font_type.as_deref().unwrap_or("Arial"),
@@ -136,7 +200,9 @@ impl Plot for ScatterPlot {
 			TickOption::Inward(false),
 		];
 
-		if self.format_x_memory {
+		if self.hide_x_tick_labels {
+			x_tick_options.push(TickOption::Format(""));
+		} else if self.format_x_memory {
 			x_tick_options.push(TickOption::Format("%.1s %cB"));
 		}
 
@@ -172,20 +238,15 @@ impl Plot for ScatterPlot {
 				Some((auto_option(self.y_tick), 0)),
 				&y_tick_options,
 				&[font],
-			)
-			.set_grid_options(false, &[
-				Color("#bbbbbb"),
-				LineWidth(2.0),
-				LineStyle(DashType::Dot),
-			])
-			.set_x_grid(true)
-			.set_y_grid(true);
+			);
+
+		self.grid.configure(axes, true, true);
 
 		if let Some(title) = &self.title {
 			axes.set_title(title, &[font]);
 		}
 
-		if let Some(x_label) = &self.x_label {
+		if let Some(x_label) = self.x_label.as_ref().filter(|_| !self.hide_x_tick_labels) {
 			axes.set_x_label(x_label, &[font]);
 		}
 
@@ -201,23 +262,48 @@ impl Plot for ScatterPlot {
 			axes.set_y_log(Some(10.0));
 		}
 
-		let mut x_values = Vec::<f64>::new();
-		let mut y_values = Vec::<f64>::new();
+		if let Some(legend_position) = &self.legend_position {
+			legend_position.configure(axes, font);
+		}
+
+		if !self.points.is_empty() {
+			let (x_values, y_values): (Vec<f64>, Vec<f64>) = self.points
+				.iter()
+				.copied()
+				.unzip();
 
-		for (x_value, y_value) in &self.points {
-			x_values.push(*x_value);
-			y_values.push(*y_value);
+			axes.points(
+				x_values,
+				y_values,
+				&[
+					PlotOption::Color("red"),
+					PlotOption::PointSymbol('o'),
+					PlotOption::PointSize(1.0),
+				]
+			);
 		}
 
-		axes.points(
-			x_values,
-			y_values,
-			&[
-				PlotOption::Color("red"),
-				PlotOption::PointSymbol('o'),
-				PlotOption::PointSize(1.0),
-			]
-		);
+		for (index, series) in self.series.iter().enumerate() {
+			let (x_values, y_values): (Vec<f64>, Vec<f64>) = series.points
+				.iter()
+				.copied()
+				.unzip();
+
+			let color = series.color
+				.as_deref()
+				.unwrap_or(self.palette.color(index));
+
+			axes.points(
+				x_values,
+				y_values,
+				&[
+					Caption(&series.label),
+					PlotOption::Color(color),
+					PlotOption::PointSymbol(POINT_SYMBOLS[index % POINT_SYMBOLS.len()]),
+					PlotOption::PointSize(1.0),
+				]
+			);
+		}
 	}
 }
 
@@ -332,8 +418,193 @@ impl ScatterPlot {
 		self
 	}
 
+	/// Sets the position of the plot's legend.
+	pub fn set_legend_position(&mut self, legend_position: LegendPosition) {
+		self.legend_position = Some(legend_position);
+	}
+
+	/// Sets the position of the plot's legend.
+	pub fn with_legend_position(mut self, legend_position: LegendPosition) -> Self {
+		self.set_legend_position(legend_position);
+		self
+	}
+
 	/// Adds a point to the plot at the supplied coordinates.
 	pub fn point(&mut self, x_value: impl AsPrimitive<f64>, y_value: impl AsPrimitive<f64>) {
 		self.points.push((x_value.as_(), y_value.as_()));
 	}
+
+	/// Returns the `(min, max)` x-axis range of the plot. Explicitly set
+	/// bounds are used if present, otherwise the bounds of the data.
+	pub fn effective_x_range(&self) -> (f64, f64) {
+		let (data_min, data_max) = self.data_range(|(x_value, _)| *x_value);
+
+		(
+			self.x_min.unwrap_or(data_min),
+			self.x_max.unwrap_or(data_max),
+		)
+	}
+
+	/// Returns the `(min, max)` y-axis range of the plot. Explicitly set
+	/// bounds are used if present, otherwise the bounds of the data.
+	pub fn effective_y_range(&self) -> (f64, f64) {
+		let (data_min, data_max) = self.data_range(|(_, y_value)| *y_value);
+
+		(
+			self.y_min.unwrap_or(data_min),
+			self.y_max.unwrap_or(data_max),
+		)
+	}
+
+	/// Returns true if any point has a zero or negative value on an axis
+	/// with logarithmic formatting enabled. Such values cannot be drawn on
+	/// a logarithmic scale and are dropped when rendering.
+	pub fn has_nonpositive_on_log_axis(&self) -> bool {
+		let points = || self.points
+			.iter()
+			.chain(self.series.iter().flat_map(|series| &series.points));
+
+		(self.format_x_log && has_nonpositive(points().map(|(x_value, _)| x_value)))
+			|| (self.format_y_log && has_nonpositive(points().map(|(_, y_value)| y_value)))
+	}
+
+	/// Returns the series with the supplied label, adding an empty one
+	/// to the plot if it does not exist. Each series is drawn in its own
+	/// color and symbol and is labeled in the plot's legend.
+	pub fn series<T>(&mut self, label: T) -> &mut ScatterSeries
+	where
+		T: Display,
+	{
+		let label = label.to_string();
+
+		let index = match self.series.iter().position(|series| series.label == label) {
+			Some(index) => index,
+
+			None => {
+				self.series.push(ScatterSeries::new(label));
+				self.series.len() - 1
+			},
+		};
+
+		&mut self.series[index]
+	}
+}
+
+impl ScatterPlot {
+	fn data_range<F>(&self, value: F) -> (f64, f64)
+	where
+		F: Fn(&(f64, f64)) -> f64,
+	{
+		let values = self.points
+			.iter()
+			.chain(self.series.iter().flat_map(|series| &series.points))
+			.map(value);
+
+		let mut range: Option<(f64, f64)> = None;
+
+		for value in values {
+			range = match range {
+				Some((min, max)) => Some((min.min(value), max.max(value))),
+				None => Some((value, value)),
+			};
+		}
+
+		range.unwrap_or((0.0, 0.0))
+	}
+}
+
+impl ScatterSeries {
+	fn new(label: String) -> Self {
+		ScatterSeries {
+			label,
+			color: None,
+
+			points: Vec::new(),
+		}
+	}
+
+	/// Returns true if the series has no points.
+	pub fn is_empty(&self) -> bool {
+		self.points.is_empty()
+	}
+
+	/// Sets the series' color.
+	pub fn set_color<T>(&mut self, color: T)
+	where
+		T: Display,
+	{
+		self.color = Some(color.to_string());
+	}
+
+	/// Adds a point to the series at the supplied coordinates.
+	pub fn point(&mut self, x_value: impl AsPrimitive<f64>, y_value: impl AsPrimitive<f64>) {
+		self.points.push((x_value.as_(), y_value.as_()));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::plot::{
+		Plot,
+		LegendPosition,
+		render,
+		scatter_plot::ScatterPlot,
+	};
+
+	#[test]
+	fn it_configures_labeled_series() {
+		let mut plot = ScatterPlot::default()
+			.with_legend_position(LegendPosition::BottomLeft);
+
+		assert!(plot.is_empty());
+
+		plot.series("first").point(1, 2);
+		plot.series("second").set_color("#000000");
+		plot.series("second").point(3, 4);
+		plot.series("first").point(5, 6);
+
+		assert!(!plot.is_empty());
+		assert_eq!(plot.series.len(), 2);
+		assert_eq!(plot.series[0].points, vec![(1.0, 2.0), (5.0, 6.0)]);
+
+		let script = render(&mut plot);
+
+		assert!(script.contains("t \"first\""));
+		assert!(script.contains("t \"second\""));
+		assert!(script.contains("\"#000000\""));
+		assert!(script.contains("set key"));
+	}
+
+	#[test]
+	fn it_computes_effective_ranges() {
+		let mut plot = ScatterPlot::default();
+
+		assert_eq!(plot.effective_x_range(), (0.0, 0.0));
+
+		plot.point(1, 5);
+		plot.series("first").point(-3, 2);
+		plot.series("second").point(4, 9);
+
+		assert_eq!(plot.effective_x_range(), (-3.0, 4.0));
+		assert_eq!(plot.effective_y_range(), (2.0, 9.0));
+
+		plot.set_x_max(10);
+		plot.set_y_min(0);
+
+		assert_eq!(plot.effective_x_range(), (-3.0, 10.0));
+		assert_eq!(plot.effective_y_range(), (0.0, 9.0));
+	}
+
+	#[test]
+	fn it_detects_nonpositive_values_on_log_axes() {
+		let mut plot = ScatterPlot::default().with_format_y_log(true);
+
+		plot.point(1, 1);
+		plot.series("first").point(-1, 2);
+
+		assert!(!plot.has_nonpositive_on_log_axis());
+
+		plot.series("second").point(3, 0);
+		assert!(plot.has_nonpositive_on_log_axis());
+	}
 }
@@ -6,9 +6,10 @@
  */
 
 use std::{
-	io::{self, Write, StdoutLock},
+	io::{self, Write, Stdout},
 	fmt::Debug,
 	cmp::Ordering,
+	sync::{Arc, Mutex, MutexGuard},
 	time::{Instant, Duration},
 };
 
@@ -23,8 +24,15 @@ const DEFAULT_REMAINING_CHARACTER: char = ' ';
 
 const PULSE_INTERVAL: Duration = Duration::from_secs(1);
 
+const SPINNER_FRAMES: &[char] = &['|', '/', '-', '\\'];
+
 /// Displays a progress bar in terminal
-pub struct Progress {
+pub struct Progress<W = Stdout>
+where
+	W: Write,
+{
+	writer: W,
+
 	width: u64,
 
 	filled_character: char,
@@ -34,15 +42,74 @@ pub struct Progress {
 	total: u64,
 	current: u64,
 
+	spinner_frame: Option<usize>,
 	stopped: bool,
 
 	tags: Vec<Tag>,
+	message: Option<String>,
 
 	rate_count: u64,
 	previous_rate: u64,
 
+	redraw_interval: Duration,
+	redraw_on_every_tick: bool,
+
 	instants: [Option<Instant>; 101],
 	pulse_instant: Instant,
+	draw_instant: Instant,
+}
+
+/// A handle to a progress bar which can be cloned and ticked from
+/// multiple threads. Redraws are throttled in the same way as for the
+/// underlying progress bar.
+pub struct SharedProgress<W = Stdout>
+where
+	W: Write,
+{
+	inner: Arc<Mutex<Progress<W>>>,
+}
+
+/// Displays multiple progress bars in terminal, each on its own line.
+/// Bars are drawn in the order they are added, and redraws from different
+/// threads are serialized so that they do not interleave.
+pub struct MultiProgress<W = Stdout>
+where
+	W: Write,
+{
+	inner: Arc<Mutex<MultiState<W>>>,
+}
+
+/// A handle to one of the progress bars of a `MultiProgress`.
+pub type ProgressHandle<W = Stdout> = SharedProgress<ProgressLine<W>>;
+
+/// The writer of one of the progress bars of a `MultiProgress`, which
+/// draws the bar on its own line.
+pub struct ProgressLine<W>
+where
+	W: Write,
+{
+	index: usize,
+	buf: Vec<u8>,
+
+	multi: Arc<Mutex<MultiState<W>>>,
+}
+
+struct MultiState<W>
+where
+	W: Write,
+{
+	writer: W,
+	lines: usize,
+}
+
+/// The state of a progress bar at a point in time, for reporting progress
+/// without drawing the bar.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressSnapshot {
+	percent: u8,
+	rate: u64,
+	eta: Option<Duration>,
+	elapsed: Duration,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -58,7 +125,7 @@ pub enum Tag {
 }
 
 impl Progress {
-	/// Initializes and prints a new progress bar
+	/// Initializes and prints a new progress bar to stdout
 	///
 	/// # Examples
 	/// ```
@@ -72,16 +139,82 @@ impl Progress {
 	/// Panics if the total is zero.
 	#[must_use]
 	pub fn new(total: impl AsPrimitive<u64>) -> Self {
+		Progress::with_writer(total, io::stdout())
+	}
+
+	/// Initializes and prints a new spinner to stdout. A spinner is used
+	/// when the total is unknown, and is only completed by stopping it.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::progress::Progress;
+	///
+	/// let mut progress = Progress::spinner();
+	///
+	/// progress.tick(10);
+	/// progress.stop();
+	/// ```
+	#[must_use]
+	pub fn spinner() -> Self {
+		Progress::spinner_with_writer(io::stdout())
+	}
+}
+
+impl<W> Progress<W>
+where
+	W: Write,
+{
+	/// Initializes and prints a new progress bar to the supplied writer
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::progress::Progress;
+	///
+	/// let mut buf = Vec::<u8>::new();
+	/// let mut progress = Progress::with_writer(100, &mut buf);
+	///
+	/// progress.tick(100);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the total is zero.
+	#[must_use]
+	pub fn with_writer(total: impl AsPrimitive<u64>, writer: W) -> Self {
 		let total = total.as_();
 
 		assert_ne!(total, 0, "Total cannot be zero.");
 
+		Progress::init(total, None, writer)
+	}
+
+	/// Initializes and prints a new spinner to the supplied writer. A
+	/// spinner has no total and is only completed by stopping it.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::progress::Progress;
+	///
+	/// let mut buf = Vec::<u8>::new();
+	/// let mut progress = Progress::spinner_with_writer(&mut buf);
+	///
+	/// progress.tick(1);
+	/// progress.stop();
+	/// ```
+	#[must_use]
+	pub fn spinner_with_writer(writer: W) -> Self {
+		Progress::init(0, Some(0), writer)
+	}
+
+	fn init(total: u64, spinner_frame: Option<usize>, writer: W) -> Self {
 		let now = Instant::now();
 		let mut instants = [None; 101];
 
 		instants[0] = Some(now);
 
-		let progress = Progress {
+		let mut progress = Progress {
+			writer,
+
 			width: DEFAULT_WIDTH,
 
 			filled_character: DEFAULT_FILLED_CHARACTER,
@@ -91,15 +224,21 @@ impl Progress {
 			total,
 			current: 0,
 
+			spinner_frame,
 			stopped: false,
 
 			tags: Vec::new(),
+			message: None,
 
 			rate_count: 0,
 			previous_rate: 0,
 
+			redraw_interval: PULSE_INTERVAL,
+			redraw_on_every_tick: false,
+
 			instants,
 			pulse_instant: now,
+			draw_instant: now,
 		};
 
 		progress.draw(0, 0, None, Duration::ZERO);
@@ -174,6 +313,57 @@ impl Progress {
 		self
 	}
 
+	/// Sets the maximum time between redraws of the progress bar while
+	/// the displayed percent does not change. The default is one second.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::Duration;
+	/// use kwik::progress::Progress;
+	///
+	/// let mut progress = Progress::new(1_000_000);
+	///
+	/// progress.set_min_redraw_interval(Duration::from_millis(100));
+	/// ```
+	#[inline]
+	pub fn set_min_redraw_interval(&mut self, interval: Duration) {
+		self.redraw_interval = interval;
+	}
+
+	/// Sets the maximum time between redraws of the progress bar while
+	/// the displayed percent does not change. The default is one second.
+	#[inline]
+	#[must_use]
+	pub fn with_min_redraw_interval(mut self, interval: Duration) -> Self {
+		self.set_min_redraw_interval(interval);
+		self
+	}
+
+	/// Enables or disables redrawing the progress bar on every tick,
+	/// regardless of the redraw interval. The default is disabled.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::progress::Progress;
+	///
+	/// let mut progress = Progress::new(10);
+	///
+	/// progress.set_redraw_on_every_tick(true);
+	/// ```
+	#[inline]
+	pub fn set_redraw_on_every_tick(&mut self, value: bool) {
+		self.redraw_on_every_tick = value;
+	}
+
+	/// Enables or disables redrawing the progress bar on every tick,
+	/// regardless of the redraw interval. The default is disabled.
+	#[inline]
+	#[must_use]
+	pub fn with_redraw_on_every_tick(mut self, value: bool) -> Self {
+		self.set_redraw_on_every_tick(value);
+		self
+	}
+
 	/// Adds the supplied tag to the enabled tags.
 	///
 	/// # Examples
@@ -222,18 +412,111 @@ impl Progress {
 		self
 	}
 
+	/// Sets a message which is displayed after the progress bar's tags
+	/// (e.g., the item currently being processed), replacing any previous
+	/// message. The progress bar is redrawn immediately.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::progress::Progress;
+	///
+	/// let mut progress = Progress::new(100);
+	///
+	/// progress.set_message("file_1.txt");
+	/// progress.tick(50);
+	///
+	/// progress.set_message("file_2.txt");
+	/// progress.tick(50);
+	/// ```
+	#[inline]
+	pub fn set_message(&mut self, message: impl Into<String>) {
+		self.message = Some(message.into());
+
+		if !self.stopped {
+			self.redraw();
+		}
+	}
+
+	/// Removes the message displayed after the progress bar's tags.
+	#[inline]
+	pub fn clear_message(&mut self) {
+		self.message = None;
+
+		if !self.stopped {
+			self.redraw();
+		}
+	}
+
+	/// Converts the progress bar into a handle which can be shared
+	/// between threads.
+	///
+	/// # Examples
+	/// ```
+	/// use std::thread;
+	/// use kwik::progress::Progress;
+	///
+	/// let progress = Progress::new(4).into_shared();
+	///
+	/// let handles = (0..4)
+	///     .map(|_| {
+	///         let progress = progress.clone();
+	///         thread::spawn(move || progress.tick(1))
+	///     })
+	///     .collect::<Vec<_>>();
+	///
+	/// for handle in handles {
+	///     handle.join().unwrap();
+	/// }
+	///
+	/// assert!(progress.is_complete());
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn into_shared(self) -> SharedProgress<W> {
+		SharedProgress {
+			inner: Arc::new(Mutex::new(self)),
+		}
+	}
+
 	/// Checks if the progress is complete.
 	#[inline]
 	#[must_use]
 	pub fn is_complete(&self) -> bool {
-		self.current == self.total
+		self.spinner_frame.is_none() && self.current == self.total
+	}
+
+	/// Returns the progress bar's current percent, rate, estimated
+	/// remaining time, and elapsed time, as they would be drawn, without
+	/// writing anything. A spinner's percent is always zero.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::progress::Progress;
+	///
+	/// let mut progress = Progress::with_writer(100, Vec::new());
+	///
+	/// progress.tick(25);
+	///
+	/// assert_eq!(progress.snapshot().percent(), 25);
+	/// ```
+	#[must_use]
+	pub fn snapshot(&self) -> ProgressSnapshot {
+		let now = Instant::now();
+
+		ProgressSnapshot {
+			percent: self.get_progress_amount(self.current) as u8,
+			rate: self.previous_rate,
+			eta: self.get_eta(&now),
+			elapsed: now.saturating_duration_since(self.instants[0].unwrap()),
+		}
 	}
 
 	/// Ticks the progress bar by the supplied amount.
 	///
 	/// # Panics
 	///
-	/// Panics if the tick amount is greater than the total.
+	/// Panics if the tick amount is greater than the total, unless the
+	/// progress bar is a spinner.
 	#[inline]
 	pub fn tick(&mut self, value: impl AsPrimitive<u64>) {
 		self.set(self.current + value.as_());
@@ -243,7 +526,7 @@ impl Progress {
 		assert!(!self.stopped, "Progress bar has been stopped.");
 
 		assert!(
-			value <= self.total,
+			self.spinner_frame.is_some() || value <= self.total,
 			"Progress value ({value}) larger than total ({}).",
 			self.total,
 		);
@@ -259,7 +542,12 @@ impl Progress {
 		let pulse_duration = self.pulse(&now);
 		let rate = self.get_rate(pulse_duration);
 
-		if amount == previous_amount && amount != 100 && pulse_duration.is_none() {
+		let should_redraw = self.redraw_on_every_tick
+			|| amount != previous_amount
+			|| amount == 100
+			|| now.duration_since(self.draw_instant) >= self.redraw_interval;
+
+		if !should_redraw {
 			return;
 		}
 
@@ -277,6 +565,18 @@ impl Progress {
 		self.stopped = amount == 100;
 	}
 
+	fn redraw(&mut self) {
+		let now = Instant::now();
+		let amount = self.get_progress_amount(self.current) as u8;
+
+		self.draw(
+			amount,
+			self.previous_rate,
+			self.get_eta(&now),
+			now - self.instants[0].unwrap(),
+		);
+	}
+
 	/// Stops the progress bar and moves the cursor to a new line.
 	///
 	/// # Examples
@@ -300,7 +600,38 @@ impl Progress {
 		let now = Instant::now();
 		let amount = self.get_progress_amount(self.current) as u8;
 
-		self.draw_final(amount, now - self.instants[0].unwrap());
+		// a spinner has no total, so stopping it is always a success
+		let success = self.spinner_frame.is_some() || amount == 100;
+
+		self.draw_final(amount, now - self.instants[0].unwrap(), success, None);
+	}
+
+	/// Stops the progress bar with the supplied message and moves the cursor
+	/// to a new line. The final bar is drawn in green if `success` is true and
+	/// in red otherwise, regardless of the progress amount. As with `stop`,
+	/// this has no effect if the progress bar has already stopped, including
+	/// when it stopped on reaching its total.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::progress::Progress;
+	///
+	/// let mut progress = Progress::new(100);
+	///
+	/// progress.tick(99);
+	/// progress.finish_with_message("done (3 errors)", false);
+	/// ```
+	pub fn finish_with_message(&mut self, message: &str, success: bool) {
+		if self.stopped {
+			return;
+		}
+
+		self.stopped = true;
+
+		let now = Instant::now();
+		let amount = self.get_progress_amount(self.current) as u8;
+
+		self.draw_final(amount, now - self.instants[0].unwrap(), success, Some(message));
 	}
 
 	#[inline]
@@ -318,6 +649,10 @@ impl Progress {
 
 	#[must_use]
 	fn get_progress_amount(&self, current: u64) -> f64 {
+		if self.spinner_frame.is_some() {
+			return 0.0;
+		}
+
 		100.0 * current as f64 / self.total as f64
 	}
 
@@ -346,54 +681,73 @@ impl Progress {
 	#[must_use]
 	fn get_eta(&self, now: &Instant) -> Option<Duration> {
 		let amount = self.get_progress_amount(self.current);
-		let elapsed = now.duration_since(self.instants[0].unwrap());
+		let elapsed = now.saturating_duration_since(self.instants[0]?);
 
-		if amount as u8 == 100 || elapsed.is_zero() {
+		if self.spinner_frame.is_some() || amount as u8 == 100 || elapsed.is_zero() {
 			return None;
 		}
 
+		self.get_interpolated_eta(now, amount)
+			.or_else(|| self.get_rate_eta(elapsed))
+	}
+
+	/// Estimates the remaining time as the time it took to complete the
+	/// most recent portion of the progress equal in size to the remaining
+	/// portion. Returns `None` if the recorded instants are insufficient or
+	/// would produce an invalid estimate, such as when ticks are bursty.
+	#[must_use]
+	fn get_interpolated_eta(&self, now: &Instant, amount: f64) -> Option<Duration> {
 		let x = amount * 2.0 - 100.0;
 		let x1 = *math::min(&[x, 98.0]).unwrap() as i64;
 
-		if x1 <= 0 || self.instants[x1 as usize].is_none() {
-			let rate = self.current as f64 / elapsed.as_millis() as f64;
+		if x1 <= 0 {
+			return None;
+		}
 
-			if rate == 0.0 {
-				return None;
-			}
+		let y1 = self.instants[x1 as usize]?;
+		let y2 = self.instants[x1 as usize + 1]?;
 
-			let duration_ms = ((self.total - self.current) as f64 / rate) as u64;
-			let duration = Duration::from_millis(duration_ms);
+		let m = y2.checked_duration_since(y1)?;
+		let b = y1.checked_sub(m.checked_mul(x1 as u32)?)?;
 
-			return Some(duration);
-		}
+		let offset = Duration::try_from_secs_f64(m.as_secs_f64() * x).ok()?;
+
+		now.checked_duration_since(b.checked_add(offset)?)
+	}
 
-		let x2 = x1 as usize + 1;
+	/// Estimates the remaining time from the average rate of progress.
+	#[must_use]
+	fn get_rate_eta(&self, elapsed: Duration) -> Option<Duration> {
+		let rate = self.current as f64 / elapsed.as_secs_f64();
 
-		let y1 = self.instants[x1 as usize].unwrap();
-		let y2 = self.instants[x2].unwrap();
+		if rate == 0.0 || !rate.is_finite() {
+			return None;
+		}
 
-		let m = y2 - y1;
-		let b = y1 - m * x1 as u32;
+		let remaining = self.total.saturating_sub(self.current) as f64;
 
-		Some(*now - (b + Duration::from_millis((m.as_millis() as f64 * x) as u64)))
+		Duration::try_from_secs_f64(remaining / rate).ok()
 	}
 
 	fn draw(
-		&self,
+		&mut self,
 		amount: u8,
 		rate: u64,
 		eta: Option<Duration>,
 		elapsed: Duration,
 	) {
+		if self.spinner_frame.is_some() {
+			return self.draw_spinner(rate, elapsed);
+		}
+
 		if amount == 100 {
-			return self.draw_final(amount, elapsed);
+			return self.draw_final(amount, elapsed, true, None);
 		}
 
-		let mut lock = io::stdout().lock();
 		let position = self.get_progress_position(amount);
+		let writer = &mut self.writer;
 
-		write!(lock, "\x1B[2K\r[").unwrap();
+		write!(writer, "\x1B[2K\r[").unwrap();
 
 		for i in 0..self.width {
 			let character = match i.cmp(&position) {
@@ -402,36 +756,53 @@ impl Progress {
 				Ordering::Equal => self.current_character,
 			};
 
-			write!(lock, "\x1B[33m{character}\x1B[0m").unwrap();
+			write!(writer, "\x1B[33m{character}\x1B[0m").unwrap();
 		}
 
-		write!(lock, "] \x1B[33m{amount} %\x1B[0m").unwrap();
+		write!(writer, "] \x1B[33m{amount} %\x1B[0m").unwrap();
 
 		for tag in &self.tags {
 			match tag {
 				Tag::Tps => if rate > 0 {
-					print_rate(&mut lock, rate);
+					print_rate(writer, rate);
 				},
 
 				Tag::Eta => if eta.is_some_and(|eta| !eta.is_zero()) {
-					print_eta(&mut lock, eta.unwrap());
+					print_eta(writer, eta.unwrap());
 				},
 
 				Tag::Time => if !elapsed.is_zero() {
-					print_time(&mut lock, elapsed);
+					print_time(writer, elapsed);
 				},
 			}
 		}
 
-		write!(lock, "\r").unwrap();
-		lock.flush().unwrap();
+		if let Some(message) = &self.message {
+			write!(writer, " {message}").unwrap();
+		}
+
+		write!(writer, "\r").unwrap();
+		writer.flush().unwrap();
+
+		self.draw_instant = Instant::now();
 	}
 
-	fn draw_final(&self, amount: u8, elapsed: Duration) {
-		let mut lock = io::stdout().lock();
+	fn draw_final(
+		&mut self,
+		amount: u8,
+		elapsed: Duration,
+		success: bool,
+		message: Option<&str>,
+	) {
+		if self.spinner_frame.is_some() {
+			return self.draw_spinner_final(elapsed, success, message);
+		}
+
 		let position = self.get_progress_position(amount);
+		let color = final_color(success);
+		let writer = &mut self.writer;
 
-		write!(lock, "\x1B[2K[").unwrap();
+		write!(writer, "\x1B[2K[").unwrap();
 
 		for i in 0..self.width {
 			let character = match i.cmp(&position) {
@@ -440,48 +811,601 @@ impl Progress {
 				Ordering::Equal => self.current_character,
 			};
 
-			if amount < 100 {
-				write!(lock, "\x1B[31m{character}\x1B[0m").unwrap();
-			} else {
-				write!(lock, "\x1B[32m{character}\x1B[0m").unwrap();
+			write!(writer, "\x1B[{color}m{character}\x1B[0m").unwrap();
+		}
+
+		write!(writer, "] \x1B[{color}m{amount} %\x1B[0m").unwrap();
+
+		if self.tags.contains(&Tag::Time) {
+			print_time(writer, elapsed);
+		}
+
+		if let Some(message) = message {
+			write!(writer, " \x1B[{color}m{message}\x1B[0m").unwrap();
+		}
+
+		writeln!(writer).unwrap();
+		writer.flush().unwrap();
+	}
+
+	fn draw_spinner(&mut self, rate: u64, elapsed: Duration) {
+		let frame = self.spinner_frame.unwrap();
+		let writer = &mut self.writer;
+
+		write!(
+			writer,
+			"\x1B[2K\r\x1B[33m{} {}\x1B[0m",
+			SPINNER_FRAMES[frame],
+			fmt::number(self.current),
+		).unwrap();
+
+		for tag in &self.tags {
+			match tag {
+				Tag::Tps => if rate > 0 {
+					print_rate(writer, rate);
+				},
+
+				Tag::Eta => {},
+
+				Tag::Time => if !elapsed.is_zero() {
+					print_time(writer, elapsed);
+				},
 			}
 		}
 
-		if amount < 100 {
-			write!(lock, "] \x1B[31m{amount} %\x1B[0m").unwrap();
-		} else {
-			write!(lock, "] \x1B[32m{amount} %\x1B[0m").unwrap();
+		if let Some(message) = &self.message {
+			write!(writer, " {message}").unwrap();
 		}
 
+		write!(writer, "\r").unwrap();
+		writer.flush().unwrap();
+
+		self.spinner_frame = Some((frame + 1) % SPINNER_FRAMES.len());
+		self.draw_instant = Instant::now();
+	}
+
+	fn draw_spinner_final(&mut self, elapsed: Duration, success: bool, message: Option<&str>) {
+		let color = final_color(success);
+		let writer = &mut self.writer;
+
+		write!(
+			writer,
+			"\x1B[2K\x1B[{color}m{}\x1B[0m",
+			fmt::number(self.current),
+		).unwrap();
+
 		if self.tags.contains(&Tag::Time) {
-			print_time(&mut lock, elapsed);
+			print_time(writer, elapsed);
 		}
 
-		writeln!(lock).unwrap();
-		lock.flush().unwrap();
+		if let Some(message) = message {
+			write!(writer, " \x1B[{color}m{message}\x1B[0m").unwrap();
+		}
+
+		writeln!(writer).unwrap();
+		writer.flush().unwrap();
+	}
+}
+
+impl<W> SharedProgress<W>
+where
+	W: Write,
+{
+	/// Checks if the progress is complete.
+	#[inline]
+	#[must_use]
+	pub fn is_complete(&self) -> bool {
+		self.lock().is_complete()
+	}
+
+	/// Returns the progress bar's current percent, rate, estimated
+	/// remaining time, and elapsed time without drawing it.
+	#[inline]
+	#[must_use]
+	pub fn snapshot(&self) -> ProgressSnapshot {
+		self.lock().snapshot()
+	}
+
+	/// Ticks the progress bar by the supplied amount.
+	///
+	/// # Panics
+	///
+	/// Panics if the tick amount is greater than the total.
+	#[inline]
+	pub fn tick(&self, value: impl AsPrimitive<u64>) {
+		self.lock().tick(value);
+	}
+
+	/// Ticks the progress bar by one.
+	///
+	/// # Panics
+	///
+	/// Panics if the progress bar is already complete.
+	#[inline]
+	pub fn inc(&self) {
+		self.tick(1);
+	}
+
+	/// Sets a message which is displayed after the progress bar's tags,
+	/// replacing any previous message.
+	#[inline]
+	pub fn set_message(&self, message: impl Into<String>) {
+		self.lock().set_message(message);
+	}
+
+	/// Stops the progress bar and moves the cursor to a new line.
+	#[inline]
+	pub fn stop(&self) {
+		self.lock().stop();
+	}
+
+	/// Stops the progress bar with the supplied message, drawn in green if
+	/// `success` is true and in red otherwise, and moves the cursor to a
+	/// new line.
+	#[inline]
+	pub fn finish_with_message(&self, message: &str, success: bool) {
+		self.lock().finish_with_message(message, success);
+	}
+
+	fn lock(&self) -> MutexGuard<'_, Progress<W>> {
+		self.inner
+			.lock()
+			.expect("Could not lock shared progress.")
 	}
 }
 
-fn print_rate(lock: &mut StdoutLock, rate: u64) {
+impl<W> Clone for SharedProgress<W>
+where
+	W: Write,
+{
+	fn clone(&self) -> Self {
+		SharedProgress {
+			inner: Arc::clone(&self.inner),
+		}
+	}
+}
+
+impl MultiProgress {
+	/// Initializes a new set of progress bars which are printed to stdout.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::progress::MultiProgress;
+	///
+	/// let multi = MultiProgress::new();
+	///
+	/// let first = multi.add(100);
+	/// let second = multi.add(100);
+	///
+	/// first.tick(100);
+	/// second.tick(100);
+	/// ```
+	#[must_use]
+	pub fn new() -> Self {
+		MultiProgress::with_writer(io::stdout())
+	}
+}
+
+impl Default for MultiProgress {
+	fn default() -> Self {
+		MultiProgress::new()
+	}
+}
+
+impl<W> MultiProgress<W>
+where
+	W: Write,
+{
+	/// Initializes a new set of progress bars which are printed to the
+	/// supplied writer.
+	#[must_use]
+	pub fn with_writer(writer: W) -> Self {
+		let state = MultiState {
+			writer,
+			lines: 0,
+		};
+
+		MultiProgress {
+			inner: Arc::new(Mutex::new(state)),
+		}
+	}
+
+	/// Adds a new progress bar with the supplied total on a new line below
+	/// the existing bars, and returns a handle to it.
+	///
+	/// # Panics
+	///
+	/// Panics if the total is zero.
+	#[must_use]
+	pub fn add(&self, total: impl AsPrimitive<u64>) -> ProgressHandle<W> {
+		let index = {
+			let mut state = self.inner
+				.lock()
+				.expect("Could not lock multi progress.");
+
+			// reserves a line for the new bar, leaving the cursor
+			// below all of the bars
+			writeln!(state.writer).unwrap();
+			state.lines += 1;
+
+			state.lines - 1
+		};
+
+		let line = ProgressLine {
+			index,
+			buf: Vec::new(),
+
+			multi: Arc::clone(&self.inner),
+		};
+
+		Progress::with_writer(total, line).into_shared()
+	}
+}
+
+impl<W> Write for ProgressLine<W>
+where
+	W: Write,
+{
+	fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+		self.buf.extend_from_slice(buf);
+		Ok(buf.len())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		let mut state = self.multi
+			.lock()
+			.map_err(|_| io::Error::other("Could not lock multi progress."))?;
+
+		let offset = state.lines - self.index;
+
+		// newlines are dropped since the bar's line is fixed, and the
+		// cursor is always returned below all of the bars
+		self.buf.retain(|byte| *byte != b'\n');
+
+		write!(state.writer, "\x1B[{offset}A")?;
+		state.writer.write_all(&self.buf)?;
+		write!(state.writer, "\r\x1B[{offset}B")?;
+
+		self.buf.clear();
+		state.writer.flush()
+	}
+}
+
+/// Returns the color code of a final progress bar.
+fn final_color(success: bool) -> u8 {
+	match success {
+		true => 32,
+		false => 31,
+	}
+}
+
+fn print_rate(writer: &mut impl Write, rate: u64) {
 	write!(
-		lock,
+		writer,
 		" ({} tps)",
 		fmt::number(rate),
 	).unwrap();
 }
 
-fn print_eta(lock: &mut StdoutLock, eta: Duration) {
+fn print_eta(writer: &mut impl Write, eta: Duration) {
 	write!(
-		lock,
+		writer,
 		" (eta {})",
 		fmt::timespan(eta.as_millis()),
 	).unwrap();
 }
 
-fn print_time(lock: &mut StdoutLock, elapsed: Duration) {
+fn print_time(writer: &mut impl Write, elapsed: Duration) {
 	write!(
-		lock,
+		writer,
 		" (time {})",
 		fmt::timespan(elapsed.as_millis()),
 	).unwrap();
 }
+
+impl ProgressSnapshot {
+	/// Returns the percent of the total which is complete.
+	#[inline]
+	pub fn percent(&self) -> u8 {
+		self.percent
+	}
+
+	/// Returns the number of ticks per second over the most recent
+	/// second, or zero if a second has not yet passed.
+	#[inline]
+	pub fn rate(&self) -> u64 {
+		self.rate
+	}
+
+	/// Returns the estimated remaining time, if it can be estimated.
+	#[inline]
+	pub fn eta(&self) -> Option<Duration> {
+		self.eta
+	}
+
+	/// Returns the time since the progress bar started.
+	#[inline]
+	pub fn elapsed(&self) -> Duration {
+		self.elapsed
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		thread,
+		io::{self, Write},
+		sync::{Arc, Mutex},
+		time::{Duration, Instant},
+	};
+
+	use crate::progress::{Progress, MultiProgress, Tag};
+
+	#[derive(Clone, Default)]
+	struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+	impl Write for SharedBuf {
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+			self.0.lock().unwrap().write(buf)
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn it_ticks_shared_progress_from_threads() {
+		let progress = Progress::with_writer(8 * 1_000, io::sink()).into_shared();
+
+		let handles = (0..8)
+			.map(|_| {
+				let progress = progress.clone();
+
+				thread::spawn(move || {
+					for _ in 0..1_000 {
+						progress.inc();
+					}
+				})
+			})
+			.collect::<Vec<_>>();
+
+		for handle in handles {
+			handle.join().unwrap();
+		}
+
+		assert!(progress.is_complete());
+	}
+
+	#[test]
+	fn it_draws_to_writers() {
+		let mut buf = Vec::<u8>::new();
+
+		let mut progress = Progress::with_writer(10, &mut buf)
+			.with_width(10)
+			.with_tag(Tag::Time);
+
+		progress.tick(5);
+		progress.tick(5);
+
+		assert!(progress.is_complete());
+
+		let output = String::from_utf8(buf).unwrap();
+
+		assert!(output.contains("\x1B[33m50 %"));
+		assert!(output.contains("\x1B[32m100 %"));
+		assert!(output.contains("(time "));
+		assert!(output.ends_with('\n'));
+	}
+
+	#[test]
+	fn it_draws_messages() {
+		let mut buf = Vec::<u8>::new();
+		let mut progress = Progress::with_writer(10, &mut buf);
+
+		progress.set_message("a_long_message");
+		progress.tick(5);
+		progress.set_message("short");
+		progress.tick(5);
+
+		let output = String::from_utf8(buf).unwrap();
+		let lines = output.split("\x1B[2K").collect::<Vec<_>>();
+
+		assert!(lines.iter().any(|line| line.ends_with(" a_long_message\r")));
+		assert!(lines.iter().any(|line| line.ends_with(" short\r")));
+		assert!(!lines.iter().any(|line| line.contains("a_long_message") && line.contains("short")));
+	}
+
+	#[test]
+	fn it_finishes_with_messages() {
+		let mut buf = Vec::<u8>::new();
+		let mut progress = Progress::with_writer(10, &mut buf);
+
+		progress.tick(10);
+		progress.finish_with_message("ignored", false);
+
+		let mut failed_buf = Vec::<u8>::new();
+		let mut failed_progress = Progress::with_writer(10, &mut failed_buf);
+
+		failed_progress.tick(9);
+		failed_progress.finish_with_message("done (3 errors)", false);
+		failed_progress.finish_with_message("ignored", true);
+
+		let mut succeeded_buf = Vec::<u8>::new();
+		let mut succeeded_progress = Progress::with_writer(10, &mut succeeded_buf);
+
+		succeeded_progress.tick(5);
+		succeeded_progress.finish_with_message("skipped the rest", true);
+
+		let output = String::from_utf8(buf).unwrap();
+		let failed_output = String::from_utf8(failed_buf).unwrap();
+		let succeeded_output = String::from_utf8(succeeded_buf).unwrap();
+
+		assert!(!output.contains("ignored"));
+
+		assert!(failed_output.ends_with("\x1B[31m90 %\x1B[0m \x1B[31mdone (3 errors)\x1B[0m\n"));
+		assert!(!failed_output.contains("ignored"));
+
+		assert!(succeeded_output.ends_with("\x1B[32m50 %\x1B[0m \x1B[32mskipped the rest\x1B[0m\n"));
+	}
+
+	#[test]
+	fn it_redraws_on_every_tick_when_forced() {
+		let mut buf = Vec::<u8>::new();
+		let mut progress = Progress::with_writer(1_000, &mut buf);
+
+		for _ in 0..10 {
+			progress.tick(1);
+		}
+
+		progress.set_redraw_on_every_tick(true);
+
+		for _ in 0..10 {
+			progress.tick(1);
+		}
+
+		let output = String::from_utf8(buf).unwrap();
+
+		// initial draw, the draw at 1 %, and every forced tick
+		assert_eq!(output.matches("\x1B[2K").count(), 12);
+	}
+
+	#[test]
+	fn it_spins_without_a_total() {
+		let mut buf = Vec::<u8>::new();
+
+		let mut progress = Progress::spinner_with_writer(&mut buf)
+			.with_redraw_on_every_tick(true)
+			.with_tag(Tag::Eta);
+
+		for _ in 0..5 {
+			progress.tick(1_000);
+		}
+
+		assert!(!progress.is_complete());
+		progress.stop();
+
+		let output = String::from_utf8(buf).unwrap();
+
+		for frame in ['|', '/', '-', '\\'] {
+			assert!(output.contains(&format!("\x1B[33m{frame} ")));
+		}
+
+		assert!(output.contains("\x1B[33m/ 5,000"));
+		assert!(!output.contains('%'));
+		assert!(!output.contains("eta"));
+		assert!(output.ends_with("\x1B[32m5,000\x1B[0m\n"));
+	}
+
+	#[test]
+	fn it_draws_multiple_bars_on_separate_lines() {
+		let buf = SharedBuf::default();
+		let multi = MultiProgress::with_writer(buf.clone());
+
+		let first = multi.add(10);
+		let second = multi.add(20);
+
+		let handles = [(first.clone(), 10), (second.clone(), 20)]
+			.into_iter()
+			.map(|(progress, total)| thread::spawn(move || {
+				for _ in 0..total {
+					progress.inc();
+				}
+			}))
+			.collect::<Vec<_>>();
+
+		for handle in handles {
+			handle.join().unwrap();
+		}
+
+		assert!(first.is_complete());
+		assert!(second.is_complete());
+
+		let output = String::from_utf8(buf.0.lock().unwrap().clone()).unwrap();
+
+		// each draw moves up to its bar's line and back down below the bars
+		let draws = output.split("\r\x1B").collect::<Vec<_>>();
+
+		assert!(draws.iter().any(|draw| draw.contains("\x1B[2A\x1B[2K[") && draw.contains("100 %")));
+		assert!(draws.iter().any(|draw| draw.contains("\x1B[1A\x1B[2K[") && draw.contains("100 %")));
+
+		assert!(!output.contains("\x1B[3A"));
+		assert_eq!(output.matches("100 %").count(), 2);
+		assert_eq!(output.matches('\n').count(), 2);
+	}
+
+	#[test]
+	fn it_estimates_eta_for_bursty_progress() {
+		let mut progress = Progress::with_writer(1_000, io::sink());
+
+		for index in 0..20 {
+			progress.tick(1);
+
+			if index % 5 == 0 {
+				thread::sleep(Duration::from_millis(10));
+			}
+		}
+
+		progress.tick(600);
+
+		for index in 0..50 {
+			progress.tick(2);
+
+			if index % 10 == 0 {
+				thread::sleep(Duration::from_millis(5));
+			}
+		}
+
+		let eta = progress.get_eta(&Instant::now()).unwrap();
+		assert!(eta < Duration::from_secs(60));
+	}
+
+	#[test]
+	fn it_falls_back_to_rate_eta_for_invalid_instants() {
+		let mut progress = Progress::with_writer(100, io::sink());
+		let start = progress.instants[0].unwrap();
+
+		progress.tick(75);
+
+		// the instants are not monotonic in time
+		progress.instants[50] = Some(start + Duration::from_millis(500));
+		progress.instants[51] = Some(start);
+
+		let eta = progress.get_eta(&(start + Duration::from_secs(3))).unwrap();
+		assert_eq!(eta, Duration::from_secs(1));
+
+		// the interpolated instant would be in the future
+		progress.instants[50] = Some(start + Duration::from_secs(5));
+		progress.instants[51] = Some(start + Duration::from_secs(6));
+
+		let eta = progress.get_eta(&(start + Duration::from_secs(3))).unwrap();
+		assert_eq!(eta, Duration::from_secs(1));
+
+		// the current instant precedes the first instant
+		assert!(progress.get_eta(&(start - Duration::from_millis(1))).is_none());
+	}
+
+	#[test]
+	fn it_snapshots_progress_without_drawing() {
+		let buf = SharedBuf::default();
+		let mut progress = Progress::with_writer(10, buf.clone());
+
+		thread::sleep(Duration::from_millis(10));
+		progress.tick(5);
+
+		let written = buf.0.lock().unwrap().len();
+		let snapshot = progress.snapshot();
+
+		assert_eq!(buf.0.lock().unwrap().len(), written);
+		assert_eq!(snapshot.percent(), 50);
+		assert!(snapshot.elapsed() >= Duration::from_millis(10));
+		assert!(snapshot.eta().is_some_and(|eta| eta < Duration::from_secs(60)));
+
+		progress.tick(5);
+
+		let snapshot = progress.snapshot();
+
+		assert_eq!(snapshot.percent(), 100);
+		assert!(snapshot.eta().is_none());
+	}
+}
@@ -5,6 +5,8 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+use std::time::Duration;
+
 use thiserror::Error;
 use sysinfo::{System, Pid as SysPid, ProcessesToUpdate};
 use crate::sys::Pid;
@@ -59,3 +61,73 @@ pub fn usage(pid: Option<Pid>) -> Result<f64, CpuError> {
 		None => Err(CpuError::InvalidPid(pid)),
 	}
 }
+
+/// Returns the total CPU time (user and system) consumed by the supplied pid
+/// since it started. If no pid is supplied, the CPU time of the current process
+/// is returned. Time spent on multiple CPUs is summed, so the CPU time may be
+/// larger than the time the process has been running.
+///
+/// # Examples
+/// ```
+/// use kwik::sys::cpu;
+///
+/// // returns the CPU time of the current process
+/// match cpu::cpu_time(None) {
+///     Ok(value) => {
+///         // process CPU time
+///     },
+///
+///     Err(err) => {
+///         // handle error
+///     },
+/// }
+/// ```
+///
+/// # Errors
+///
+/// This function will return an error if an invalid pid is supplied.
+pub fn cpu_time(pid: Option<Pid>) -> Result<Duration, CpuError> {
+	let pid = pid.unwrap_or(std::process::id());
+	let sys_pid = SysPid::from_u32(pid);
+
+	let mut sys = System::new();
+	sys.refresh_processes(ProcessesToUpdate::Some(&[sys_pid]), true);
+
+	match sys.process(sys_pid) {
+		Some(process) => Ok(Duration::from_millis(process.accumulated_cpu_time())),
+		None => Err(CpuError::InvalidPid(pid)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		hint,
+		time::{Duration, Instant},
+	};
+
+	use crate::sys::cpu;
+
+	#[test]
+	fn it_returns_the_cpu_time_of_the_current_process() {
+		let before = cpu::cpu_time(None).unwrap();
+		let start = Instant::now();
+
+		let mut value = 0u64;
+		let mut after = before;
+
+		// the process may get little of a core on a loaded machine, so the
+		// work continues until the cpu time advances rather than for a
+		// fixed amount of it
+		while after == before && start.elapsed() < Duration::from_secs(30) {
+			for _ in 0..1_000_000 {
+				value = hint::black_box(value.wrapping_mul(31).wrapping_add(7));
+			}
+
+			after = cpu::cpu_time(None).unwrap();
+		}
+
+		assert!(after > before);
+		assert!(cpu::cpu_time(Some(u32::MAX)).is_err());
+	}
+}
@@ -7,12 +7,23 @@
 
 use std::{
 	mem,
+	fmt::{self, Display, Formatter},
 	str::FromStr,
-	process::Command,
+	process::{self, Command},
+	collections::HashMap,
+	thread::{self, JoinHandle},
+	time::Duration,
+	sync::{
+		mpsc,
+		Arc,
+		Mutex,
+		OnceLock,
+		atomic::{AtomicU64, Ordering},
+	},
 };
 
 use thiserror::Error;
-use sysinfo::System;
+use sysinfo::{System, Pid as SysPid, ProcessesToUpdate};
 
 use crate::{
 	file::{
@@ -33,10 +44,31 @@ pub enum MemError {
 	#[error("could not clear memory HWM")]
 	Clear,
 
+	#[error("process with id `{0}` not found")]
+	InvalidPid(Pid),
+
 	#[error("an internal error occurred")]
 	Internal,
 }
 
+/// A summary of the memory usage of a process and the system, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemReport {
+	rss: u64,
+	hwm: u64,
+	total: u64,
+}
+
+/// Tracks the peak resident set size of a process by polling it on a
+/// background thread. This approximates the high water mark on platforms
+/// where the kernel does not report one.
+pub struct PeakWatcher {
+	peak: Arc<AtomicU64>,
+
+	stop_sender: Option<mpsc::Sender<()>>,
+	thread: Option<JoinHandle<()>>,
+}
+
 /// Returns a parsed status member from the process status file.
 /// If a pid is supplied, the status member of that process is returned;
 /// otherwise, the status member of the current process is returned.
@@ -73,23 +105,39 @@ where
 		.map_err(|_| MemError::Internal)?;
 
 	for line in reader {
-		if line.starts_with(key) {
-			let parsed = &line[key.len() + 1..line.len() - 2]
-				.trim()
-				.parse::<T>();
-
-			return match parsed {
-				Ok(value) => Ok(*value),
-				Err(_) => Err(MemError::InvalidStat(key.to_string())),
-			}
+		if let Some(parsed) = parse_stat_line(&line, key) {
+			return parsed;
 		}
 	}
 
 	Err(MemError::InvalidStat(key.to_string()))
 }
 
+/// Parses the value of a `key: value [unit]` status line. If the line
+/// is not for the supplied key, `None` is returned.
+fn parse_stat_line<T>(line: &str, key: &str) -> Option<Result<T, MemError>>
+where
+	T: FromStr,
+{
+	let (name, value) = line.split_once(':')?;
+
+	if name.trim() != key {
+		return None;
+	}
+
+	let parsed = value
+		.split_whitespace()
+		.next()
+		.and_then(|value| value.parse::<T>().ok())
+		.ok_or_else(|| MemError::InvalidStat(key.to_string()));
+
+	Some(parsed)
+}
+
 /// Returns the high water mark of the supplied pid in bytes. If no pid
 /// is supplied, the high water mark of the current process is returned.
+/// On platforms without `/proc`, this is the largest resident set size
+/// observed by calls to `hwm` and `rss` for the pid.
 ///
 /// # Examples
 /// ```
@@ -113,7 +161,11 @@ where
 /// be determined.
 #[inline]
 pub fn hwm(pid: Option<Pid>) -> Result<u64, MemError> {
-	stat::<u64>("VmHWM", pid).map(|value| value * 1024)
+	match stat::<u64>("VmHWM", pid) {
+		Ok(value) => Ok(value * 1024),
+		Err(MemError::Internal) => rss(pid).map(|value| track_peak(pid, value)),
+		Err(err) => Err(err),
+	}
 }
 
 /// Returns the resident set size of the supplied pid in bytes. If no pid
@@ -141,7 +193,140 @@ pub fn hwm(pid: Option<Pid>) -> Result<u64, MemError> {
 /// be determined.
 #[inline]
 pub fn rss(pid: Option<Pid>) -> Result<u64, MemError> {
-	stat::<u64>("VmRSS", pid).map(|value| value * 1024)
+	match stat::<u64>("VmRSS", pid) {
+		Ok(value) => Ok(value * 1024),
+		Err(MemError::Internal) => process_memory(pid).map(|value| track_peak(pid, value)),
+		Err(err) => Err(err),
+	}
+}
+
+/// Returns the resident set size of the supplied pid in bytes using
+/// `sysinfo`, for platforms where `/proc` is not available.
+fn process_memory(pid: Option<Pid>) -> Result<u64, MemError> {
+	let pid = pid.unwrap_or(process::id());
+	let sys_pid = SysPid::from_u32(pid);
+
+	let mut sys = System::new();
+	sys.refresh_processes(ProcessesToUpdate::Some(&[sys_pid]), true);
+
+	match sys.process(sys_pid) {
+		Some(process) => Ok(process.memory()),
+		None => Err(MemError::InvalidPid(pid)),
+	}
+}
+
+/// Records the supplied resident set size of the pid and returns the
+/// largest one recorded for it.
+fn track_peak(pid: Option<Pid>, value: u64) -> u64 {
+	static PEAKS: OnceLock<Mutex<HashMap<Pid, u64>>> = OnceLock::new();
+
+	let pid = pid.unwrap_or(process::id());
+
+	let mut peaks = PEAKS
+		.get_or_init(Default::default)
+		.lock()
+		.expect("Could not lock memory peaks.");
+
+	if !peaks.contains_key(&pid) {
+		// processes which have exited are forgotten before a new one is
+		// tracked, so the peaks do not grow without bound and a reused
+		// pid does not inherit an old peak
+		remove_exited(&mut peaks);
+	}
+
+	let peak = peaks.entry(pid).or_default();
+
+	*peak = (*peak).max(value);
+	*peak
+}
+
+/// Removes the peaks of processes which are no longer running.
+fn remove_exited(peaks: &mut HashMap<Pid, u64>) {
+	if peaks.is_empty() {
+		return;
+	}
+
+	let sys_pids = peaks
+		.keys()
+		.map(|pid| SysPid::from_u32(*pid))
+		.collect::<Vec<_>>();
+
+	let mut sys = System::new();
+	sys.refresh_processes(ProcessesToUpdate::Some(&sys_pids), true);
+
+	peaks.retain(|pid, _| sys.process(SysPid::from_u32(*pid)).is_some());
+}
+
+impl PeakWatcher {
+	/// Starts polling the resident set size of the supplied pid at the
+	/// supplied interval. If no pid is supplied, the current process
+	/// is watched.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::Duration;
+	/// use kwik::sys::mem::PeakWatcher;
+	///
+	/// let mut watcher = PeakWatcher::spawn(None, Duration::from_millis(10));
+	///
+	/// // do work here
+	///
+	/// watcher.stop();
+	/// assert!(watcher.peak() > 0);
+	/// ```
+	#[must_use]
+	pub fn spawn(pid: Option<Pid>, interval: Duration) -> Self {
+		let peak = Arc::new(AtomicU64::new(0));
+		let (stop_sender, stop_receiver) = mpsc::channel::<()>();
+
+		let thread = {
+			let peak = Arc::clone(&peak);
+
+			thread::spawn(move || loop {
+				// samples which could not be read are skipped, since the
+				// process may be briefly unavailable
+				if let Ok(value) = rss(pid) {
+					peak.fetch_max(value, Ordering::Relaxed);
+				}
+
+				match stop_receiver.recv_timeout(interval) {
+					Err(mpsc::RecvTimeoutError::Timeout) => continue,
+					_ => break,
+				}
+			})
+		};
+
+		PeakWatcher {
+			peak,
+
+			stop_sender: Some(stop_sender),
+			thread: Some(thread),
+		}
+	}
+
+	/// Returns the largest resident set size observed so far in bytes.
+	#[inline]
+	#[must_use]
+	pub fn peak(&self) -> u64 {
+		self.peak.load(Ordering::Relaxed)
+	}
+
+	/// Stops polling and waits for the background thread to exit. The
+	/// peak remains available after stopping. This is also done when the
+	/// watcher is dropped.
+	pub fn stop(&mut self) {
+		drop(self.stop_sender.take());
+
+		if let Some(thread) = self.thread.take() {
+			let _ = thread.join();
+		}
+	}
+}
+
+impl Drop for PeakWatcher {
+	fn drop(&mut self) {
+		self.stop();
+	}
 }
 
 /// Returns the total physical memory of the system in bytes.
@@ -166,6 +351,39 @@ pub fn total() -> u64 {
 	sys.total_memory()
 }
 
+/// Returns the resident set size and high water mark of the supplied pid,
+/// along with the system's total memory. If no pid is supplied, the
+/// current process is reported.
+///
+/// # Examples
+/// ```
+/// use kwik::sys::mem;
+///
+/// let report = mem::report(None).unwrap();
+///
+/// // e.g., "RSS 12.0 MiB / HWM 14.0 MiB / Total 16.0 GiB"
+/// println!("{report}");
+/// ```
+///
+/// # Errors
+///
+/// This function returns an error if the resident set size or high
+/// water mark could not be determined.
+pub fn report(pid: Option<Pid>) -> Result<MemReport, MemError> {
+	// the resident set size is read first so it cannot exceed the
+	// high water mark
+	let rss = rss(pid)?;
+	let hwm = hwm(pid)?;
+
+	let report = MemReport {
+		rss,
+		hwm,
+		total: total(),
+	};
+
+	Ok(report)
+}
+
 /// Clears the memory refs of the supplied pid. If no pid is supplied,
 /// clears the memory refs of the current process.
 ///
@@ -239,3 +457,128 @@ pub fn size_of_vec<T>(value: &Vec<T>) -> usize {
 
 	container_size + value.len() * size_of(&value[0])
 }
+
+impl MemReport {
+	/// Returns the resident set size of the process in bytes.
+	#[inline]
+	#[must_use]
+	pub fn rss(&self) -> u64 {
+		self.rss
+	}
+
+	/// Returns the high water mark of the process in bytes.
+	#[inline]
+	#[must_use]
+	pub fn hwm(&self) -> u64 {
+		self.hwm
+	}
+
+	/// Returns the total memory of the system in bytes.
+	#[inline]
+	#[must_use]
+	pub fn total(&self) -> u64 {
+		self.total
+	}
+}
+
+impl Display for MemReport {
+	fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"RSS {} / HWM {} / Total {}",
+			crate::fmt::memory(self.rss, Some(1)),
+			crate::fmt::memory(self.hwm, Some(1)),
+			crate::fmt::memory(self.total, Some(1)),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		hint,
+		thread,
+		time::Duration,
+	};
+
+	use crate::sys::mem;
+
+	#[test]
+	fn it_returns_the_rss_of_the_current_process() {
+		let rss = mem::rss(None).unwrap();
+		let hwm = mem::hwm(None).unwrap();
+
+		assert!(rss > 0);
+		assert!(hwm > 0);
+	}
+
+	#[test]
+	fn it_reports_memory_usage() {
+		let report = mem::report(None).unwrap();
+
+		assert!(report.rss() > 0);
+		assert!(report.rss() <= report.hwm());
+		assert!(report.total() > 0);
+
+		let formatted = report.to_string();
+
+		assert!(formatted.starts_with("RSS "));
+		assert!(formatted.contains(" / HWM "));
+		assert!(formatted.contains(" / Total "));
+	}
+
+	#[test]
+	fn it_returns_process_memory_without_proc() {
+		let memory = mem::process_memory(None).unwrap();
+		assert!(memory > 0);
+
+		assert_eq!(mem::track_peak(Some(u32::MAX), 10), 10);
+		assert_eq!(mem::track_peak(Some(u32::MAX), 5), 10);
+		assert_eq!(mem::track_peak(Some(u32::MAX), 20), 20);
+
+		// the pid does not exist, so it is forgotten once another is tracked
+		mem::track_peak(Some(u32::MAX - 1), 1);
+		assert_eq!(mem::track_peak(Some(u32::MAX), 5), 5);
+	}
+
+	#[test]
+	fn it_parses_stat_lines() {
+		let hwm = mem::parse_stat_line::<u64>("VmHWM:  1234 kB", "VmHWM");
+		assert!(matches!(hwm, Some(Ok(1234))));
+
+		let threads = mem::parse_stat_line::<u64>("Threads:\t8", "Threads");
+		assert!(matches!(threads, Some(Ok(8))));
+
+		let other = mem::parse_stat_line::<u64>("VmHWMExtra: 1 kB", "VmHWM");
+		assert!(other.is_none());
+
+		let malformed = mem::parse_stat_line::<u64>("VmHWM: kB", "VmHWM");
+		assert!(matches!(malformed, Some(Err(mem::MemError::InvalidStat(_)))));
+
+		let empty = mem::parse_stat_line::<u64>("VmHWM:", "VmHWM");
+		assert!(matches!(empty, Some(Err(mem::MemError::InvalidStat(_)))));
+	}
+
+	#[test]
+	fn it_watches_the_peak_rss() {
+		let mut watcher = mem::PeakWatcher::spawn(None, Duration::from_millis(5));
+
+		thread::sleep(Duration::from_millis(50));
+
+		let initial_peak = watcher.peak();
+		assert!(initial_peak > 0);
+
+		let buf = vec![1u8; 32 * 1024 * 1024];
+		hint::black_box(&buf);
+
+		thread::sleep(Duration::from_millis(100));
+		drop(buf);
+
+		watcher.stop();
+
+		let peak = watcher.peak();
+
+		assert!(peak >= initial_peak + 16 * 1024 * 1024);
+		assert!(watcher.thread.is_none());
+	}
+}
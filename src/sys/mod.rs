@@ -8,4 +8,12 @@
 pub mod mem;
 pub mod cpu;
 
+mod sampler;
+mod process;
+
 pub type Pid = u32;
+
+pub use crate::sys::{
+	sampler::{ResourceSampler, SamplerError},
+	process::{process_exists, wait_for_exit},
+};
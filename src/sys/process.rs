@@ -0,0 +1,102 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::{
+	thread,
+	time::{Duration, Instant},
+};
+
+use sysinfo::{System, Pid as SysPid, ProcessesToUpdate, ProcessStatus};
+use crate::sys::Pid;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Returns true if a live process with the supplied pid exists. Processes
+/// which have exited but have not yet been reaped by their parent (i.e.,
+/// zombies) are not considered live.
+///
+/// # Examples
+/// ```
+/// use kwik::sys;
+///
+/// assert!(sys::process_exists(std::process::id()));
+/// ```
+#[must_use]
+pub fn process_exists(pid: Pid) -> bool {
+	let sys_pid = SysPid::from_u32(pid);
+
+	let mut sys = System::new();
+	sys.refresh_processes(ProcessesToUpdate::Some(&[sys_pid]), true);
+
+	match sys.process(sys_pid) {
+		Some(process) => !matches!(process.status(), ProcessStatus::Zombie | ProcessStatus::Dead),
+		None => false,
+	}
+}
+
+/// Waits for the process with the supplied pid to exit, returning true
+/// if it exited before the timeout elapsed.
+///
+/// # Examples
+/// ```no_run
+/// use std::{process::Command, time::Duration};
+/// use kwik::sys;
+///
+/// let mut child = Command::new("true").spawn().unwrap();
+///
+/// assert!(sys::wait_for_exit(child.id(), Duration::from_secs(5)));
+/// child.wait().unwrap();
+/// ```
+pub fn wait_for_exit(pid: Pid, timeout: Duration) -> bool {
+	let start = Instant::now();
+
+	loop {
+		if !process_exists(pid) {
+			return true;
+		}
+
+		let elapsed = start.elapsed();
+
+		if elapsed >= timeout {
+			return false;
+		}
+
+		thread::sleep(POLL_INTERVAL.min(timeout - elapsed));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		process,
+		time::Duration,
+	};
+
+	use crate::sys;
+
+	#[test]
+	fn it_checks_process_existence() {
+		assert!(sys::process_exists(process::id()));
+		assert!(!sys::process_exists(u32::MAX));
+	}
+
+	#[test]
+	#[cfg(unix)]
+	fn it_waits_for_exit() {
+		use std::process::Command;
+
+		let mut child = Command::new("sleep")
+			.arg("0.05")
+			.spawn()
+			.unwrap();
+
+		assert!(!sys::wait_for_exit(process::id(), Duration::from_millis(20)));
+		assert!(sys::wait_for_exit(child.id(), Duration::from_secs(5)));
+
+		child.wait().unwrap();
+	}
+}
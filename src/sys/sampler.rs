@@ -0,0 +1,121 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::time::Instant;
+use thiserror::Error;
+
+use crate::{
+	tma::TimeMovingAverage,
+	sys::{
+		Pid,
+		mem::{self, MemError},
+		cpu::{self, CpuError},
+	},
+};
+
+/// Records the memory and CPU usage of a process over time.
+pub struct ResourceSampler {
+	pid: Option<Pid>,
+
+	rss: TimeMovingAverage,
+	cpu: TimeMovingAverage,
+}
+
+#[derive(Debug, Error)]
+pub enum SamplerError {
+	#[error(transparent)]
+	Mem(#[from] MemError),
+
+	#[error(transparent)]
+	Cpu(#[from] CpuError),
+}
+
+impl ResourceSampler {
+	/// Creates a new sampler for the supplied pid. If no pid is supplied,
+	/// the current process is sampled.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::sys::ResourceSampler;
+	///
+	/// let sampler = ResourceSampler::new(None);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn new(pid: Option<Pid>) -> Self {
+		ResourceSampler {
+			pid,
+
+			rss: TimeMovingAverage::default(),
+			cpu: TimeMovingAverage::default(),
+		}
+	}
+
+	/// Records the current resident set size (in bytes) and CPU usage
+	/// (between [0, 1]) of the process.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::sys::ResourceSampler;
+	///
+	/// let mut sampler = ResourceSampler::new(None);
+	///
+	/// if let Err(err) = sampler.sample() {
+	///     // handle error
+	/// }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function returns an error if the memory or CPU usage of the
+	/// process could not be determined.
+	pub fn sample(&mut self) -> Result<(), SamplerError> {
+		let rss = mem::rss(self.pid)?;
+		let cpu = cpu::usage(self.pid)?;
+
+		let now = Instant::now();
+
+		self.rss.push(now, rss);
+		self.cpu.push(now, cpu);
+
+		Ok(())
+	}
+
+	/// Returns the recorded resident set sizes in bytes.
+	#[inline]
+	#[must_use]
+	pub fn rss_series(&self) -> &TimeMovingAverage {
+		&self.rss
+	}
+
+	/// Returns the recorded CPU usages between [0, 1].
+	#[inline]
+	#[must_use]
+	pub fn cpu_series(&self) -> &TimeMovingAverage {
+		&self.cpu
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::sys::ResourceSampler;
+
+	#[test]
+	fn it_samples_the_current_process() {
+		let mut sampler = ResourceSampler::new(None);
+
+		assert!(sampler.rss_series().is_empty());
+		assert!(sampler.cpu_series().is_empty());
+
+		for count in 1..=3 {
+			sampler.sample().unwrap();
+
+			assert_eq!(sampler.rss_series().len(), count);
+			assert_eq!(sampler.cpu_series().len(), count);
+		}
+	}
+}
@@ -5,21 +5,43 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+#[derive(Clone)]
 pub struct Cell {
 	value: String,
 	align: Align,
 	style: Style,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Align {
 	Left,
 	Right,
 	Center,
+
+	/// Aligns numeric values in a column on their decimal points.
+	/// Non-numeric values are aligned right.
+	Decimal,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Style {
 	Bold,
 	Normal,
+	Color(Color),
+	BoldColor(Color),
+}
+
+/// A terminal foreground color of a cell.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Color {
+	Black,
+	Red,
+	Green,
+	Yellow,
+	Blue,
+	Magenta,
+	Cyan,
+	White,
 }
 
 impl Cell {
@@ -40,32 +62,173 @@ impl Cell {
 		&self.value
 	}
 
+	#[inline]
+	pub fn align(&self) -> Align {
+		self.align
+	}
+
+	#[inline]
+	pub fn style(&self) -> Style {
+		self.style
+	}
+
+	/// Returns the visible width of the value, excluding any ANSI escape
+	/// sequences it contains.
 	#[inline]
 	pub fn size(&self) -> usize {
-		self.value.len()
+		visible_width(&self.value)
+	}
+
+	/// Splits the cell into cells no wider than the supplied width,
+	/// breaking the value between words where possible.
+	pub fn wrap(&self, width: usize) -> Vec<Cell> {
+		if self.size() <= width || width == 0 {
+			return vec![self.clone()];
+		}
+
+		let mut lines = Vec::<String>::new();
+		let mut line = String::new();
+
+		for word in self.value.split_whitespace() {
+			let mut word = word.chars().collect::<Vec<char>>();
+
+			if !line.is_empty() && line.chars().count() + 1 + word.len() <= width {
+				line.push(' ');
+				line.extend(&word);
+				continue;
+			}
+
+			if !line.is_empty() {
+				lines.push(line);
+			}
+
+			while word.len() > width {
+				lines.push(word.drain(..width).collect());
+			}
+
+			line = word.into_iter().collect();
+		}
+
+		if !line.is_empty() || lines.is_empty() {
+			lines.push(line);
+		}
+
+		lines
+			.into_iter()
+			.map(|value| Cell::new(value, self.align, self.style))
+			.collect()
+	}
+
+	/// Returns the lengths of the integer and fractional (including the
+	/// decimal point) parts of the value if it is a decimal-aligned number.
+	#[inline]
+	pub fn decimal_parts(&self) -> Option<(usize, usize)> {
+		if !matches!(self.align, Align::Decimal) || self.value.parse::<f64>().is_err() {
+			return None;
+		}
+
+		let integer_len = self.value
+			.find('.')
+			.unwrap_or(self.value.len());
+
+		Some((integer_len, self.value.len() - integer_len))
 	}
 
+	/// Returns the value padded to the supplied size. Decimal-aligned
+	/// numbers are first padded to the supplied integer and fractional
+	/// part lengths of the column.
 	#[inline]
-	pub fn to_sized_string(&self, size: usize) -> String {
+	pub fn to_sized_string(
+		&self,
+		size: usize,
+		decimal_lens: Option<(usize, usize)>,
+	) -> String {
+		// padding is computed from the visible width since the formatting
+		// width would also count any escape sequences in the value
+		let padding = size.saturating_sub(self.size());
+
 		let string = match &self.align {
-			Align::Left => format!("{:<size$}", self.value),
-			Align::Right => format!("{:>size$}", self.value),
-
-			Align::Center => {
-				let before = (size as f64 - self.value.len() as f64) / 2.0;
-				let after = (size as f64 - self.value.len() as f64) / 2.0;
-
-				format!(
-					"{:before$}{}{:after$}", "", self.value, "",
-					before = before.floor() as usize,
-					after = after.ceil() as usize,
-				)
+			Align::Left => format!("{}{}", self.value, " ".repeat(padding)),
+			Align::Right => format!("{}{}", " ".repeat(padding), self.value),
+
+			Align::Decimal => match (self.decimal_parts(), decimal_lens) {
+				(Some((integer_len, _)), Some((max_integer_len, max_fraction_len))) => {
+					let (integer, fraction) = self.value.split_at(integer_len);
+					let value = format!("{integer:>max_integer_len$}{fraction:<max_fraction_len$}");
+
+					format!("{value:>size$}")
+				},
+
+				_ => format!("{}{}", " ".repeat(padding), self.value),
 			},
+
+			Align::Center => format!(
+				"{}{}{}",
+				" ".repeat(padding / 2),
+				self.value,
+				" ".repeat(padding - padding / 2),
+			),
 		};
 
 		match &self.style {
 			Style::Bold => format!("\x1B[1m{string}\x1B[0m"),
 			Style::Normal => string,
+			Style::Color(color) => format!("\x1B[{}m{string}\x1B[0m", color.ansi_code()),
+			Style::BoldColor(color) => format!("\x1B[1;{}m{string}\x1B[0m", color.ansi_code()),
+		}
+	}
+}
+
+impl Color {
+	/// Returns the ANSI foreground color code of the color.
+	fn ansi_code(&self) -> u8 {
+		match self {
+			Color::Black => 30,
+			Color::Red => 31,
+			Color::Green => 32,
+			Color::Yellow => 33,
+			Color::Blue => 34,
+			Color::Magenta => 35,
+			Color::Cyan => 36,
+			Color::White => 37,
+		}
+	}
+
+	/// Returns the CSS name of the color.
+	pub(crate) fn css_name(&self) -> &'static str {
+		match self {
+			Color::Black => "black",
+			Color::Red => "red",
+			Color::Green => "green",
+			Color::Yellow => "yellow",
+			Color::Blue => "blue",
+			Color::Magenta => "magenta",
+			Color::Cyan => "cyan",
+			Color::White => "white",
+		}
+	}
+}
+
+fn visible_width(value: &str) -> usize {
+	let mut width = 0;
+	let mut chars = value.chars();
+
+	while let Some(character) = chars.next() {
+		if character != '\x1B' {
+			width += 1;
+			continue;
+		}
+
+		// skips the parameters of a control sequence up to and
+		// including its final byte
+		if chars.next() == Some('[') {
+			for character in chars.by_ref() {
+				if ('@'..='~').contains(&character) {
+					break;
+				}
+			}
 		}
 	}
+
+	width
 }
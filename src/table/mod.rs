@@ -11,30 +11,115 @@ mod cell;
 use std::{
 	path::Path,
 	io::{self, Write},
-	collections::HashSet,
+	collections::{HashSet, HashMap},
 };
 
 use crate::file::{
 	FileWriter,
-	csv::CsvWriter,
+	csv::{CsvWriter, WriteRow, RowData},
 };
 
+use crate::table::cell::Cell;
+
 pub use crate::table::{
 	row::{Row, ColumnJoinType},
-	cell::{Align, Style},
+	cell::{Align, Style, Color},
 };
 
 #[derive(Default)]
 pub struct Table {
 	header: Option<Row>,
 	rows: Vec<Row>,
+	footer: Option<Row>,
 	spacers: HashSet<usize>,
 
+	max_column_widths: HashMap<usize, usize>,
+
 	row_len: usize,
 }
 
 /// Prints a table to a stream.
 impl Table {
+	/// Creates a table with a row for each of the supplied items, using
+	/// the same `WriteRow` implementation as the CSV writer. Each value is
+	/// left-aligned. If any headers are supplied, they are set as the
+	/// table's header row in bold, centered cells.
+	///
+	/// # Examples
+	/// ```
+	/// use std::io;
+	///
+	/// use kwik::{
+	///     file::csv::{WriteRow, RowData},
+	///     table::Table,
+	/// };
+	///
+	/// struct MyStruct {
+	///     name: String,
+	///     count: u32,
+	/// }
+	///
+	/// impl WriteRow for MyStruct {
+	///     fn as_row(&self, row: &mut RowData) -> io::Result<()> {
+	///         row.push(&self.name);
+	///         row.push(self.count);
+	///
+	///         Ok(())
+	///     }
+	/// }
+	///
+	/// let items = vec![
+	///     MyStruct { name: "a".into(), count: 10 },
+	///     MyStruct { name: "b".into(), count: 2 },
+	/// ];
+	///
+	/// let table = Table::from_rows(&[], &items).unwrap();
+	///
+	/// let mut stdout = Vec::new();
+	/// table.print(&mut stdout);
+	///
+	/// assert_eq!(stdout, b"| a | 10 |\n| b | 2  |\n");
+	/// ```
+	///
+	/// # Errors
+	///
+	/// This function will return an error if an item could not be
+	/// converted to a row.
+	///
+	/// # Panics
+	///
+	/// Panics if the items' rows and the headers do not all have the same
+	/// number of columns.
+	pub fn from_rows<T>(headers: &[&str], items: &[T]) -> io::Result<Self>
+	where
+		T: WriteRow,
+	{
+		let mut table = Table::default();
+
+		if !headers.is_empty() {
+			let header = headers
+				.iter()
+				.fold(Row::default(), |row, header| row.push(header.to_string(), Align::Center, Style::Bold));
+
+			table.set_header(header);
+		}
+
+		for item in items {
+			let mut row_data = RowData::default();
+			item.as_row(&mut row_data)?;
+
+			let mut row = Row::default();
+
+			for index in 0..row_data.len() {
+				row = row.push(row_data.get(index)?.to_string(), Align::Left, Style::Normal);
+			}
+
+			table.add_row(row);
+		}
+
+		Ok(table)
+	}
+
 	/// Sets the table's header row. The header row is followed by a spacer
 	/// row by default.
 	///
@@ -137,6 +222,128 @@ impl Table {
 		self.spacers.insert(index);
 	}
 
+	/// Sets the table's footer row. The footer row is printed after all
+	/// other rows and is preceded by a spacer row.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::table::{Table, Row, Align, Style};
+	///
+	/// let mut table = Table::default();
+	///
+	/// table.add_row(Row::default().push("Row 1", Align::Left, Style::Normal));
+	/// table.set_footer(Row::default().push("Total", Align::Left, Style::Normal));
+	///
+	/// let mut stdout = Vec::new();
+	/// table.print(&mut stdout);
+	///
+	/// assert_eq!(stdout, b"| Row 1 |\n|-------|\n| Total |\n");
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the footer length does not match the existing row length.
+	#[inline]
+	pub fn set_footer(&mut self, footer: Row) {
+		assert!(
+			(self.rows.is_empty() && self.header.is_none()) || footer.len() == self.row_len,
+			"Invalid number of columns in row.",
+		);
+
+		self.row_len = footer.len();
+		self.footer = Some(footer);
+	}
+
+	/// Sets the table's footer row to the totals of the supplied columns
+	/// across all rows. The first column is labeled `Total` if it is not
+	/// one of the supplied columns, and all other columns are left blank.
+	/// Empty cells are skipped, and if a supplied column contains a value
+	/// which is not numeric, its total is left blank.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::table::{Table, Row, Align, Style};
+	///
+	/// let mut table = Table::default();
+	///
+	/// table.add_row(
+	///     Row::default()
+	///         .push("a", Align::Left, Style::Normal)
+	///         .push(1, Align::Right, Style::Normal)
+	/// );
+	///
+	/// table.add_row(
+	///     Row::default()
+	///         .push("b", Align::Left, Style::Normal)
+	///         .push(2, Align::Right, Style::Normal)
+	/// );
+	///
+	/// table.add_totals_row(&[1]);
+	///
+	/// let mut stdout = Vec::new();
+	/// table.print(&mut stdout);
+	///
+	/// assert_eq!(stdout, b"| a     | 1 |\n| b     | 2 |\n|-------+---|\n| Total | 3 |\n");
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if a supplied column is out of bounds.
+	pub fn add_totals_row(&mut self, columns: &[usize]) {
+		assert!(
+			columns.iter().all(|column| *column < self.row_len),
+			"Column index out of bounds.",
+		);
+
+		let aligns = self.column_aligns();
+		let mut footer = Row::default();
+
+		for (index, align) in aligns.into_iter().enumerate() {
+			footer = match (columns.contains(&index), index) {
+				(true, _) => match self.column_total(index) {
+					Some(total) => footer.push(total, align, Style::Normal),
+					None => footer.push("", align, Style::Normal),
+				},
+
+				(false, 0) => footer.push("Total", align, Style::Normal),
+				(false, _) => footer.push("", align, Style::Normal),
+			};
+		}
+
+		self.set_footer(footer);
+	}
+
+	/// Sets the maximum width of the column at the supplied index. Cells
+	/// wider than the maximum are wrapped onto additional lines.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::table::{Table, Row, Align, Style};
+	///
+	/// let mut table = Table::default();
+	///
+	/// let row = Row::default()
+	///     .push("Row 1 is long", Align::Left, Style::Normal)
+	///     .push("Row 1", Align::Left, Style::Normal);
+	///
+	/// table.add_row(row);
+	/// table.set_max_column_width(0, 5);
+	///
+	/// let mut stdout = Vec::new();
+	/// table.print(&mut stdout);
+	///
+	/// assert_eq!(stdout, b"| Row 1 | Row 1 |\n| is    |       |\n| long  |       |\n");
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the width is zero.
+	#[inline]
+	pub fn set_max_column_width(&mut self, column: usize, width: usize) {
+		assert_ne!(width, 0, "Width cannot be zero.");
+		self.max_column_widths.insert(column, width);
+	}
+
 	/// Prints the table to the supplied stream.
 	///
 	/// # Examples
@@ -162,6 +369,7 @@ impl Table {
 	pub fn print(&self, stdout: &mut impl Write) {
 		let mut index: usize = 0;
 		let column_lens = self.max_column_lens();
+		let decimal_lens = self.max_decimal_lens();
 
 		if self.spacers.contains(&index) {
 			print_spacer_row(stdout, &column_lens);
@@ -170,7 +378,7 @@ impl Table {
 		if let Some(header) = &self.header {
 			index += 1;
 
-			header.print(stdout, &column_lens, ColumnJoinType::Spaced);
+			header.print_aligned(stdout, &column_lens, &decimal_lens, ColumnJoinType::Spaced);
 
 			if self.spacers.contains(&index) {
 				print_spacer_row(stdout, &column_lens);
@@ -180,12 +388,124 @@ impl Table {
 		for row in &self.rows {
 			index += 1;
 
-			row.print(stdout, &column_lens, ColumnJoinType::Spaced);
+			row.print_aligned(stdout, &column_lens, &decimal_lens, ColumnJoinType::Spaced);
 
 			if self.spacers.contains(&index) {
 				print_spacer_row(stdout, &column_lens);
 			}
 		}
+
+		if let Some(footer) = &self.footer {
+			if !self.spacers.contains(&index) {
+				print_spacer_row(stdout, &column_lens);
+			}
+
+			footer.print_aligned(stdout, &column_lens, &decimal_lens, ColumnJoinType::Spaced);
+		}
+	}
+
+	/// Prints the table to the supplied stream as a GitHub-flavored
+	/// Markdown table. Spacer rows are omitted and bold cells are
+	/// emphasized with `**`.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::table::{Table, Row, Align, Style};
+	///
+	/// let mut table = Table::default();
+	///
+	/// let header = Row::default()
+	///     .push("Header 1", Align::Center, Style::Bold);
+	///
+	/// let row = Row::default()
+	///     .push("Row 1", Align::Left, Style::Normal);
+	///
+	/// table.set_header(header);
+	/// table.add_row(row);
+	///
+	/// let mut stdout = Vec::new();
+	/// table.print_markdown(&mut stdout);
+	///
+	/// assert_eq!(stdout, b"| **Header 1** |\n| :--- |\n| Row 1 |\n");
+	/// ```
+	pub fn print_markdown(&self, stdout: &mut impl Write) {
+		let header_values = match &self.header {
+			Some(header) => header.cells()
+				.iter()
+				.map(markdown_value)
+				.collect::<Vec<String>>(),
+
+			None => vec![String::new(); self.row_len],
+		};
+
+		let separators = self.column_aligns()
+			.iter()
+			.map(|align| match align {
+				Align::Left => ":---",
+				Align::Right | Align::Decimal => "---:",
+				Align::Center => ":---:",
+			})
+			.collect::<Vec<&str>>();
+
+		writeln!(stdout, "| {} |", header_values.join(" | ")).unwrap();
+		writeln!(stdout, "| {} |", separators.join(" | ")).unwrap();
+
+		for row in self.rows.iter().chain(&self.footer) {
+			let values = row.cells()
+				.iter()
+				.map(markdown_value)
+				.collect::<Vec<String>>();
+
+			writeln!(stdout, "| {} |", values.join(" | ")).unwrap();
+		}
+	}
+
+	/// Prints the table to the supplied stream as an HTML table. Spacer
+	/// rows are omitted and bold cells are wrapped in `<b>` tags.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::table::{Table, Row, Align, Style};
+	///
+	/// let mut table = Table::default();
+	///
+	/// let row = Row::default()
+	///     .push("Row 1", Align::Left, Style::Normal);
+	///
+	/// table.add_row(row);
+	///
+	/// let mut stdout = Vec::new();
+	/// table.print_html(&mut stdout);
+	///
+	/// assert_eq!(
+	///     stdout,
+	///     b"<table>\n<tbody>\n<tr><td style=\"text-align: left\">Row 1</td></tr>\n</tbody>\n</table>\n",
+	/// );
+	/// ```
+	pub fn print_html(&self, stdout: &mut impl Write) {
+		writeln!(stdout, "<table>").unwrap();
+
+		if let Some(header) = &self.header {
+			writeln!(stdout, "<thead>").unwrap();
+			writeln!(stdout, "<tr>{}</tr>", html_cells(header, "th")).unwrap();
+			writeln!(stdout, "</thead>").unwrap();
+		}
+
+		writeln!(stdout, "<tbody>").unwrap();
+
+		for row in &self.rows {
+			writeln!(stdout, "<tr>{}</tr>", html_cells(row, "td")).unwrap();
+		}
+
+		writeln!(stdout, "</tbody>").unwrap();
+
+		if let Some(footer) = &self.footer {
+			writeln!(stdout, "<tfoot>").unwrap();
+			writeln!(stdout, "<tr>{}</tr>", html_cells(footer, "td")).unwrap();
+			writeln!(stdout, "</tfoot>").unwrap();
+		}
+
+		writeln!(stdout, "</table>").unwrap();
 	}
 
 	/// Writes the table to the file at the supplied path.
@@ -222,7 +542,7 @@ impl Table {
 			writer.write_row(header).unwrap();
 		}
 
-		for row in &self.rows {
+		for row in self.rows.iter().chain(&self.footer) {
 			writer.write_row(row).unwrap();
 		}
 
@@ -242,7 +562,7 @@ impl Table {
 			}
 		}
 
-		for row in &self.rows {
+		for row in self.rows.iter().chain(&self.footer) {
 			for (index, size) in sizes.iter_mut().enumerate() {
 				let row_column_size = row.get_column_size(index);
 
@@ -252,20 +572,416 @@ impl Table {
 			}
 		}
 
+		for (size, (integer_len, fraction_len)) in sizes.iter_mut().zip(self.max_decimal_lens()) {
+			if integer_len + fraction_len > *size {
+				*size = integer_len + fraction_len;
+			}
+		}
+
+		for (index, size) in sizes.iter_mut().enumerate() {
+			if let Some(max_width) = self.max_column_widths.get(&index) {
+				*size = (*size).min(*max_width);
+			}
+		}
+
 		sizes
 	}
+
+	fn column_total(&self, column: usize) -> Option<String> {
+		let mut integer_total = Some(0i64);
+		let mut total = 0.0;
+		let mut precision = 0;
+
+		for row in &self.rows {
+			let value = row.cells()[column].value().trim();
+
+			if value.is_empty() {
+				continue;
+			}
+
+			integer_total = integer_total
+				.zip(value.parse::<i64>().ok())
+				.and_then(|(integer_total, value)| integer_total.checked_add(value));
+
+			total += value.parse::<f64>().ok()?;
+
+			if let Some((_, fraction)) = value.split_once('.') {
+				precision = precision.max(fraction.len());
+			}
+		}
+
+		// the float total is rounded to the precision of the column's cells
+		// so that rounding errors in the sum (e.g., 0.1 + 0.2) are not shown
+		match integer_total {
+			Some(integer_total) => Some(integer_total.to_string()),
+			None => Some(format!("{total:.precision$}")),
+		}
+	}
+
+	fn column_aligns(&self) -> Vec<Align> {
+		let row = self.rows
+			.first()
+			.or(self.header.as_ref());
+
+		match row {
+			Some(row) => row.cells()
+				.iter()
+				.map(|cell| cell.align())
+				.collect(),
+
+			None => Vec::new(),
+		}
+	}
+
+	fn max_decimal_lens(&self) -> Vec<(usize, usize)> {
+		let mut lens: Vec<(usize, usize)> = vec![(0, 0); self.row_len];
+
+		for row in self.header.iter().chain(&self.rows).chain(&self.footer) {
+			for (index, (integer_len, fraction_len)) in lens.iter_mut().enumerate() {
+				if let Some((row_integer_len, row_fraction_len)) = row.get_column_decimal_parts(index) {
+					*integer_len = (*integer_len).max(row_integer_len);
+					*fraction_len = (*fraction_len).max(row_fraction_len);
+				}
+			}
+		}
+
+		lens
+	}
+}
+
+fn markdown_value(cell: &Cell) -> String {
+	let value = cell.value().replace('|', "\\|");
+
+	match cell.style() {
+		Style::Bold | Style::BoldColor(_) => format!("**{value}**"),
+		Style::Normal | Style::Color(_) => value,
+	}
+}
+
+fn html_cells(row: &Row, tag: &str) -> String {
+	row.cells()
+		.iter()
+		.map(|cell| {
+			let align = match cell.align() {
+				Align::Left => "left",
+				Align::Right | Align::Decimal => "right",
+				Align::Center => "center",
+			};
+
+			let value = cell.value()
+				.replace('&', "&amp;")
+				.replace('<', "&lt;")
+				.replace('>', "&gt;");
+
+			let value = match cell.style() {
+				Style::Bold => format!("<b>{value}</b>"),
+				Style::Normal => value,
+
+				Style::Color(color) => format!(
+					"<span style=\"color: {}\">{value}</span>",
+					color.css_name(),
+				),
+
+				Style::BoldColor(color) => format!(
+					"<b style=\"color: {}\">{value}</b>",
+					color.css_name(),
+				),
+			};
+
+			format!("<{tag} style=\"text-align: {align}\">{value}</{tag}>")
+		})
+		.collect()
 }
 
 fn print_spacer_row(
 	stdout: &mut impl Write,
-	sizes: &Vec<usize>
+	sizes: &[usize]
 ) {
 	let mut row = Row::default();
 
-	for size in sizes {
-		let value = vec!["-"; *size + 2].join("");
+	// the spacer spans the padding on either side of each column
+	let sizes = sizes
+		.iter()
+		.map(|size| size + 2)
+		.collect::<Vec<usize>>();
+
+	for size in &sizes {
+		let value = vec!["-"; *size].join("");
 		row = row.push(value, Align::Left, Style::Normal);
 	}
 
-	row.print(stdout, sizes, ColumnJoinType::Plus);
+	row.print(stdout, &sizes, ColumnJoinType::Plus);
+}
+
+#[cfg(test)]
+mod tests {
+	use std::io;
+
+	use crate::{
+		file::csv::{WriteRow, RowData},
+		table::{Table, Row, Align, Style, Color},
+	};
+
+	#[test]
+	fn it_aligns_decimal_points() {
+		let mut table = Table::default();
+
+		table.set_header(Row::default().push("Value", Align::Center, Style::Normal));
+
+		for value in ["1.5", "12.25", "100.0", "7", "n/a"] {
+			table.add_row(Row::default().push(value, Align::Decimal, Style::Normal));
+		}
+
+		let mut stdout = Vec::new();
+		table.print(&mut stdout);
+
+		let output = String::from_utf8(stdout).unwrap();
+		let lines = output.lines().collect::<Vec<_>>();
+
+		assert_eq!(lines[2], "|   1.5  |");
+		assert_eq!(lines[3], "|  12.25 |");
+		assert_eq!(lines[4], "| 100.0  |");
+		assert_eq!(lines[5], "|   7    |");
+		assert_eq!(lines[6], "|    n/a |");
+
+		let points = lines[2..5]
+			.iter()
+			.map(|line| line.find('.').unwrap())
+			.collect::<Vec<_>>();
+
+		assert!(points.iter().all(|point| *point == points[0]));
+	}
+
+	#[test]
+	fn it_wraps_wide_cells() {
+		let mut table = Table::default();
+
+		table.set_header(
+			Row::default()
+				.push("Name", Align::Left, Style::Normal)
+				.push("Description", Align::Left, Style::Normal)
+		);
+
+		table.add_row(
+			Row::default()
+				.push("first", Align::Left, Style::Normal)
+				.push("a description which is too long", Align::Left, Style::Normal)
+		);
+
+		table.set_max_column_width(1, 20);
+
+		let mut stdout = Vec::new();
+		table.print(&mut stdout);
+
+		let output = String::from_utf8(stdout).unwrap();
+		let lines = output.lines().collect::<Vec<_>>();
+
+		assert_eq!(lines.len(), 4);
+		assert_eq!(lines[2], "| first | a description which  |");
+		assert_eq!(lines[3], "|       | is too long          |");
+
+		for line in &lines {
+			assert_eq!(line.len(), lines[0].len());
+			assert_eq!(line.rfind('|'), lines[0].rfind('|'));
+		}
+	}
+
+	fn two_column_table() -> Table {
+		let mut table = Table::default();
+
+		table.set_header(
+			Row::default()
+				.push("Name", Align::Center, Style::Bold)
+				.push("Score", Align::Center, Style::Bold)
+		);
+
+		table.add_row(
+			Row::default()
+				.push("a|b", Align::Left, Style::Normal)
+				.push(1.5, Align::Right, Style::Normal)
+		);
+
+		table.add_spacer();
+
+		table.add_row(
+			Row::default()
+				.push("<c>", Align::Center, Style::Bold)
+				.push(10, Align::Decimal, Style::Normal)
+		);
+
+		table
+	}
+
+	#[test]
+	fn it_prints_markdown() {
+		let mut stdout = Vec::new();
+		two_column_table().print_markdown(&mut stdout);
+
+		assert_eq!(
+			String::from_utf8(stdout).unwrap(),
+			"| **Name** | **Score** |\n\
+			| :--- | ---: |\n\
+			| a\\|b | 1.5 |\n\
+			| **<c>** | 10 |\n",
+		);
+	}
+
+	#[test]
+	fn it_prints_html() {
+		let mut stdout = Vec::new();
+		two_column_table().print_html(&mut stdout);
+
+		assert_eq!(
+			String::from_utf8(stdout).unwrap(),
+			"<table>\n\
+			<thead>\n\
+			<tr><th style=\"text-align: center\"><b>Name</b></th><th style=\"text-align: center\"><b>Score</b></th></tr>\n\
+			</thead>\n\
+			<tbody>\n\
+			<tr><td style=\"text-align: left\">a|b</td><td style=\"text-align: right\">1.5</td></tr>\n\
+			<tr><td style=\"text-align: center\"><b>&lt;c&gt;</b></td><td style=\"text-align: right\">10</td></tr>\n\
+			</tbody>\n\
+			</table>\n",
+		);
+	}
+
+	#[test]
+	fn it_sizes_colored_cells_by_visible_width() {
+		let mut table = Table::default();
+
+		table.set_header(Row::default().push("Status", Align::Left, Style::Normal));
+		table.add_row(Row::default().push("OK", Align::Left, Style::Color(Color::Green)));
+		table.add_row(Row::default().push("FAIL", Align::Left, Style::BoldColor(Color::Red)));
+
+		let mut stdout = Vec::new();
+		table.print(&mut stdout);
+
+		assert_eq!(
+			String::from_utf8(stdout).unwrap(),
+			"| Status |\n\
+			|--------|\n\
+			| \x1B[32mOK    \x1B[0m |\n\
+			| \x1B[1;31mFAIL  \x1B[0m |\n",
+		);
+	}
+
+	#[test]
+	fn it_sizes_columns_by_visible_width() {
+		let mut table = Table::default();
+
+		table.set_header(Row::default().push("Header", Align::Center, Style::Bold));
+		table.add_row(Row::default().push("\x1B[4mrow\x1B[0m", Align::Center, Style::Normal));
+
+		let mut stdout = Vec::new();
+		table.print(&mut stdout);
+
+		assert_eq!(
+			String::from_utf8(stdout).unwrap(),
+			"| \x1B[1mHeader\x1B[0m |\n\
+			|--------|\n\
+			|  \x1B[4mrow\x1B[0m   |\n",
+		);
+	}
+
+	#[test]
+	fn it_prints_a_totals_row() {
+		let mut table = Table::default();
+
+		table.set_header(
+			Row::default()
+				.push("Name", Align::Left, Style::Normal)
+				.push("Count", Align::Right, Style::Normal)
+				.push("Score", Align::Decimal, Style::Normal)
+		);
+
+		for (name, count, score) in [("a", 3, 1.5), ("b", 12, 10.25), ("c", 5, 0.5)] {
+			table.add_row(
+				Row::default()
+					.push(name, Align::Left, Style::Normal)
+					.push(count, Align::Right, Style::Normal)
+					.push(score, Align::Decimal, Style::Normal)
+			);
+		}
+
+		table.add_totals_row(&[1, 2]);
+
+		let mut stdout = Vec::new();
+		table.print(&mut stdout);
+
+		assert_eq!(
+			String::from_utf8(stdout).unwrap(),
+			"| Name  | Count | Score |\n\
+			|-------+-------+-------|\n\
+			| a     |     3 |  1.5  |\n\
+			| b     |    12 | 10.25 |\n\
+			| c     |     5 |  0.5  |\n\
+			|-------+-------+-------|\n\
+			| Total |    20 | 12.25 |\n",
+		);
+	}
+
+	#[test]
+	fn it_rounds_float_totals_to_the_column_precision() {
+		let mut table = Table::default();
+
+		for value in [0.1, 0.2] {
+			table.add_row(Row::default().push(value, Align::Right, Style::Normal));
+		}
+
+		table.add_totals_row(&[0]);
+
+		let mut stdout = Vec::new();
+		table.print(&mut stdout);
+
+		assert!(String::from_utf8(stdout).unwrap().ends_with("| 0.3 |\n"));
+	}
+
+	#[test]
+	fn it_leaves_non_numeric_totals_blank() {
+		let mut table = two_column_table();
+		table.add_totals_row(&[0, 1]);
+
+		let mut stdout = Vec::new();
+		table.print_markdown(&mut stdout);
+
+		let output = String::from_utf8(stdout).unwrap();
+		assert!(output.ends_with("| **<c>** | 10 |\n|  | 11.5 |\n"));
+	}
+
+	#[test]
+	fn it_creates_tables_from_rows() {
+		struct Request {
+			path: &'static str,
+			status: u16,
+			duration_ms: f64,
+		}
+
+		impl WriteRow for Request {
+			fn as_row(&self, row: &mut RowData) -> io::Result<()> {
+				row.push(self.path);
+				row.push(self.status);
+				row.push(self.duration_ms);
+
+				Ok(())
+			}
+		}
+
+		let requests = vec![
+			Request { path: "/", status: 200, duration_ms: 1.5 },
+			Request { path: "/login", status: 302, duration_ms: 12.25 },
+		];
+
+		let table = Table::from_rows(&["Path", "Status", "Duration"], &requests).unwrap();
+
+		let mut stdout = Vec::new();
+		table.print(&mut stdout);
+
+		assert_eq!(
+			String::from_utf8(stdout).unwrap(),
+			"| \x1B[1m Path \x1B[0m | \x1B[1mStatus\x1B[0m | \x1B[1mDuration\x1B[0m |\n\
+			|--------+--------+----------|\n\
+			| /      | 200    | 1.5      |\n\
+			| /login | 302    | 12.25    |\n",
+		);
+	}
 }
@@ -90,12 +90,10 @@ impl Row {
 	where
 		T: 'static + Display,
 	{
-		let string = value.to_string();
-		let len = string.len();
-		let cell = Cell::new(string, align, style);
+		let cell = Cell::new(value.to_string(), align, style);
 
-		if len > self.max_len {
-			self.max_len = len;
+		if cell.size() > self.max_len {
+			self.max_len = cell.size();
 		}
 
 		self.cells.push(cell);
@@ -117,11 +115,16 @@ impl Row {
 		self.push("", Align::Left, Style::Normal)
 	}
 
+	#[inline]
+	pub(crate) fn cells(&self) -> &[Cell] {
+		&self.cells
+	}
+
 	/// Returns the printed size of the row.
 	#[inline]
 	#[must_use]
 	pub fn size(&self) -> usize {
-		self.to_string(None, ColumnJoinType::Spaced).len()
+		self.to_string(None, None, ColumnJoinType::Spaced).len()
 	}
 
 	/// Returns the printed size of the column at the supplied index.
@@ -136,6 +139,19 @@ impl Row {
 		self.cells[index].size()
 	}
 
+	/// Returns the lengths of the integer and fractional parts of the
+	/// column at the supplied index if it is a decimal-aligned number.
+	///
+	/// # Panics
+	///
+	/// Panics if the column index is out of the bounds of the columns.
+	#[inline]
+	#[must_use]
+	pub(crate) fn get_column_decimal_parts(&self, index: usize) -> Option<(usize, usize)> {
+		assert!(index < self.cells.len(), "Invalid column index.");
+		self.cells[index].decimal_parts()
+	}
+
 	/// Prints the column to the supplied stream.
 	#[inline]
 	pub fn print(
@@ -147,7 +163,24 @@ impl Row {
 		writeln!(
 			stdout,
 			"{}",
-			self.to_string(Some(sizes), join_type)
+			self.to_string(Some(sizes), None, join_type)
+		).unwrap();
+	}
+
+	/// Prints the column to the supplied stream, aligning decimal-aligned
+	/// numbers to the supplied integer and fractional part lengths.
+	#[inline]
+	pub(crate) fn print_aligned(
+		&self,
+		stdout: &mut impl Write,
+		sizes: &Vec<usize>,
+		decimal_lens: &[(usize, usize)],
+		join_type: ColumnJoinType,
+	) {
+		writeln!(
+			stdout,
+			"{}",
+			self.to_string(Some(sizes), Some(decimal_lens), join_type)
 		).unwrap();
 	}
 
@@ -157,6 +190,7 @@ impl Row {
 	fn to_string(
 		&self,
 		sizes: Option<&Vec<usize>>,
+		decimal_lens: Option<&[(usize, usize)]>,
 		join_type: ColumnJoinType,
 	) -> String {
 		let join_str = match join_type {
@@ -165,25 +199,54 @@ impl Row {
 			ColumnJoinType::Plus => "+",
 		};
 
-		let line = self.cells
+		let sizes = self.cells
 			.iter()
 			.enumerate()
-			.map(|(index, cell)| {
-				let size = match sizes {
-					Some(sizes) => sizes[index],
-					None => cell.size(),
-				};
+			.map(|(index, cell)| match sizes {
+				Some(sizes) => sizes[index],
+				None => cell.size(),
+			})
+			.collect::<Vec<usize>>();
 
-				cell.to_sized_string(size)
+		// cells wider than their column are wrapped onto additional lines,
+		// with the other columns left blank on those lines
+		let wrapped_cells = self.cells
+			.iter()
+			.zip(&sizes)
+			.map(|(cell, size)| cell.wrap(*size))
+			.collect::<Vec<Vec<Cell>>>();
+
+		let num_lines = wrapped_cells
+			.iter()
+			.map(|cells| cells.len())
+			.max()
+			.unwrap_or(1);
+
+		(0..num_lines)
+			.map(|line_index| {
+				let line = wrapped_cells
+					.iter()
+					.enumerate()
+					.map(|(index, cells)| {
+						let size = sizes[index];
+						let decimal_lens = decimal_lens.map(|decimal_lens| decimal_lens[index]);
+
+						match cells.get(line_index) {
+							Some(cell) => cell.to_sized_string(size, decimal_lens),
+							None => " ".repeat(size),
+						}
+					})
+					.collect::<Vec<String>>()
+					.join(join_str);
+
+				if join_type == ColumnJoinType::Spaced {
+					format!("| {line} |")
+				} else {
+					format!("|{line}|")
+				}
 			})
 			.collect::<Vec<String>>()
-			.join(join_str);
-
-		if join_type == ColumnJoinType::Spaced {
-			format!("| {line} |")
-		} else {
-			format!("|{line}|")
-		}
+			.join("\n")
 	}
 }
 
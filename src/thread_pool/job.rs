@@ -0,0 +1,44 @@
+/*
+ * Copyright (c) Kia Shakiba
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::sync::mpsc;
+
+/// A handle to a job submitted to a thread pool, used to wait for
+/// the job's result.
+pub struct JobHandle<R> {
+	receiver: mpsc::Receiver<R>,
+}
+
+impl<R> JobHandle<R> {
+	pub(crate) fn new(receiver: mpsc::Receiver<R>) -> Self {
+		JobHandle {
+			receiver,
+		}
+	}
+
+	/// Blocks until the job is complete and returns its result.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::thread_pool::ThreadPool;
+	///
+	/// let pool = ThreadPool::new(4);
+	/// let handle = pool.submit(|| 1 + 2);
+	///
+	/// assert_eq!(handle.join(), 3);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the job panicked.
+	#[inline]
+	pub fn join(self) -> R {
+		self.receiver
+			.recv()
+			.expect("Job did not complete.")
+	}
+}
@@ -6,13 +6,38 @@
  */
 
 mod worker;
+mod job;
 
-use std::sync::{mpsc, Arc, Mutex};
+use std::sync::{
+	mpsc,
+	Arc,
+	Mutex,
+	atomic::{AtomicIsize, Ordering},
+};
+
+use thiserror::Error;
 use crate::thread_pool::worker::{Worker, Job};
 
+pub use crate::thread_pool::job::JobHandle;
+
 pub struct ThreadPool {
 	workers: Vec<Worker>,
-	sender: Option<mpsc::Sender<Job>>,
+	sender: Option<JobSender>,
+	queued: Arc<AtomicIsize>,
+}
+
+#[derive(Debug, Error)]
+pub enum TryError {
+	#[error("thread pool queue is full")]
+	Full,
+
+	#[error("thread pool has been shut down")]
+	Shutdown,
+}
+
+enum JobSender {
+	Unbounded(mpsc::Sender<Job>),
+	Bounded(mpsc::SyncSender<Job>),
 }
 
 /// A thread pool implementaton that creates a number of threads
@@ -30,20 +55,26 @@ impl ThreadPool {
 	/// ```
 	#[must_use]
 	pub fn new(size: usize) -> ThreadPool {
-		let mut workers = Vec::<Worker>::new();
 		let (sender, receiver) = mpsc::channel();
-		let receiver = Arc::new(Mutex::new(receiver));
-
-		for _ in 0..size {
-			workers.push(Worker::new(
-				Arc::clone(&receiver)
-			));
-		}
+		ThreadPool::init(size, JobSender::Unbounded(sender), receiver)
+	}
 
-		ThreadPool {
-			workers,
-			sender: Some(sender),
-		}
+	/// Creates a new instance of a thread pool with the specified number
+	/// of threads and a queue which holds at most `queue_cap` pending jobs.
+	/// Once the queue is full, submitting a job blocks until a worker takes
+	/// a job from the queue.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::thread_pool::ThreadPool;
+	///
+	/// // create a thread pool with 4 threads and up to 16 pending jobs
+	/// let pool = ThreadPool::with_capacity(4, 16);
+	/// ```
+	#[must_use]
+	pub fn with_capacity(size: usize, queue_cap: usize) -> ThreadPool {
+		let (sender, receiver) = mpsc::sync_channel(queue_cap);
+		ThreadPool::init(size, JobSender::Bounded(sender), receiver)
 	}
 
 	/// Executes a job in one of the thread pool's worker threads.
@@ -61,28 +92,370 @@ impl ThreadPool {
 	///
 	/// # Panics
 	///
-	/// Panics if the job cannot be sent to a worker.
+	/// Panics if the thread pool has been shut down or if the job cannot
+	/// be sent to a worker.
 	#[inline]
 	pub fn execute<F>(&self, f: F)
 	where
 		F: 'static + FnOnce() + Send,
 	{
-		let job = Box::new(f);
+		let sender = self.sender
+			.as_ref()
+			.expect("Thread pool has been shut down.");
+
+		match sender {
+			JobSender::Unbounded(sender) => sender.send(Box::new(f)).unwrap(),
+			JobSender::Bounded(sender) => sender.send(Box::new(f)).unwrap(),
+		}
 
-		self.sender
-			.as_ref().unwrap()
-			.send(job).unwrap();
+		// the job is only counted once it is in the queue, so producers
+		// blocked on a full queue are not counted as queued
+		self.queued.fetch_add(1, Ordering::SeqCst);
 	}
-}
 
-impl Drop for ThreadPool {
-	fn drop(&mut self) {
+	/// Executes a job in one of the thread pool's worker threads and
+	/// returns a handle which can be joined for the job's result.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::thread_pool::ThreadPool;
+	///
+	/// let pool = ThreadPool::new(4);
+	///
+	/// let handles = (0..4)
+	///     .map(|value| pool.submit(move || value * 2))
+	///     .collect::<Vec<_>>();
+	///
+	/// let results = handles
+	///     .into_iter()
+	///     .map(|handle| handle.join())
+	///     .collect::<Vec<_>>();
+	///
+	/// assert_eq!(results, vec![0, 2, 4, 6]);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the thread pool has been shut down or if the job cannot
+	/// be sent to a worker.
+	#[inline]
+	pub fn submit<F, R>(&self, f: F) -> JobHandle<R>
+	where
+		F: 'static + FnOnce() -> R + Send,
+		R: 'static + Send,
+	{
+		let (sender, receiver) = mpsc::channel();
+
+		self.execute(move || {
+			// the handle may have been dropped without being joined
+			let _ = sender.send(f());
+		});
+
+		JobHandle::new(receiver)
+	}
+
+	/// Executes a job in one of the thread pool's worker threads and
+	/// returns a handle which can be joined for the job's result. Unlike
+	/// `submit`, this does not block if the thread pool's queue is full.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::thread_pool::ThreadPool;
+	///
+	/// let pool = ThreadPool::with_capacity(4, 16);
+	///
+	/// if let Ok(handle) = pool.try_submit(|| 1 + 2) {
+	///     assert_eq!(handle.join(), 3);
+	/// }
+	/// ```
+	///
+	/// # Errors
+	///
+	/// Returns an error if the thread pool's queue is full or if the
+	/// thread pool has been shut down.
+	pub fn try_submit<F, R>(&self, f: F) -> Result<JobHandle<R>, TryError>
+	where
+		F: 'static + FnOnce() -> R + Send,
+		R: 'static + Send,
+	{
+		let Some(sender) = &self.sender else {
+			return Err(TryError::Shutdown);
+		};
+
+		let (result_sender, receiver) = mpsc::channel();
+
+		let job: Job = Box::new(move || {
+			// the handle may have been dropped without being joined
+			let _ = result_sender.send(f());
+		});
+
+		let result = match sender {
+			JobSender::Unbounded(sender) => sender
+				.send(job)
+				.map_err(|_| TryError::Shutdown),
+
+			JobSender::Bounded(sender) => sender
+				.try_send(job)
+				.map_err(|error| match error {
+					mpsc::TrySendError::Full(_) => TryError::Full,
+					mpsc::TrySendError::Disconnected(_) => TryError::Shutdown,
+				}),
+		};
+
+		result?;
+		self.queued.fetch_add(1, Ordering::SeqCst);
+
+		Ok(JobHandle::new(receiver))
+	}
+
+	/// Returns the number of jobs waiting in the thread pool's queue
+	/// which have not yet been taken by a worker.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::thread_pool::ThreadPool;
+	///
+	/// let pool = ThreadPool::new(4);
+	///
+	/// assert_eq!(pool.queued_len(), 0);
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn queued_len(&self) -> usize {
+		// a worker may take a job before its sender counts it, which
+		// briefly makes the count negative
+		self.queued.load(Ordering::SeqCst).max(0) as usize
+	}
+
+	/// Stops accepting new jobs, waits for all submitted jobs to complete,
+	/// and joins the thread pool's worker threads. This is also done when
+	/// the thread pool is dropped.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::thread_pool::ThreadPool;
+	///
+	/// let mut pool = ThreadPool::new(4);
+	///
+	/// pool.execute(|| {
+	///     // do work here
+	/// });
+	///
+	/// pool.shutdown();
+	/// ```
+	pub fn shutdown(&mut self) {
 		drop(self.sender.take());
 
 		for worker in &mut self.workers {
 			if let Some(thread) = worker.thread.take() {
-				thread.join().unwrap();
+				// a worker only fails to join if one of its jobs panicked,
+				// which should not prevent the other workers from joining
+				let _ = thread.join();
 			}
 		}
 	}
+
+	/// Applies the supplied function to each item in the thread pool's
+	/// worker threads, returning the results in the order of the items.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::thread_pool::ThreadPool;
+	///
+	/// let pool = ThreadPool::new(4);
+	/// let squares = pool.map(0..5, |value: u64| value * value);
+	///
+	/// assert_eq!(squares, vec![0, 1, 4, 9, 16]);
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if a job cannot be sent to a worker or if the function
+	/// panics for any item.
+	pub fn map<I, T, R, F>(&self, items: I, f: F) -> Vec<R>
+	where
+		I: IntoIterator<Item = T>,
+		T: 'static + Send,
+		R: 'static + Send,
+		F: 'static + Fn(T) -> R + Send + Sync,
+	{
+		let f = Arc::new(f);
+		let (sender, receiver) = mpsc::channel::<(usize, R)>();
+
+		let mut count: usize = 0;
+
+		for (index, item) in items.into_iter().enumerate() {
+			let f = Arc::clone(&f);
+			let sender = sender.clone();
+
+			self.execute(move || {
+				// the receiver only hangs up if another job panicked
+				let _ = sender.send((index, f(item)));
+			});
+
+			count += 1;
+		}
+
+		drop(sender);
+
+		let mut results = receiver
+			.iter()
+			.take(count)
+			.collect::<Vec<(usize, R)>>();
+
+		assert_eq!(results.len(), count, "Could not map all items.");
+
+		results.sort_unstable_by_key(|(index, _)| *index);
+
+		results
+			.into_iter()
+			.map(|(_, result)| result)
+			.collect()
+	}
+}
+
+impl ThreadPool {
+	fn init(
+		size: usize,
+		sender: JobSender,
+		receiver: mpsc::Receiver<Job>,
+	) -> ThreadPool {
+		let mut workers = Vec::<Worker>::new();
+		let receiver = Arc::new(Mutex::new(receiver));
+		let queued = Arc::new(AtomicIsize::new(0));
+
+		for _ in 0..size {
+			workers.push(Worker::new(
+				Arc::clone(&receiver),
+				Arc::clone(&queued),
+			));
+		}
+
+		ThreadPool {
+			workers,
+			sender: Some(sender),
+			queued,
+		}
+	}
+}
+
+impl Drop for ThreadPool {
+	fn drop(&mut self) {
+		self.shutdown();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		thread,
+		time::Duration,
+		sync::{
+			mpsc,
+			Arc,
+			atomic::{AtomicBool, Ordering},
+		},
+	};
+
+	use crate::thread_pool::{ThreadPool, TryError};
+
+	#[test]
+	fn it_maps_items_in_order() {
+		let pool = ThreadPool::new(4);
+		let squares = pool.map(0..1_000, |value: u64| value * value);
+
+		let expected = (0..1_000)
+			.map(|value: u64| value * value)
+			.collect::<Vec<u64>>();
+
+		assert_eq!(squares, expected);
+	}
+
+	#[test]
+	fn it_maps_empty_items() {
+		let pool = ThreadPool::new(2);
+		let results = pool.map(Vec::<u64>::new(), |value| value + 1);
+
+		assert!(results.is_empty());
+	}
+
+	#[test]
+	fn it_joins_submitted_jobs() {
+		let pool = ThreadPool::new(4);
+
+		let handles = (0..100)
+			.map(|value: u64| pool.submit(move || value + 1))
+			.collect::<Vec<_>>();
+
+		for (value, handle) in handles.into_iter().enumerate() {
+			assert_eq!(handle.join(), value as u64 + 1);
+		}
+	}
+
+	#[test]
+	fn it_terminates_workers_on_shutdown() {
+		let mut pool = ThreadPool::new(4);
+		let handle = pool.submit(|| 5);
+
+		pool.shutdown();
+
+		assert_eq!(handle.join(), 5);
+		assert!(pool.sender.is_none());
+		assert!(pool.workers.iter().all(|worker| worker.thread.is_none()));
+
+		// shutting down again is a no-op
+		pool.shutdown();
+	}
+
+	#[test]
+	fn it_blocks_when_queue_is_full() {
+		let pool = Arc::new(ThreadPool::with_capacity(1, 1));
+
+		let (started_sender, started_receiver) = mpsc::channel();
+		let (release_sender, release_receiver) = mpsc::channel::<()>();
+
+		let slow = pool.submit(move || {
+			started_sender.send(()).unwrap();
+			release_receiver.recv().unwrap();
+		});
+
+		// the worker is now busy, so the next job fills the queue
+		started_receiver.recv().unwrap();
+
+		let queued = pool.submit(|| 2);
+		assert_eq!(pool.queued_len(), 1);
+
+		assert!(matches!(pool.try_submit(|| 3), Err(TryError::Full)));
+		assert_eq!(pool.queued_len(), 1);
+
+		let submitted = Arc::new(AtomicBool::new(false));
+
+		let producer = {
+			let pool = Arc::clone(&pool);
+			let submitted = Arc::clone(&submitted);
+
+			thread::spawn(move || {
+				let handle = pool.submit(|| 4);
+				submitted.store(true, Ordering::SeqCst);
+				handle
+			})
+		};
+
+		thread::sleep(Duration::from_millis(100));
+		assert!(!submitted.load(Ordering::SeqCst));
+
+		// the blocked producer's job is not in the queue yet
+		assert_eq!(pool.queued_len(), 1);
+
+		release_sender.send(()).unwrap();
+
+		let blocked = producer.join().unwrap();
+
+		assert!(submitted.load(Ordering::SeqCst));
+
+		slow.join();
+		assert_eq!(queued.join(), 2);
+		assert_eq!(blocked.join(), 4);
+		assert_eq!(pool.queued_len(), 0);
+	}
 }
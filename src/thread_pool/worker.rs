@@ -6,7 +6,12 @@
  */
 
 use std::{
-	sync::{mpsc, Arc, Mutex},
+	sync::{
+		mpsc,
+		Arc,
+		Mutex,
+		atomic::{AtomicIsize, Ordering},
+	},
 	thread,
 };
 
@@ -17,14 +22,21 @@ pub struct Worker {
 pub type Job = Box<dyn 'static + FnOnce() + Send>;
 
 impl Worker {
-	pub fn new(receiver: Arc<Mutex<mpsc::Receiver<Job>>>) -> Worker {
+	pub fn new(
+		receiver: Arc<Mutex<mpsc::Receiver<Job>>>,
+		queued: Arc<AtomicIsize>,
+	) -> Worker {
 		let thread = thread::spawn(move || loop {
 			let job = receiver
 				.lock().unwrap()
 				.recv();
 
 			match job {
-				Ok(job) => job(),
+				Ok(job) => {
+					queued.fetch_sub(1, Ordering::SeqCst);
+					job();
+				},
+
 				Err(_) => break,
 			}
 		});
@@ -5,7 +5,33 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+	io::{self, Write},
+	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::fmt;
+
+/// Measures the time between its creation and when it is dropped,
+/// reporting the elapsed time on drop.
+pub struct ScopedTimer {
+	label: String,
+	start: Instant,
+	report: Option<Report>,
+}
+
+/// Limits an action to running at most once per interval, such as
+/// periodic logging inside a tight loop.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+	interval: Duration,
+	last_run: Option<Instant>,
+}
+
+enum Report {
+	Writer(Box<dyn Write>),
+	Callback(Box<dyn FnOnce(Duration)>),
+}
 
 /// Returns the current system timestamp in milliseconds.
 ///
@@ -28,3 +54,231 @@ pub fn timestamp() -> u64 {
 
 	now.as_secs() * 1000 + u64::from(now.subsec_nanos()) / 1_000_000
 }
+
+impl ScopedTimer {
+	/// Starts a new timer which writes `"{label}: {elapsed}"` to stderr
+	/// when it is dropped.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::time::ScopedTimer;
+	///
+	/// {
+	///     let _timer = ScopedTimer::new("work");
+	///
+	///     // do work here
+	/// }
+	/// ```
+	#[must_use]
+	pub fn new(label: &str) -> Self {
+		ScopedTimer::with_writer(label, io::stderr())
+	}
+
+	/// Starts a new timer which writes `"{label}: {elapsed}"` to the
+	/// supplied writer when it is dropped.
+	///
+	/// # Examples
+	/// ```
+	/// use kwik::time::ScopedTimer;
+	///
+	/// {
+	///     let _timer = ScopedTimer::with_writer("work", std::io::stdout());
+	///
+	///     // do work here
+	/// }
+	/// ```
+	#[must_use]
+	pub fn with_writer(label: &str, writer: impl Write + 'static) -> Self {
+		ScopedTimer {
+			label: label.to_string(),
+			start: Instant::now(),
+			report: Some(Report::Writer(Box::new(writer))),
+		}
+	}
+
+	/// Starts a new timer which calls the supplied function with the
+	/// elapsed time when it is dropped, instead of writing it.
+	///
+	/// # Examples
+	/// ```
+	/// use std::{cell::Cell, rc::Rc, time::Duration};
+	/// use kwik::time::ScopedTimer;
+	///
+	/// let elapsed = Rc::new(Cell::new(Duration::ZERO));
+	///
+	/// {
+	///     let elapsed = Rc::clone(&elapsed);
+	///     let _timer = ScopedTimer::with_callback("work", move |duration| elapsed.set(duration));
+	///
+	///     // do work here
+	/// }
+	///
+	/// assert!(elapsed.get() > Duration::ZERO);
+	/// ```
+	#[must_use]
+	pub fn with_callback<F>(label: &str, f: F) -> Self
+	where
+		F: 'static + FnOnce(Duration),
+	{
+		ScopedTimer {
+			label: label.to_string(),
+			start: Instant::now(),
+			report: Some(Report::Callback(Box::new(f))),
+		}
+	}
+
+	/// Returns the timer's label.
+	#[inline]
+	#[must_use]
+	pub fn label(&self) -> &str {
+		&self.label
+	}
+
+	/// Returns the time elapsed since the timer was started.
+	#[inline]
+	#[must_use]
+	pub fn elapsed(&self) -> Duration {
+		self.start.elapsed()
+	}
+}
+
+impl RateLimiter {
+	/// Creates a rate limiter which allows an action to run at most once
+	/// per the supplied interval.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::Duration;
+	/// use kwik::time::RateLimiter;
+	///
+	/// let mut limiter = RateLimiter::new(Duration::from_secs(1));
+	///
+	/// for iteration in 0..1_000 {
+	///     if limiter.should_run() {
+	///         println!("iteration {iteration}");
+	///     }
+	/// }
+	/// ```
+	#[must_use]
+	pub fn new(interval: Duration) -> Self {
+		RateLimiter {
+			interval,
+			last_run: None,
+		}
+	}
+
+	/// Returns the interval between runs.
+	#[inline]
+	#[must_use]
+	pub fn interval(&self) -> Duration {
+		self.interval
+	}
+
+	/// Returns true if the action has not run within the interval, in
+	/// which case the interval is restarted. The first call always
+	/// returns true.
+	#[must_use]
+	pub fn should_run(&mut self) -> bool {
+		let now = Instant::now();
+
+		let ready = self.last_run
+			.is_none_or(|last_run| now.duration_since(last_run) >= self.interval);
+
+		if ready {
+			self.last_run = Some(now);
+		}
+
+		ready
+	}
+}
+
+impl Drop for ScopedTimer {
+	fn drop(&mut self) {
+		let elapsed = self.elapsed();
+
+		match self.report.take() {
+			Some(Report::Writer(mut writer)) => {
+				// errors cannot be surfaced from a drop, so a failed
+				// report is ignored rather than panicking
+				let _ = writeln!(
+					writer,
+					"{}: {}",
+					self.label,
+					fmt::timespan(elapsed.as_millis() as u64),
+				);
+			},
+
+			Some(Report::Callback(f)) => f(elapsed),
+			None => {},
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		thread,
+		rc::Rc,
+		cell::{Cell, RefCell},
+		io::{self, Write},
+		time::Duration,
+	};
+
+	use crate::time::{ScopedTimer, RateLimiter};
+
+	struct SharedWriter(Rc<RefCell<Vec<u8>>>);
+
+	impl Write for SharedWriter {
+		fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+			self.0.borrow_mut().write(buf)
+		}
+
+		fn flush(&mut self) -> io::Result<()> {
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn it_reports_elapsed_time_to_callback() {
+		let elapsed = Rc::new(Cell::new(Duration::ZERO));
+
+		{
+			let elapsed = Rc::clone(&elapsed);
+			let _timer = ScopedTimer::with_callback("test", move |duration| elapsed.set(duration));
+
+			thread::sleep(Duration::from_millis(10));
+		}
+
+		assert!(elapsed.get() >= Duration::from_millis(10));
+	}
+
+	#[test]
+	fn it_writes_elapsed_time_on_drop() {
+		let buf = Rc::new(RefCell::new(Vec::<u8>::new()));
+
+		{
+			let _timer = ScopedTimer::with_writer("test", SharedWriter(Rc::clone(&buf)));
+		}
+
+		let output = String::from_utf8(buf.borrow().clone()).unwrap();
+
+		assert!(output.starts_with("test: "));
+		assert!(output.ends_with('\n'));
+	}
+
+	#[test]
+	fn it_limits_runs_to_once_per_interval() {
+		let mut limiter = RateLimiter::new(Duration::from_millis(50));
+
+		assert!(limiter.should_run());
+
+		for _ in 0..1_000 {
+			assert!(!limiter.should_run());
+		}
+
+		thread::sleep(Duration::from_millis(60));
+
+		assert!(limiter.should_run());
+		assert!(!limiter.should_run());
+	}
+}
@@ -22,6 +22,7 @@ pub struct TimeMovingAverage {
 pub struct WindowIter<'a> {
 	tma: &'a TimeMovingAverage,
 
+	mode: WindowMode,
 	window: Duration,
 	current: Option<Instant>,
 }
@@ -29,10 +30,18 @@ pub struct WindowIter<'a> {
 pub struct IntoWindowIter {
 	tma: TimeMovingAverage,
 
+	mode: WindowMode,
 	window: Duration,
 	current: Option<Instant>,
 }
 
+#[derive(Clone, Copy)]
+enum WindowMode {
+	Centered,
+	Trailing,
+	Leading,
+}
+
 impl TimeMovingAverage {
 	/// Returns true if there are no points in the dataset.
 	///
@@ -62,6 +71,76 @@ impl TimeMovingAverage {
 		self.points.len()
 	}
 
+	/// Returns the earliest point in the dataset, or `None` if the
+	/// dataset is empty.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	/// let now = Instant::now();
+	///
+	/// tma.push(now + Duration::from_secs(1), 2.0);
+	/// tma.push(now, 1.0);
+	///
+	/// assert_eq!(tma.first(), Some((now, 1.0)));
+	/// ```
+	#[inline]
+	pub fn first(&self) -> Option<(Instant, f64)> {
+		self.points
+			.first_key_value()
+			.map(|(instant, value)| (*instant, *value))
+	}
+
+	/// Returns the latest point in the dataset, or `None` if the
+	/// dataset is empty.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	/// let now = Instant::now();
+	///
+	/// tma.push(now + Duration::from_secs(1), 2.0);
+	/// tma.push(now, 1.0);
+	///
+	/// assert_eq!(tma.last(), Some((now + Duration::from_secs(1), 2.0)));
+	/// ```
+	#[inline]
+	pub fn last(&self) -> Option<(Instant, f64)> {
+		self.points
+			.last_key_value()
+			.map(|(instant, value)| (*instant, *value))
+	}
+
+	/// Returns an iterator over the raw points in the dataset, in
+	/// ascending order of their instants.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	///
+	/// tma.push(Instant::now(), 1.0);
+	/// tma.push(Instant::now() + Duration::from_secs(1), 2.0);
+	///
+	/// for (instant, value) in tma.iter() {
+	///     // do something with the instant and value
+	/// }
+	/// ```
+	#[inline]
+	pub fn iter(&self) -> impl Iterator<Item = (Instant, f64)> + '_ {
+		self.points
+			.iter()
+			.map(|(instant, value)| (*instant, *value))
+	}
+
 	/// Adds a point to the dataset.
 	///
 	/// # Examples
@@ -81,6 +160,29 @@ impl TimeMovingAverage {
 		self.points.insert(instant, value.as_());
 	}
 
+	/// Adds all points of the supplied dataset to this one. As with `push`,
+	/// a point at the same instant as an existing point overwrites it.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	/// let mut other = TimeMovingAverage::default();
+	///
+	/// tma.push(Instant::now(), 1.0);
+	/// other.push(Instant::now() + Duration::from_secs(1), 2.0);
+	///
+	/// tma.merge(other);
+	///
+	/// assert_eq!(tma.len(), 2);
+	/// ```
+	#[inline]
+	pub fn merge(&mut self, other: TimeMovingAverage) {
+		self.points.extend(other.points);
+	}
+
 	/// Returns the windowed average at the supplied instant based on the
 	/// supplied window duration. The window is centered at the insant. If
 	/// no points in the dataset are within the window range at the supplied
@@ -110,25 +212,312 @@ impl TimeMovingAverage {
 		instant: Instant,
 		window: Duration,
 	) -> Option<f64> {
-		let shift = window / 2;
+		let (start, end) = centered_range(instant, window);
+
+		self.get_range_average((start, end))
+	}
+
+	/// Returns the trailing average at the supplied instant based on the
+	/// supplied window duration. The window covers the range
+	/// `(instant - window, instant]`, so only points up to the instant are
+	/// included. If no points in the dataset are within the window range
+	/// at the supplied instant, `None` is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	///
+	/// let now = Instant::now();
+	/// let later = now + Duration::from_secs(1);
+	///
+	/// tma.push(now, 1.0);
+	/// tma.push(later, 3.0);
+	///
+	/// assert_eq!(tma.get_trailing_average(now, Duration::from_secs(2)), Some(1.0));
+	/// assert_eq!(tma.get_trailing_average(later, Duration::from_secs(2)), Some(2.0));
+	/// ```
+	#[inline]
+	pub fn get_trailing_average(
+		&self,
+		instant: Instant,
+		window: Duration,
+	) -> Option<f64> {
+		let start = match instant.checked_sub(window) {
+			Some(start) => Bound::Excluded(start),
+			None => Bound::Unbounded,
+		};
+
+		let end = Bound::Included(instant);
+
+		self.get_range_average((start, end))
+	}
+
+	/// Returns the leading average at the supplied instant based on the
+	/// supplied window duration. The window covers the range
+	/// `[instant, instant + window)`, so only points from the instant
+	/// onward are included. If no points in the dataset are within the
+	/// window range at the supplied instant, `None` is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	///
+	/// let now = Instant::now();
+	/// let later = now + Duration::from_secs(1);
+	///
+	/// tma.push(now, 1.0);
+	/// tma.push(later, 3.0);
+	///
+	/// assert_eq!(tma.get_leading_average(now, Duration::from_secs(2)), Some(2.0));
+	/// assert_eq!(tma.get_leading_average(later, Duration::from_secs(2)), Some(3.0));
+	/// ```
+	#[inline]
+	pub fn get_leading_average(
+		&self,
+		instant: Instant,
+		window: Duration,
+	) -> Option<f64> {
+		let start = Bound::Included(instant);
+		let end = match instant.checked_add(window) {
+			Some(end) => Bound::Excluded(end),
+			None => Bound::Unbounded,
+		};
 
-		let start = Bound::Included(instant - shift);
-		let end = Bound::Included(instant + shift);
+		self.get_range_average((start, end))
+	}
+
+	/// Returns the exponentially weighted average at the supplied instant
+	/// based on the supplied window duration. The window covers the range
+	/// `(instant - window, instant]`, and each point within it is weighted by
+	/// `0.5^(age / half_life)`, where `age` is the time between the point and
+	/// the instant, so more recent points carry more weight. If no points in
+	/// the dataset are within the window range at the supplied instant,
+	/// `None` is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	///
+	/// let now = Instant::now();
+	/// let later = now + Duration::from_secs(1);
+	///
+	/// tma.push(now, 1.0);
+	/// tma.push(later, 4.0);
+	///
+	/// let average = tma.get_exponential_average(
+	///     later,
+	///     Duration::from_secs(4),
+	///     Duration::from_secs(1),
+	/// );
+	///
+	/// // the older point has half the weight of the newer one
+	/// assert_eq!(average, Some((0.5 * 1.0 + 4.0) / 1.5));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if the half-life is zero.
+	#[inline]
+	pub fn get_exponential_average(
+		&self,
+		instant: Instant,
+		window: Duration,
+		half_life: Duration,
+	) -> Option<f64> {
+		assert!(!half_life.is_zero(), "Half-life must be greater than zero.");
+
+		let start = match instant.checked_sub(window) {
+			Some(start) => Bound::Excluded(start),
+			None => Bound::Unbounded,
+		};
+
+		let end = Bound::Included(instant);
 
 		let mut total: f64 = 0.0;
-		let mut count: usize = 0;
+		let mut total_weight: f64 = 0.0;
 
-		for (_, value) in self.points.range((start, end)) {
-			total += *value;
-			count += 1;
+		for (point_instant, value) in self.points.range((start, end)) {
+			let age = instant - *point_instant;
+			let weight = 0.5f64.powf(age.as_secs_f64() / half_life.as_secs_f64());
+
+			total += weight * *value;
+			total_weight += weight;
 		}
 
-		match count {
-			0 => None,
-			count => Some(total / count as f64),
+		match total_weight {
+			0.0 => None,
+			total_weight => Some(total / total_weight),
 		}
 	}
 
+	/// Returns the maximum value at the supplied instant based on the
+	/// supplied window duration. The window is centered at the instant. If
+	/// no points in the dataset are within the window range at the supplied
+	/// instant, `None` is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	/// let now = Instant::now();
+	///
+	/// tma.push(now, 1.0);
+	/// tma.push(now + Duration::from_millis(100), 3.0);
+	///
+	/// assert_eq!(tma.get_windowed_max(now, Duration::from_secs(1)), Some(3.0));
+	/// ```
+	#[inline]
+	pub fn get_windowed_max(
+		&self,
+		instant: Instant,
+		window: Duration,
+	) -> Option<f64> {
+		self.get_windowed_values(instant, window)
+			.into_iter()
+			.max_by(f64::total_cmp)
+	}
+
+	/// Returns the minimum value at the supplied instant based on the
+	/// supplied window duration. The window is centered at the instant. If
+	/// no points in the dataset are within the window range at the supplied
+	/// instant, `None` is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	/// let now = Instant::now();
+	///
+	/// tma.push(now, 1.0);
+	/// tma.push(now + Duration::from_millis(100), 3.0);
+	///
+	/// assert_eq!(tma.get_windowed_min(now, Duration::from_secs(1)), Some(1.0));
+	/// ```
+	#[inline]
+	pub fn get_windowed_min(
+		&self,
+		instant: Instant,
+		window: Duration,
+	) -> Option<f64> {
+		self.get_windowed_values(instant, window)
+			.into_iter()
+			.min_by(f64::total_cmp)
+	}
+
+	/// Returns the `p`th percentile (between 0 and 100) of the values at
+	/// the supplied instant based on the supplied window duration. The
+	/// window is centered at the instant, and values between ranks are
+	/// linearly interpolated. If no points in the dataset are within the
+	/// window range at the supplied instant, `None` is returned.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	/// let now = Instant::now();
+	///
+	/// for (index, value) in [5.0, 1.0, 3.0].into_iter().enumerate() {
+	///     tma.push(now + Duration::from_millis(index as u64 * 100), value);
+	/// }
+	///
+	/// assert_eq!(tma.get_windowed_percentile(now, Duration::from_secs(1), 50.0), Some(3.0));
+	/// assert_eq!(tma.get_windowed_percentile(now, Duration::from_secs(1), 100.0), Some(5.0));
+	/// ```
+	///
+	/// # Panics
+	///
+	/// Panics if `p` is not between 0 and 100.
+	pub fn get_windowed_percentile(
+		&self,
+		instant: Instant,
+		window: Duration,
+		p: f64,
+	) -> Option<f64> {
+		assert!((0.0..=100.0).contains(&p), "Percentile must be between 0 and 100.");
+
+		let mut values = self.get_windowed_values(instant, window);
+
+		if values.is_empty() {
+			return None;
+		}
+
+		values.sort_unstable_by(f64::total_cmp);
+
+		let rank = p / 100.0 * (values.len() - 1) as f64;
+		let lower = rank.floor() as usize;
+		let upper = rank.ceil() as usize;
+
+		Some(values[lower] + (values[upper] - values[lower]) * (rank - lower as f64))
+	}
+
+	/// Reduces the dataset to at most `max_points` points for plotting. The
+	/// time range of the dataset is split into `max_points` equal buckets,
+	/// and each bucket yields the average of its points at the bucket's
+	/// center. Buckets without any points are skipped.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	/// let now = Instant::now();
+	///
+	/// for time in 0..1_000 {
+	///     tma.push(now + Duration::from_millis(time), time);
+	/// }
+	///
+	/// assert_eq!(tma.downsample(10).len(), 10);
+	/// ```
+	pub fn downsample(&self, max_points: usize) -> Vec<(Instant, f64)> {
+		let (Some((first, _)), Some((last, _))) = (self.first(), self.last()) else {
+			return Vec::new();
+		};
+
+		if max_points == 0 {
+			return Vec::new();
+		}
+
+		let bucket_width = (last - first).div_f64(max_points as f64);
+
+		if bucket_width.is_zero() {
+			return self.get_range_average((Bound::Included(first), Bound::Included(last)))
+				.map(|value| vec![(first, value)])
+				.unwrap_or_default();
+		}
+
+		(0..max_points)
+			.filter_map(|index| {
+				let start = first + bucket_width.mul_f64(index as f64);
+
+				// the last bucket includes the last point, which could
+				// otherwise fall outside of it due to rounding
+				let end = match index == max_points - 1 {
+					true => Bound::Included(last),
+					false => Bound::Excluded(first + bucket_width.mul_f64((index + 1) as f64)),
+				};
+
+				self.get_range_average((Bound::Included(start), end))
+					.map(|value| (start + bucket_width / 2, value))
+			})
+			.collect()
+	}
+
 	/// Returns an iterator over a windowed average of the points. The iterator
 	/// yields averages centered within the windows with half-window overlaps.
 	///
@@ -149,7 +538,77 @@ impl TimeMovingAverage {
 	/// }
 	/// ```
 	#[inline]
-	pub fn window_iter(&self, window: Duration) -> WindowIter {
+	pub fn window_iter(&self, window: Duration) -> WindowIter<'_> {
+		let current = self.points
+			.first_key_value()
+			.map(|(instant, _)| *instant);
+
+		WindowIter {
+			tma: self,
+
+			mode: WindowMode::Centered,
+			window,
+			current,
+		}
+	}
+
+	/// Returns an iterator over a trailing average of the points. The
+	/// iterator yields averages of the windows ending at each instant,
+	/// stepping by half a window.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	/// let window = Duration::from_secs(1);
+	///
+	/// tma.push(Instant::now(), 1.0);
+	/// tma.push(Instant::now() + Duration::from_secs(1), 2.0);
+	/// tma.push(Instant::now() + Duration::from_secs(2), 3.0);
+	///
+	/// for (instant, value) in tma.trailing_window_iter(window) {
+	///     // do something with the instant and value
+	/// }
+	/// ```
+	#[inline]
+	pub fn trailing_window_iter(&self, window: Duration) -> WindowIter<'_> {
+		let current = self.points
+			.first_key_value()
+			.map(|(instant, _)| *instant);
+
+		WindowIter {
+			tma: self,
+
+			mode: WindowMode::Trailing,
+			window,
+			current,
+		}
+	}
+
+	/// Returns an iterator over a leading average of the points. The
+	/// iterator yields averages of the windows starting at each instant,
+	/// stepping by half a window.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	/// let window = Duration::from_secs(1);
+	///
+	/// tma.push(Instant::now(), 1.0);
+	/// tma.push(Instant::now() + Duration::from_secs(1), 2.0);
+	/// tma.push(Instant::now() + Duration::from_secs(2), 3.0);
+	///
+	/// for (instant, value) in tma.leading_window_iter(window) {
+	///     // do something with the instant and value
+	/// }
+	/// ```
+	#[inline]
+	pub fn leading_window_iter(&self, window: Duration) -> WindowIter<'_> {
 		let current = self.points
 			.first_key_value()
 			.map(|(instant, _)| *instant);
@@ -157,6 +616,7 @@ impl TimeMovingAverage {
 		WindowIter {
 			tma: self,
 
+			mode: WindowMode::Leading,
 			window,
 			current,
 		}
@@ -191,20 +651,103 @@ impl TimeMovingAverage {
 		IntoWindowIter {
 			tma: self,
 
+			mode: WindowMode::Centered,
 			window,
 			current,
 		}
 	}
 }
 
+impl TimeMovingAverage {
+	fn get_average(
+		&self,
+		mode: WindowMode,
+		instant: Instant,
+		window: Duration,
+	) -> Option<f64> {
+		match mode {
+			WindowMode::Centered => self.get_windowed_average(instant, window),
+			WindowMode::Trailing => self.get_trailing_average(instant, window),
+			WindowMode::Leading => self.get_leading_average(instant, window),
+		}
+	}
+
+	fn get_windowed_values(&self, instant: Instant, window: Duration) -> Vec<f64> {
+		let (start, end) = centered_range(instant, window);
+
+		self.points
+			.range((start, end))
+			.map(|(_, value)| *value)
+			.collect()
+	}
+
+	fn get_range_average(&self, range: (Bound<Instant>, Bound<Instant>)) -> Option<f64> {
+		let mut total: f64 = 0.0;
+		let mut count: usize = 0;
+
+		for (_, value) in self.points.range(range) {
+			total += *value;
+			count += 1;
+		}
+
+		match count {
+			0 => None,
+			count => Some(total / count as f64),
+		}
+	}
+}
+
+/// Returns the bounds of the window centered at the supplied instant. If
+/// either bound would overflow an `Instant`, that side of the window is
+/// unbounded, covering the rest of the dataset.
+fn centered_range(instant: Instant, window: Duration) -> (Bound<Instant>, Bound<Instant>) {
+	let shift = window / 2;
+
+	let start = match instant.checked_sub(shift) {
+		Some(start) => Bound::Included(start),
+		None => Bound::Unbounded,
+	};
+
+	let end = match instant.checked_add(shift) {
+		Some(end) => Bound::Included(end),
+		None => Bound::Unbounded,
+	};
+
+	(start, end)
+}
+
+impl Extend<(Instant, f64)> for TimeMovingAverage {
+	/// Adds the points of the iterator to the dataset. As with `push`, a
+	/// point at the same instant as an existing point overwrites it.
+	///
+	/// # Examples
+	/// ```
+	/// use std::time::{Instant, Duration};
+	/// use kwik::tma::TimeMovingAverage;
+	///
+	/// let mut tma = TimeMovingAverage::default();
+	/// let now = Instant::now();
+	///
+	/// tma.extend([(now, 1.0), (now + Duration::from_secs(1), 2.0)]);
+	///
+	/// assert_eq!(tma.len(), 2);
+	/// ```
+	fn extend<I>(&mut self, iter: I)
+	where
+		I: IntoIterator<Item = (Instant, f64)>,
+	{
+		self.points.extend(iter);
+	}
+}
+
 impl Iterator for WindowIter<'_> {
 	type Item = (Instant, f64);
 
 	fn next(&mut self) -> Option<Self::Item> {
 		let instant = self.current?;
-		let value = self.tma.get_windowed_average(instant, self.window)?;
+		let value = self.tma.get_average(self.mode, instant, self.window)?;
 
-		self.current = Some(instant + self.window / 2);
+		self.current = instant.checked_add(self.window / 2);
 
 		Some((instant, value))
 	}
@@ -215,9 +758,9 @@ impl Iterator for IntoWindowIter {
 
 	fn next(&mut self) -> Option<Self::Item> {
 		let instant = self.current?;
-		let value = self.tma.get_windowed_average(instant, self.window)?;
+		let value = self.tma.get_average(self.mode, instant, self.window)?;
 
-		self.current = Some(instant + self.window / 2);
+		self.current = instant.checked_add(self.window / 2);
 
 		Some((instant, value))
 	}
@@ -271,4 +814,273 @@ mod tests {
 
 		assert_eq!(into_iter_count, expected_values.len());
 	}
+
+	#[test]
+	fn it_yields_correct_trailing_averages() {
+		let mut tma = TimeMovingAverage::default();
+
+		let times = &[0, 1, 2, 3, 4, 5];
+		let values = &[1.0, 1.5, 2.0, 3.0, 5.0, 5.5];
+
+		let start = Instant::now();
+
+		for (time, value) in times.iter().zip(values.iter()) {
+			tma.push(start + Duration::from_secs(*time), *value);
+		}
+
+		let window = Duration::from_secs(2);
+
+		let expected_values = &[
+			1.0,
+			(1.0 + 1.5) / 2.0,
+			(1.5 + 2.0) / 2.0,
+			(2.0 + 3.0) / 2.0,
+			(3.0 + 5.0) / 2.0,
+			(5.0 + 5.5) / 2.0,
+			5.5,
+		];
+
+		let mut iter_count = 0;
+
+		for (_, value) in tma.trailing_window_iter(window) {
+			assert_eq!(value, expected_values[iter_count]);
+			iter_count += 1;
+		}
+
+		assert_eq!(iter_count, expected_values.len());
+
+		let at = start + Duration::from_secs(3);
+
+		assert_eq!(tma.get_trailing_average(at, window), Some((2.0 + 3.0) / 2.0));
+		assert_eq!(tma.get_leading_average(at, window), Some((3.0 + 5.0) / 2.0));
+		assert_eq!(tma.get_windowed_average(at, window), Some((2.0 + 3.0 + 5.0) / 3.0));
+	}
+
+	#[test]
+	fn it_weights_recent_points_more() {
+		let mut recent_tma = TimeMovingAverage::default();
+		let mut old_tma = TimeMovingAverage::default();
+
+		let start = Instant::now();
+
+		for time in 0..5 {
+			let instant = start + Duration::from_secs(time);
+
+			recent_tma.push(instant, if time == 4 { 10.0 } else { 1.0 });
+			old_tma.push(instant, if time == 0 { 10.0 } else { 1.0 });
+		}
+
+		let at = start + Duration::from_secs(4);
+		let window = Duration::from_secs(8);
+		let half_life = Duration::from_secs(1);
+
+		let mean = recent_tma.get_windowed_average(at, window).unwrap();
+		let recent = recent_tma.get_exponential_average(at, window, half_life).unwrap();
+		let old = old_tma.get_exponential_average(at, window, half_life).unwrap();
+
+		assert_eq!(old_tma.get_windowed_average(at, window), Some(mean));
+
+		assert!(recent > mean);
+		assert!(old < mean);
+
+		assert_eq!(
+			recent_tma.get_exponential_average(start + Duration::from_secs(20), window, half_life),
+			None,
+		);
+	}
+
+	#[test]
+	fn it_ignores_points_after_the_instant() {
+		let mut recent_tma = TimeMovingAverage::default();
+		let mut old_tma = TimeMovingAverage::default();
+
+		let start = Instant::now();
+
+		for time in 0..4 {
+			let instant = start + Duration::from_secs(time);
+
+			recent_tma.push(instant, if time == 3 { 10.0 } else { 1.0 });
+			old_tma.push(instant, if time == 0 { 10.0 } else { 1.0 });
+		}
+
+		let at = start + Duration::from_secs(3);
+		let window = Duration::from_secs(8);
+		let half_life = Duration::from_secs(1);
+
+		let recent = recent_tma.get_exponential_average(at, window, half_life).unwrap();
+		let old = old_tma.get_exponential_average(at, window, half_life).unwrap();
+
+		// a spike after the instant does not count towards the average
+		old_tma.push(start + Duration::from_secs(4), 100.0);
+
+		assert!(recent > old);
+		assert_eq!(old_tma.get_exponential_average(at, window, half_life), Some(old));
+	}
+
+	#[test]
+	#[should_panic]
+	fn it_panics_on_a_zero_half_life() {
+		let mut tma = TimeMovingAverage::default();
+		let now = Instant::now();
+
+		tma.push(now, 1.0);
+		tma.get_exponential_average(now, Duration::from_secs(1), Duration::ZERO);
+	}
+
+	#[test]
+	fn it_yields_correct_windowed_statistics() {
+		let mut tma = TimeMovingAverage::default();
+
+		let times = &[0, 1, 2, 3, 4, 5];
+		let values = &[1.0, 1.5, 2.0, 3.0, 5.0, 5.5];
+
+		let start = Instant::now();
+
+		for (time, value) in times.iter().zip(values.iter()) {
+			tma.push(start + Duration::from_secs(*time), *value);
+		}
+
+		let window = Duration::from_secs(2);
+
+		let expected_medians = &[
+			(1.0 + 1.5) / 2.0,
+			1.5,
+			2.0,
+			3.0,
+			5.0,
+			(5.0 + 5.5) / 2.0,
+		];
+
+		for (time, median) in times.iter().zip(expected_medians.iter()) {
+			let instant = start + Duration::from_secs(*time);
+			assert_eq!(tma.get_windowed_percentile(instant, window, 50.0), Some(*median));
+		}
+
+		let at = start + Duration::from_secs(3);
+
+		assert_eq!(tma.get_windowed_min(at, window), Some(2.0));
+		assert_eq!(tma.get_windowed_max(at, window), Some(5.0));
+		assert_eq!(tma.get_windowed_percentile(at, window, 0.0), Some(2.0));
+		assert_eq!(tma.get_windowed_percentile(at, window, 75.0), Some(4.0));
+
+		let empty = start + Duration::from_secs(20);
+
+		assert_eq!(tma.get_windowed_min(empty, window), None);
+		assert_eq!(tma.get_windowed_max(empty, window), None);
+		assert_eq!(tma.get_windowed_percentile(empty, window, 50.0), None);
+	}
+
+	#[test]
+	fn it_merges_datasets() {
+		let mut first = TimeMovingAverage::default();
+		let mut second = TimeMovingAverage::default();
+
+		let start = Instant::now();
+
+		first.push(start, 1.0);
+		first.push(start + Duration::from_secs(1), 2.0);
+
+		second.push(start + Duration::from_secs(2), 3.0);
+		second.push(start + Duration::from_secs(3), 4.0);
+
+		first.merge(second);
+
+		let at = start + Duration::from_secs(2);
+		let window = Duration::from_secs(4);
+
+		assert_eq!(first.len(), 4);
+		assert_eq!(first.get_windowed_average(at, window), Some((1.0 + 2.0 + 3.0 + 4.0) / 4.0));
+
+		first.extend([(start, 5.0), (start + Duration::from_secs(4), 6.0)]);
+
+		assert_eq!(first.len(), 5);
+		assert_eq!(first.get_windowed_average(at, window), Some((5.0 + 2.0 + 3.0 + 4.0 + 6.0) / 5.0));
+	}
+
+	#[test]
+	fn it_iterates_points_in_order() {
+		let mut tma = TimeMovingAverage::default();
+
+		let times = &[3, 0, 5, 1, 4, 2];
+		let start = Instant::now();
+
+		assert_eq!(tma.first(), None);
+		assert_eq!(tma.last(), None);
+
+		for time in times {
+			tma.push(start + Duration::from_secs(*time), *time as f64);
+		}
+
+		let points = tma.iter().collect::<Vec<_>>();
+
+		assert_eq!(points.len(), times.len());
+		assert!(points.windows(2).all(|pair| pair[0].0 < pair[1].0));
+		assert!(points.iter().all(|(instant, value)| *instant == start + Duration::from_secs(*value as u64)));
+
+		assert_eq!(tma.first(), Some((start, 0.0)));
+		assert_eq!(tma.last(), Some((start + Duration::from_secs(5), 5.0)));
+	}
+
+	#[test]
+	fn it_downsamples_points() {
+		let mut tma = TimeMovingAverage::default();
+
+		let times = &[0, 1, 2, 3, 4, 5];
+		let values = &[1.0, 1.5, 2.0, 3.0, 5.0, 5.5];
+
+		let start = Instant::now();
+
+		assert!(tma.downsample(3).is_empty());
+
+		for (time, value) in times.iter().zip(values.iter()) {
+			tma.push(start + Duration::from_secs(*time), *value);
+		}
+
+		let points = tma.downsample(3);
+
+		let expected_values = &[
+			(1.0 + 1.5) / 2.0,
+			(2.0 + 3.0) / 2.0,
+			(5.0 + 5.5) / 2.0,
+		];
+
+		assert_eq!(points.len(), expected_values.len());
+
+		for ((_, value), expected_value) in points.iter().zip(expected_values) {
+			assert_eq!(value, expected_value);
+		}
+
+		assert!(points.windows(2).all(|pair| pair[0].0 < pair[1].0));
+		assert!(points[0].0 > start);
+		assert!(points[2].0 < start + Duration::from_secs(5));
+
+		// buckets between the points are empty
+		assert_eq!(tma.downsample(100).len(), 6);
+		assert!(tma.downsample(0).is_empty());
+	}
+
+	#[test]
+	fn it_clamps_windows_larger_than_the_data() {
+		let mut tma = TimeMovingAverage::default();
+		let start = Instant::now();
+
+		for (time, value) in [(0, 1.0), (1, 2.0), (2, 6.0)] {
+			tma.push(start + Duration::from_secs(time), value);
+		}
+
+		let window = Duration::MAX;
+		let end = start + Duration::from_secs(2);
+
+		assert_eq!(tma.get_windowed_average(start, window), Some(3.0));
+		assert_eq!(tma.get_windowed_average(end, window), Some(3.0));
+		assert_eq!(tma.get_leading_average(start, window), Some(3.0));
+		assert_eq!(tma.get_trailing_average(end, window), Some(3.0));
+		assert_eq!(tma.get_windowed_max(start, window), Some(6.0));
+
+		let points = tma.window_iter(window).collect::<Vec<_>>();
+		assert_eq!(points, vec![(start, 3.0)]);
+
+		let points = tma.leading_window_iter(window).collect::<Vec<_>>();
+		assert_eq!(points, vec![(start, 3.0)]);
+	}
 }